@@ -1,5 +1,6 @@
 //! Common Data Structures and Utilities for the Cutout GD Extension
 
+pub mod rng;
 
 /// Just a simple 2D grid
 #[derive(Debug, Clone, Default)]
@@ -22,6 +23,8 @@ impl<T: Default + Clone> Grid2D<T> {
 impl<T> Grid2D<T> {
     /// Create a grid from a pre-built data vector.
     ///
+    /// Use `try_from_raw` when the vector comes from outside and may not match.
+    ///
     /// # Panics
     /// Panics if `data.len() != width * height`.
     pub fn from_raw(width: usize, height: usize, data: Vec<T>) -> Self {
@@ -37,6 +40,35 @@ impl<T> Grid2D<T> {
         Self { data, width, height }
     }
 
+    /// Create a grid from a pre-built data vector, checking its length.
+    ///
+    /// # Returns
+    /// The grid, or `None` if `data.len() != width * height` (or the product
+    /// overflows)
+    pub fn try_from_raw(width: usize, height: usize, data: Vec<T>) -> Option<Self> {
+        if width.checked_mul(height) != Some(data.len()) {
+            return None;
+        }
+        Some(Self { data, width, height })
+    }
+
+    /// Create a grid from a data vector built to hold exactly `width * height` cells.
+    ///
+    /// For hot paths that generate the vector from the dimensions themselves. The
+    /// length is only checked in debug builds; a mismatched vector makes later
+    /// reads panic or return the wrong cell.
+    pub fn from_raw_unchecked(width: usize, height: usize, data: Vec<T>) -> Self {
+        debug_assert_eq!(
+            data.len(),
+            width * height,
+            "Grid2D::from_raw_unchecked: data length ({}) does not match dimensions ({}x{})",
+            data.len(),
+            width,
+            height,
+        );
+        Self { data, width, height }
+    }
+
     pub fn new_with_default(width: usize, height: usize, default_value: T) -> Self
     where
         T: Clone,
@@ -74,4 +106,50 @@ impl<T> Grid2D<T> {
     pub fn height(&self) -> usize {
         self.height
     }
+
+    /// Iterate over every cell as `(x, y, &value)` in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        let width = self.width;
+        self.data
+            .iter()
+            .enumerate()
+            .map(move |(i, value)| (i % width, i / width, value))
+    }
+
+    /// Iterate over every cell as `(x, y, &mut value)` in row-major order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (usize, usize, &mut T)> {
+        let width = self.width;
+        self.data
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, value)| (i % width, i / width, value))
+    }
+
+    /// The cells of row `y` as a slice.
+    ///
+    /// # Panics
+    /// Panics if `y >= height`.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(
+            y < self.height,
+            "Grid2D::row: row {} out of bounds (height {})",
+            y,
+            self.height,
+        );
+        &self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Iterate over the rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    /// Build a new grid of the same size by applying `f` to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid2D<U> {
+        Grid2D {
+            data: self.data.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
 }
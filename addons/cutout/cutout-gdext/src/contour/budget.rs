@@ -0,0 +1,47 @@
+//! Point budget shared by the contour tracers
+//!
+//! Tracing a huge or noisy image can produce millions of contour points. A
+//! `PointBudget` caps the total traced for one image: once it runs out, the
+//! tracers stop and return only the contours they had finished, and the caller
+//! can check `is_exhausted` to downscale further and retry.
+
+/// Number of contour points a trace may still produce
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PointBudget {
+    remaining: usize,
+    exhausted: bool,
+}
+
+impl PointBudget {
+    /// A budget of `max_points` points across every contour of an image
+    pub fn new(max_points: usize) -> Self {
+        Self {
+            remaining: max_points,
+            exhausted: false,
+        }
+    }
+
+    /// A budget that never runs out
+    pub fn unlimited() -> Self {
+        Self::new(usize::MAX)
+    }
+
+    /// Spend `points` points, or mark the budget exhausted if fewer remain
+    ///
+    /// # Returns
+    /// True if the points fit in the budget
+    pub fn spend(&mut self, points: usize) -> bool {
+        if self.exhausted || points > self.remaining {
+            self.exhausted = true;
+            return false;
+        }
+
+        self.remaining -= points;
+        true
+    }
+
+    /// True once a trace has been cut short
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+}
@@ -0,0 +1,192 @@
+//! Outer/hole classification of detected contours
+//!
+//! Contour algorithms emit a flat list of closed rings. This module works out how
+//! they nest:
+//! 1. Contours are sorted by absolute area, largest first
+//! 2. Each contour's parent is the smallest larger contour containing it
+//! 3. Even nesting depth = outer ring, odd depth = hole of its parent
+//!
+//! Outer rings are oriented CCW (positive `polygon_area`) and holes CW, and each
+//! outer ring is paired with the holes directly inside it. A solid island sitting
+//! inside a hole becomes a new outer ring with its own holes.
+//!
+//! Every ring is also rotated to start at its anchor vertex (lowest y, then
+//! lowest x), so the same outline always comes out as the same point sequence,
+//! whatever vertex the tracing happened to start from.
+
+use crate::fracture::geometry::{calculate_bounds, point_in_polygon, polygon_area};
+use godot::prelude::*;
+
+/// A solid outer ring together with the holes directly inside it.
+///
+/// This matches the `[outer, holes...]` layout the fracture functions expect.
+#[derive(Debug, Clone, Default)]
+pub struct ContourShape {
+    pub outer: Vec<Vector2>,
+    pub holes: Vec<Vec<Vector2>>,
+}
+
+impl ContourShape {
+    /// Flatten into `[outer, holes...]` order.
+    pub fn into_rings(self) -> Vec<Vec<Vector2>> {
+        let mut rings = Vec::with_capacity(self.holes.len() + 1);
+        rings.push(self.outer);
+        rings.extend(self.holes);
+        rings
+    }
+}
+
+/// Build the nesting tree of a set of contours and group them into shapes.
+///
+/// # Arguments
+/// * `contours` - Closed rings in any order and with any winding
+///
+/// # Returns
+/// Shapes ordered by outer ring area, largest first. Rings are re-oriented so
+/// outers are CCW and holes are CW, and rotated to start at their anchor vertex.
+pub fn classify_contours(contours: Vec<Vec<Vector2>>) -> Vec<ContourShape> {
+    let rings = sort_by_area(contours);
+    let (depth, parent) = nesting(&rings);
+
+    // Map ring index -> shape index for outer rings
+    let mut shape_of: Vec<Option<usize>> = vec![None; rings.len()];
+    let mut shapes: Vec<ContourShape> = Vec::new();
+
+    for (i, (_, _, ring)) in rings.into_iter().enumerate() {
+        let mut ring = ring;
+
+        if depth[i].is_multiple_of(2) {
+            orient(&mut ring, true);
+            rotate_to_anchor(&mut ring);
+            shape_of[i] = Some(shapes.len());
+            shapes.push(ContourShape {
+                outer: ring,
+                holes: Vec::new(),
+            });
+        } else if let Some(shape_idx) = parent[i].and_then(|p| shape_of[p]) {
+            orient(&mut ring, false);
+            rotate_to_anchor(&mut ring);
+            shapes[shape_idx].holes.push(ring);
+        }
+    }
+
+    shapes
+}
+
+/// Keep only the outermost rings, as if every hole were filled in.
+///
+/// Holes are dropped along with any islands inside them, since those lie within
+/// the filled silhouette anyway.
+///
+/// # Returns
+/// Outer rings ordered by area, largest first, oriented CCW and rotated to start
+/// at their anchor vertex
+pub fn fill_holes(contours: Vec<Vec<Vector2>>) -> Vec<Vec<Vector2>> {
+    let rings = sort_by_area(contours);
+    let (depth, _) = nesting(&rings);
+
+    rings
+        .into_iter()
+        .zip(depth)
+        .filter(|(_, depth)| *depth == 0)
+        .map(|((_, _, mut ring), _)| {
+            orient(&mut ring, true);
+            rotate_to_anchor(&mut ring);
+            ring
+        })
+        .collect()
+}
+
+/// Pair each ring with its absolute area and bounds, largest first.
+///
+/// Containers always have a larger area than what they contain, so a ring's
+/// possible parents all come before it.
+fn sort_by_area(contours: Vec<Vec<Vector2>>) -> Vec<(f32, Rect2, Vec<Vector2>)> {
+    let mut rings: Vec<(f32, Rect2, Vec<Vector2>)> = contours
+        .into_iter()
+        .map(|c| (polygon_area(&c).abs(), calculate_bounds(&c), c))
+        .collect();
+    rings.sort_by(|a, b| b.0.total_cmp(&a.0));
+    rings
+}
+
+/// Nesting depth and direct parent index of each ring from `sort_by_area`.
+fn nesting(rings: &[(f32, Rect2, Vec<Vector2>)]) -> (Vec<usize>, Vec<Option<usize>>) {
+    let mut depth: Vec<usize> = Vec::with_capacity(rings.len());
+    let mut parent: Vec<Option<usize>> = Vec::with_capacity(rings.len());
+
+    for (i, (_, bounds, ring)) in rings.iter().enumerate() {
+        // Walk candidates smallest-first so the first hit is the direct parent
+        let found = (0..i).rev().find(|&j| {
+            let (_, outer_bounds, ref outer) = rings[j];
+            rect_contains(outer_bounds, *bounds)
+                && ring.first().is_some_and(|&p| point_in_polygon(p, outer))
+        });
+
+        parent.push(found);
+        depth.push(found.map_or(0, |j| depth[j] + 1));
+    }
+
+    (depth, parent)
+}
+
+/// Flatten shapes into a single contour list in `outer, holes..., outer, holes...` order.
+pub fn flatten_shapes(shapes: Vec<ContourShape>) -> Vec<Vec<Vector2>> {
+    shapes.into_iter().flat_map(ContourShape::into_rings).collect()
+}
+
+/// Keep only the shape with the largest outer ring, dropping every other contour.
+///
+/// Holes can't be larger than the outer ring containing them, so this is also
+/// the contour with the greatest absolute area.
+///
+/// # Returns
+/// The largest outer ring followed by its holes if `with_holes` is set, or an
+/// empty list if there are no contours
+pub fn largest_shape(contours: Vec<Vec<Vector2>>, with_holes: bool) -> Vec<Vec<Vector2>> {
+    match classify_contours(contours).into_iter().next() {
+        Some(shape) if with_holes => shape.into_rings(),
+        Some(shape) => vec![shape.outer],
+        None => Vec::new(),
+    }
+}
+
+/// Reverse a ring if needed so it is CCW (`ccw = true`) or CW (`ccw = false`).
+pub fn orient(ring: &mut [Vector2], ccw: bool) {
+    let area = polygon_area(ring);
+    if area != 0.0 && (area > 0.0) != ccw {
+        ring.reverse();
+    }
+}
+
+/// Rotate a ring to start at its anchor vertex: the lowest y, then the lowest x.
+///
+/// An explicitly closed ring stays closed, with the anchor repeated at the end.
+pub fn rotate_to_anchor(ring: &mut Vec<Vector2>) {
+    let closed = ring.len() > 1 && ring.first() == ring.last();
+    if closed {
+        ring.pop();
+    }
+
+    let anchor = (0..ring.len()).min_by(|&a, &b| {
+        ring[a]
+            .y
+            .total_cmp(&ring[b].y)
+            .then(ring[a].x.total_cmp(&ring[b].x))
+    });
+    if let Some(anchor) = anchor {
+        ring.rotate_left(anchor);
+    }
+
+    if closed {
+        ring.push(ring[0]);
+    }
+}
+
+/// Check whether `inner` lies entirely within `outer`.
+fn rect_contains(outer: Rect2, inner: Rect2) -> bool {
+    inner.position.x >= outer.position.x
+        && inner.position.y >= outer.position.y
+        && inner.position.x + inner.size.x <= outer.position.x + outer.size.x
+        && inner.position.y + inner.size.y <= outer.position.y + outer.size.y
+}
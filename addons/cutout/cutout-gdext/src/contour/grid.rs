@@ -11,11 +11,68 @@ use crate::common::Grid2D;
 
 pub type Grid = Grid2D<bool>;
 
+#[cfg(test)]
+impl Grid {
+    /// Build a grid from rows of `#` (solid) and `.` (empty)
+    pub(crate) fn from_ascii(rows: &[&str]) -> Self {
+        let width = rows.first().map_or(0, |row| row.len());
+        let cells: Vec<bool> = rows
+            .iter()
+            .flat_map(|row| row.chars().map(|c| c == '#'))
+            .collect();
+        Grid::from_raw(width, rows.len(), cells)
+    }
+}
+
+/// Collect `(x, y)` pairs into points, for comparing against traced contours
+#[cfg(test)]
+pub(crate) fn points(coords: &[(f32, f32)]) -> Vec<Vector2> {
+    coords.iter().map(|&(x, y)| Vector2::new(x, y)).collect()
+}
+
+/// Grid of per-pixel alpha values in the range 0.0 - 1.0
+pub type AlphaGrid = Grid2D<f32>;
+
 /// Bytes per pixel for RGBA8
 const RGBA8_BPP: usize = 4;
 /// Alpha channel offset within an RGBA8 pixel
 const RGBA8_ALPHA_OFFSET: usize = 3;
 
+/// Rec. 709 luma weights for the red, green and blue channels
+const LUMA_WEIGHTS: [f32; 3] = [0.2126, 0.7152, 0.0722];
+
+/// How pixels are classified as solid when building a grid
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ThresholdMode {
+    /// Solid where alpha is above the threshold
+    Alpha,
+    /// Solid where Rec. 709 luminance is above the threshold; alpha is ignored
+    Luminance,
+    /// Empty where the colour is within `tolerance` of `key`; all other pixels
+    /// are solid where alpha is above the threshold.
+    ///
+    /// `tolerance` is the RGB distance normalised to 0.0 - 1.0, so 0.0 only
+    /// matches the exact key colour and 1.0 matches everything.
+    ChromaKey { key: Color, tolerance: f32 },
+}
+
+impl ThresholdMode {
+    /// The mode to actually use on an image.
+    ///
+    /// An image without an alpha channel would come out fully solid under
+    /// `Alpha`, so it falls back to `Luminance` with a warning.
+    pub fn for_image(self, image: &ImageData) -> Self {
+        if self == Self::Alpha && !image.has_alpha() {
+            godot_warn!(
+                "Image has no alpha channel ({:?}), thresholding on luminance instead of alpha",
+                image.layout
+            );
+            return Self::Luminance;
+        }
+        self
+    }
+}
+
 /// Find the topmost, then leftmost solid pixel in the grid.
 ///
 /// Scans from top to bottom, left to right, returning the first solid pixel found.
@@ -44,34 +101,554 @@ pub fn first_bottom_left_solid_pixel(grid: &Grid) -> Option<Vector2> {
     None
 }
 
-/// Create a binary grid from a Godot Image using an alpha threshold.
+/// Offsets of the 8 pixels surrounding a pixel (Moore neighbourhood)
+const MOORE_OFFSETS: [(i32, i32); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Offsets of the 4 edge-adjacent pixels (von Neumann neighbourhood)
+const VON_NEUMANN_OFFSETS: [(i32, i32); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Label the connected components of solid pixels.
 ///
-/// The image **must** already be decompressed and in RGBA8 format.
-/// Call `Image::decompress()` and `Image::convert(Format::RGBA8)` before
-/// passing the image to this function. The processor methods handle this.
+/// Returns a grid of labels where 0 is empty and `1..=count` identifies the
+/// component each solid pixel belongs to, along with the component count.
+/// Labels are assigned in top-to-bottom, left-to-right scan order.
+pub fn label_components(grid: &Grid, connectivity: Connectivity) -> (Grid2D<u32>, usize) {
+    label_regions(grid, true, connectivity.offsets())
+}
+
+/// Label the regions of empty pixels around solid components of the given connectivity.
 ///
-/// Internally calls `Image::get_data()` once to bulk-read the pixel buffer,
-/// then iterates entirely in Rust with no further FFI calls.
-pub fn create_grid_from_image(image: &Image, threshold: f32) -> Grid {
-    debug_assert_eq!(
-        image.get_format(),
-        Format::RGBA8,
-        "create_grid_from_image: expected RGBA8, got {:?}",
-        image.get_format(),
-    );
+/// Empty pixels use the dual connectivity (4 for 8-connected solids and vice
+/// versa), so an empty region never leaks through a gap the solid components
+/// treat as closed. Label 0 marks solid pixels.
+pub fn label_empty_regions(grid: &Grid, connectivity: Connectivity) -> (Grid2D<u32>, usize) {
+    label_regions(grid, false, connectivity.dual().offsets())
+}
+
+/// Which neighbours count as connected when grouping solid pixels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Edge-adjacent pixels only
+    Four,
+    /// Edge- and corner-adjacent pixels, so diagonal touches join components
+    Eight,
+}
+
+impl Connectivity {
+    /// Neighbour offsets for this connectivity
+    fn offsets(self) -> &'static [(i32, i32)] {
+        match self {
+            Connectivity::Four => &VON_NEUMANN_OFFSETS,
+            Connectivity::Eight => &MOORE_OFFSETS,
+        }
+    }
+
+    /// The connectivity that keeps the background consistent with this one
+    pub fn dual(self) -> Self {
+        match self {
+            Connectivity::Four => Connectivity::Eight,
+            Connectivity::Eight => Connectivity::Four,
+        }
+    }
+}
+
+/// Clear every connected component of solid pixels smaller than `min_pixels`.
+///
+/// Removes dust and compression artifacts so they never produce contours of
+/// their own. Holes are left alone.
+pub fn remove_small_components(grid: &Grid, min_pixels: usize, connectivity: Connectivity) -> Grid {
+    if min_pixels <= 1 {
+        return grid.clone();
+    }
+
+    let (labels, count) = label_components(grid, connectivity);
+
+    // Index 0 is the empty label
+    let mut sizes = vec![0usize; count + 1];
+    for (_, _, &label) in labels.iter() {
+        sizes[label as usize] += 1;
+    }
+
+    labels.map(|&label| label != 0 && sizes[label as usize] >= min_pixels)
+}
+
+/// Keep the connected components of `weak` that contain at least one `strong` pixel.
+///
+/// Hysteresis thresholding: `strong` is the grid from the high threshold and
+/// `weak` the one from the low threshold, so every strong pixel is also weak.
+/// Strong pixels seed each region, which then grows through the weak pixels
+/// connected to it; weak regions that never touch a strong pixel are dropped.
+pub fn hysteresis(strong: &Grid, weak: &Grid, connectivity: Connectivity) -> Grid {
+    let (labels, count) = label_components(weak, connectivity);
+
+    // Index 0 is the empty label
+    let mut seeded = vec![false; count + 1];
+    for (x, y, &label) in labels.iter() {
+        if strong.get_at(x, y) == Some(&true) {
+            seeded[label as usize] = true;
+        }
+    }
+
+    labels.map(|&label| label != 0 && seeded[label as usize])
+}
+
+/// Label connected regions of pixels equal to `target` using the given neighbour offsets.
+fn label_regions(grid: &Grid, target: bool, offsets: &[(i32, i32)]) -> (Grid2D<u32>, usize) {
+    let mut labels: Grid2D<u32> = Grid2D::new(grid.width(), grid.height());
+    let mut count = 0;
+    let mut stack = Vec::new();
+
+    for y in 0..grid.height() {
+        for x in 0..grid.width() {
+            if grid.get_at(x, y) != Some(&target) || labels.get_at(x, y) != Some(&0) {
+                continue;
+            }
+
+            count += 1;
+            let label = count as u32;
+            labels.set(x, y, label);
+            stack.push((x as i32, y as i32));
+
+            // Iterative flood fill to avoid stack overflow on large regions
+            while let Some((cx, cy)) = stack.pop() {
+                for &(dx, dy) in offsets {
+                    let (nx, ny) = (cx + dx, cy + dy);
+                    if grid.get(nx, ny) == Some(&target)
+                        && labels.get_at(nx as usize, ny as usize) == Some(&0)
+                    {
+                        labels.set(nx as usize, ny as usize, label);
+                        stack.push((nx, ny));
+                    }
+                }
+            }
+        }
+    }
+
+    (labels, count)
+}
+
+/// Byte layout of the pixels in an `ImageData` buffer.
+///
+/// These are the 8-bit formats read directly; any other format must be
+/// converted to RGBA8 first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PixelLayout {
+    /// One luminance byte
+    L8,
+    /// Luminance and alpha bytes
+    La8,
+    /// Red, green and blue bytes
+    Rgb8,
+    /// Red, green, blue and alpha bytes
+    Rgba8,
+}
+
+impl PixelLayout {
+    /// The layout of an image format, or `None` if it needs converting to RGBA8
+    pub fn from_format(format: Format) -> Option<Self> {
+        match format {
+            Format::L8 => Some(Self::L8),
+            Format::LA8 => Some(Self::La8),
+            Format::RGB8 => Some(Self::Rgb8),
+            Format::RGBA8 => Some(Self::Rgba8),
+            _ => None,
+        }
+    }
+
+    /// Bytes per pixel
+    #[inline]
+    fn bytes_per_pixel(self) -> usize {
+        match self {
+            Self::L8 => 1,
+            Self::La8 => 2,
+            Self::Rgb8 => 3,
+            Self::Rgba8 => RGBA8_BPP,
+        }
+    }
+
+    /// Offset of the alpha byte within a pixel, if there is one
+    #[inline]
+    fn alpha_offset(self) -> Option<usize> {
+        match self {
+            Self::La8 => Some(1),
+            Self::Rgba8 => Some(RGBA8_ALPHA_OFFSET),
+            Self::L8 | Self::Rgb8 => None,
+        }
+    }
+}
+
+/// Raw 8-bit pixel buffer copied out of a Godot Image.
+///
+/// Unlike `Gd<Image>`, this is `Send`, so it can be handed to worker threads
+/// for grid building and contour detection.
+#[derive(Debug, Clone)]
+pub struct ImageData {
+    pub width: usize,
+    pub height: usize,
+    pub data: Vec<u8>,
+    pub layout: PixelLayout,
+}
+
+impl ImageData {
+    /// Copy the pixel buffer out of an image.
+    ///
+    /// The image **must** already be decompressed and in L8, LA8, RGB8 or RGBA8
+    /// format (see `PixelLayout::from_format`). Call `Image::decompress()` and
+    /// `Image::convert(Format::RGBA8)` on anything else before passing the image
+    /// to this function. The processor methods handle this.
+    ///
+    /// Internally calls `Image::get_data()` once to bulk-read the pixel buffer.
+    pub fn from_image(image: &Image) -> Self {
+        let layout = PixelLayout::from_format(image.get_format());
+        debug_assert!(
+            layout.is_some(),
+            "ImageData::from_image: expected an 8-bit L/LA/RGB/RGBA format, got {:?}",
+            image.get_format(),
+        );
+
+        // Single FFI call - copies the entire pixel buffer into Rust
+        Self {
+            width: image.get_width() as usize,
+            height: image.get_height() as usize,
+            data: image.get_data().to_vec(),
+            layout: layout.unwrap_or(PixelLayout::Rgba8),
+        }
+    }
+
+    /// Wrap a raw pixel buffer, e.g. one built in Rust without a Godot image.
+    ///
+    /// # Returns
+    /// The pixel data, or `None` if `data` doesn't hold exactly `width * height`
+    /// pixels in `layout`
+    pub fn from_raw(
+        width: usize,
+        height: usize,
+        data: Vec<u8>,
+        layout: PixelLayout,
+    ) -> Option<Self> {
+        let expected = width
+            .checked_mul(height)?
+            .checked_mul(layout.bytes_per_pixel())?;
+        (data.len() == expected).then_some(Self {
+            width,
+            height,
+            data,
+            layout,
+        })
+    }
+
+    /// True if the pixels carry an alpha channel
+    pub fn has_alpha(&self) -> bool {
+        self.layout.alpha_offset().is_some()
+    }
+
+    /// Divide premultiplied colour back out by alpha, in place.
+    ///
+    /// Fully transparent pixels carry no colour and are left as they are.
+    /// Layouts without alpha are left unchanged.
+    pub fn unpremultiply(&mut self) {
+        let Some(alpha_offset) = self.layout.alpha_offset() else {
+            return;
+        };
+
+        for pixel in self.data.chunks_exact_mut(self.layout.bytes_per_pixel()) {
+            let alpha = pixel[alpha_offset] as u32;
+            if alpha == 0 {
+                continue;
+            }
+            for channel in &mut pixel[..alpha_offset] {
+                *channel = ((*channel as u32 * 255 + alpha / 2) / alpha).min(255) as u8;
+            }
+        }
+    }
+
+    /// Alpha byte of the pixel at the given linear index (255 without alpha)
+    #[inline]
+    fn alpha_at(&self, index: usize) -> u8 {
+        match self.layout.alpha_offset() {
+            Some(offset) => self.data[index * self.layout.bytes_per_pixel() + offset],
+            None => 255,
+        }
+    }
+
+    /// RGB bytes of the pixel at the given linear index (grey for luminance layouts)
+    #[inline]
+    fn rgb_at(&self, index: usize) -> [u8; 3] {
+        let offset = index * self.layout.bytes_per_pixel();
+        match self.layout {
+            PixelLayout::L8 | PixelLayout::La8 => [self.data[offset]; 3],
+            PixelLayout::Rgb8 | PixelLayout::Rgba8 => [
+                self.data[offset],
+                self.data[offset + 1],
+                self.data[offset + 2],
+            ],
+        }
+    }
+
+    /// Rec. 709 luminance (0.0 - 1.0) of the pixel at the given linear index
+    #[inline]
+    fn luminance_at(&self, index: usize) -> f32 {
+        let [r, g, b] = self.rgb_at(index);
+        (r as f32 * LUMA_WEIGHTS[0] + g as f32 * LUMA_WEIGHTS[1] + b as f32 * LUMA_WEIGHTS[2])
+            / 255.0
+    }
+}
 
-    let width = image.get_width() as usize;
-    let height = image.get_height() as usize;
+/// Create a binary grid from a Godot Image.
+///
+/// Same requirements as `ImageData::from_image`: the image must already be
+/// decompressed and in an 8-bit L, LA, RGB or RGBA format. Those are read in
+/// place, without a conversion to RGBA8. Pass the result to `invert_grid` to
+/// trace the transparent regions instead.
+pub fn create_grid_from_image(image: &Image, threshold: f32, mode: ThresholdMode) -> Grid {
+    create_grid_from_data(&ImageData::from_image(image), threshold, mode)
+}
 
-    // Single FFI call - copies the entire pixel buffer into Rust
-    let data = image.get_data();
+/// Create a binary grid from a pixel buffer.
+///
+/// `threshold` (0.0 - 1.0) applies to alpha, or to luminance in
+/// `ThresholdMode::Luminance`. See `ThresholdMode::for_image` for buffers
+/// without an alpha channel.
+pub fn create_grid_from_data(image: &ImageData, threshold: f32, mode: ThresholdMode) -> Grid {
     let threshold_byte = (threshold * 255.0) as u8;
+    let pixels = 0..image.width * image.height;
+
+    // Branch once on the mode so each pass is a tight loop over the buffer
+    let grid_data: Vec<bool> = match mode.for_image(image) {
+        ThresholdMode::Alpha => pixels.map(|i| image.alpha_at(i) > threshold_byte).collect(),
+        ThresholdMode::Luminance => pixels.map(|i| image.luminance_at(i) > threshold).collect(),
+        ThresholdMode::ChromaKey { key, tolerance } => {
+            let key_rgb = [key.r, key.g, key.b].map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as i32);
+            // Compare squared byte distances; 1.0 tolerance spans the RGB cube diagonal
+            let max_distance = tolerance.max(0.0) * 255.0;
+            let max_distance_sq = 3.0 * max_distance * max_distance;
+
+            pixels
+                .map(|i| {
+                    let distance_sq: i32 = image
+                        .rgb_at(i)
+                        .iter()
+                        .zip(key_rgb)
+                        .map(|(&c, k)| (c as i32 - k).pow(2))
+                        .sum();
+                    image.alpha_at(i) > threshold_byte && distance_sq as f32 > max_distance_sq
+                })
+                .collect()
+        }
+    };
+
+    Grid::from_raw_unchecked(image.width, image.height, grid_data)
+}
+
+/// Render a binary grid as an RGBA8 Image, for inspecting what was thresholded.
+///
+/// Solid cells are opaque white and empty cells fully transparent. An empty grid
+/// gives an empty image.
+pub fn grid_to_image(grid: &Grid) -> Gd<Image> {
+    let data: Vec<u8> = grid
+        .rows()
+        .flatten()
+        .flat_map(|&solid| [if solid { 255 } else { 0 }; RGBA8_BPP])
+        .collect();
+
+    Image::create_from_data(
+        grid.width() as i32,
+        grid.height() as i32,
+        false,
+        Format::RGBA8,
+        &PackedByteArray::from(data.as_slice()),
+    )
+    .unwrap_or_else(Image::new_gd)
+}
+
+/// Create a grid of alpha values (0.0 - 1.0) from a Godot Image.
+///
+/// Same requirements as `ImageData::from_image`: the image must already be
+/// decompressed and in RGBA8 format.
+pub fn create_alpha_grid_from_image(image: &Image) -> AlphaGrid {
+    create_alpha_grid_from_data(&ImageData::from_image(image))
+}
+
+/// Create a grid of alpha values (0.0 - 1.0) from an RGBA8 pixel buffer.
+pub fn create_alpha_grid_from_data(image: &ImageData) -> AlphaGrid {
+    let alpha_data: Vec<f32> = (0..image.width * image.height)
+        .map(|i| image.alpha_at(i) as f32 / 255.0)
+        .collect();
+
+    AlphaGrid::from_raw_unchecked(image.width, image.height, alpha_data)
+}
+
+/// Create a grid of luminance values (0.0 - 1.0) from an RGBA8 pixel buffer.
+pub fn create_luminance_grid_from_data(image: &ImageData) -> AlphaGrid {
+    let luminance_data: Vec<f32> = (0..image.width * image.height)
+        .map(|i| image.luminance_at(i))
+        .collect();
+
+    AlphaGrid::from_raw_unchecked(image.width, image.height, luminance_data)
+}
+
+/// Pick an alpha threshold byte with Otsu's method.
+///
+/// Builds a 256-bin alpha histogram and splits it with `otsu_threshold`.
+/// Returns `None` when there is no split to find, i.e. every pixel has the
+/// same alpha.
+pub fn otsu_alpha_threshold(image: &ImageData) -> Option<u8> {
+    let mut histogram = [0u64; 256];
+    for i in 0..image.width * image.height {
+        histogram[image.alpha_at(i) as usize] += 1;
+    }
+
+    otsu_threshold(&histogram)
+}
+
+/// Split a 256-bin histogram with Otsu's method.
+///
+/// Returns the byte that maximises the between-class variance of the values at
+/// or below it and those above it. When a run of empty bins ties, as with clean
+/// 0/255 alpha, the middle of the run is used. Returns `None` when every value
+/// falls in a single bin.
+pub fn otsu_threshold(histogram: &[u64; 256]) -> Option<u8> {
+    let total: u64 = histogram.iter().sum();
+    let total_sum: f64 = histogram
+        .iter()
+        .enumerate()
+        .map(|(value, &count)| value as f64 * count as f64)
+        .sum();
+
+    // First and last byte reaching the best variance so far
+    let mut best: Option<(usize, usize, f64)> = None;
+    let mut below_count = 0u64;
+    let mut below_sum = 0.0;
+
+    for (value, &count) in histogram.iter().enumerate().take(255) {
+        below_count += count;
+        below_sum += value as f64 * count as f64;
+
+        let above_count = total - below_count;
+        if below_count == 0 || above_count == 0 {
+            continue;
+        }
+
+        let below_mean = below_sum / below_count as f64;
+        let above_mean = (total_sum - below_sum) / above_count as f64;
+        let variance = below_count as f64 * above_count as f64 * (below_mean - above_mean).powi(2);
+
+        // Bins with no pixels leave the variance bit-for-bit unchanged, so ties are exact
+        best = match best {
+            Some((first, _, best_variance)) if variance == best_variance => {
+                Some((first, value, variance))
+            }
+            Some((_, _, best_variance)) if variance < best_variance => best,
+            _ => Some((value, value, variance)),
+        };
+    }
+
+    best.map(|(first, last, _)| ((first + last) / 2) as u8)
+}
+
+/// Threshold each connected component of a pixel buffer on its own.
+///
+/// Components are the connected regions of pixels that aren't fully transparent
+/// (or fully black in `ThresholdMode::Luminance`; key-coloured pixels never
+/// belong to one in `ThresholdMode::ChromaKey`). Each is then split with
+/// `otsu_threshold` over its own alpha (or luminance) values, so a faint glow
+/// standing apart from a solid body gets a cutoff suited to its own range
+/// instead of the body's. A component with a single value is kept whole.
+pub fn component_otsu_grid(
+    image: &ImageData,
+    mode: ThresholdMode,
+    connectivity: Connectivity,
+) -> Grid {
+    let support = create_grid_from_data(image, 0.0, mode);
+    let (labels, component_count) = label_components(&support, connectivity);
+
+    let value_at = |i: usize| match mode {
+        ThresholdMode::Luminance => (image.luminance_at(i) * 255.0) as u8,
+        ThresholdMode::Alpha | ThresholdMode::ChromaKey { .. } => image.alpha_at(i),
+    };
 
-    let grid_data: Vec<bool> = (0..width * height)
-        .map(|i| data[i * RGBA8_BPP + RGBA8_ALPHA_OFFSET] > threshold_byte)
+    // Gather values first: a full histogram per component would cost 2 KiB
+    // even for one-pixel specks
+    let mut members: Vec<Vec<u8>> = vec![Vec::new(); component_count + 1];
+    for (i, (_, _, &label)) in labels.iter().enumerate() {
+        if label != 0 {
+            members[label as usize].push(value_at(i));
+        }
+    }
+
+    let thresholds: Vec<u8> = members
+        .iter()
+        .map(|values| {
+            let mut histogram = [0u64; 256];
+            for &value in values {
+                histogram[value as usize] += 1;
+            }
+            otsu_threshold(&histogram).unwrap_or(0)
+        })
         .collect();
 
-    Grid::from_raw(width, height, grid_data)
+    let mut i = 0;
+    labels.map(|&label| {
+        let solid = label != 0 && value_at(i) > thresholds[label as usize];
+        i += 1;
+        solid
+    })
+}
+
+/// Swap the solid and empty cells of a grid.
+pub fn invert_grid(grid: &Grid) -> Grid {
+    grid.map(|&solid| !solid)
+}
+
+/// Downsample a grid by taking the maximum over each source block.
+///
+/// Each output cell covers the source cells that map onto it, so blocks may
+/// differ in size by one when the dimensions don't divide evenly. For a binary
+/// grid this is an OR reduction: a block is solid if any of its pixels is.
+pub fn max_pool<T: Copy + PartialOrd + Default>(
+    grid: &Grid2D<T>,
+    new_width: usize,
+    new_height: usize,
+) -> Grid2D<T> {
+    let (width, height) = (grid.width(), grid.height());
+    let mut pooled = Grid2D::new(new_width, new_height);
+
+    if new_width == 0 || new_height == 0 {
+        return pooled;
+    }
+
+    for oy in 0..new_height {
+        let y_start = oy * height / new_height;
+        let y_end = ((oy + 1) * height).div_ceil(new_height).min(height);
+
+        for ox in 0..new_width {
+            let x_start = ox * width / new_width;
+            let x_end = ((ox + 1) * width).div_ceil(new_width).min(width);
+
+            let mut max = T::default();
+            for y in y_start..y_end {
+                for x in x_start..x_end {
+                    if let Some(&value) = grid.get_at(x, y) {
+                        if value > max {
+                            max = value;
+                        }
+                    }
+                }
+            }
+            pooled.set(ox, oy, max);
+        }
+    }
+
+    pooled
 }
 
 /// Specialized implementation for bool grids (used for contour detection)
@@ -89,3 +666,87 @@ impl Grid2D<bool> {
         self.get_at(x as usize, y as usize)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cells(grid: &Grid) -> Vec<bool> {
+        grid.iter().map(|(_, _, &solid)| solid).collect()
+    }
+
+    #[test]
+    fn raw_pixels_must_fill_the_image() {
+        assert!(ImageData::from_raw(2, 2, vec![0; 8], PixelLayout::La8).is_some());
+        assert!(ImageData::from_raw(2, 2, vec![0; 7], PixelLayout::La8).is_none());
+        assert!(ImageData::from_raw(usize::MAX, 2, Vec::new(), PixelLayout::L8).is_none());
+    }
+
+    #[test]
+    fn alpha_threshold_splits_raw_pixels() {
+        // Luminance/alpha pairs; only alpha decides in Alpha mode
+        let data = vec![255, 0, 0, 255, 255, 128, 255, 127];
+        let image = ImageData::from_raw(2, 2, data, PixelLayout::La8).unwrap();
+
+        let solid = create_grid_from_data(&image, 0.5, ThresholdMode::Alpha);
+        assert_eq!(cells(&solid), vec![false, true, true, false]);
+    }
+
+    #[test]
+    fn luminance_threshold_reads_rgb() {
+        let data = vec![255, 255, 255, 0, 0, 0, 255, 0, 0];
+        let image = ImageData::from_raw(3, 1, data, PixelLayout::Rgb8).unwrap();
+
+        let solid = create_grid_from_data(&image, 0.5, ThresholdMode::Luminance);
+        assert_eq!(cells(&solid), vec![true, false, false]);
+    }
+
+    #[test]
+    fn hysteresis_keeps_weak_regions_touching_strong_pixels() {
+        let strong = Grid::from_ascii(&["#....", ".....", "....."]);
+        let weak = Grid::from_ascii(&["###..", "....#", "...##"]);
+
+        let kept = hysteresis(&strong, &weak, Connectivity::Eight);
+        assert_eq!(
+            cells(&kept),
+            cells(&Grid::from_ascii(&["###..", ".....", "....."]))
+        );
+    }
+
+    #[test]
+    fn small_components_are_removed() {
+        let speckled = Grid::from_ascii(&["##..#", "##...", "....#"]);
+
+        let cleaned = remove_small_components(&speckled, 2, Connectivity::Four);
+        assert_eq!(
+            cells(&cleaned),
+            cells(&Grid::from_ascii(&["##...", "##...", "....."]))
+        );
+    }
+
+    #[test]
+    fn non_square_pooling_keeps_the_unscaled_axis() {
+        // Only x is over its limit, as with a max_resolution of (2, -1)
+        let wide = Grid::from_ascii(&["#.......", "........", ".......#"]);
+
+        let pooled = max_pool(&wide, 2, 3);
+        assert_eq!(
+            cells(&pooled),
+            cells(&Grid::from_ascii(&["#.", "..", ".#"]))
+        );
+    }
+
+    #[test]
+    fn each_component_gets_its_own_otsu_split() {
+        // An opaque body, a gap, then a faint glow with a brighter core
+        let alpha = [255, 255, 0, 10, 90, 90, 10];
+        let data = alpha.iter().flat_map(|&a| [255, a]).collect();
+        let image = ImageData::from_raw(7, 1, data, PixelLayout::La8).unwrap();
+
+        let solid = component_otsu_grid(&image, ThresholdMode::Alpha, Connectivity::Four);
+        assert_eq!(
+            cells(&solid),
+            vec![true, true, false, false, true, true, false]
+        );
+    }
+}
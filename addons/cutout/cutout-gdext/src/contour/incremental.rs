@@ -0,0 +1,123 @@
+//! CutoutIncrementalContour - Stateful contour detection for editing tools
+//!
+//! Unlike the stateless processors, this keeps the binary grid and the Marching
+//! Squares edge graph of one image between calls. When a paint or erase tool
+//! changes part of the image, `update_region` re-reads only the dirty pixels and
+//! re-evaluates the cells around them, so contours stay interactive on large
+//! canvases.
+//!
+//! Always uses Marching Squares with midpoint placement, thresholding on alpha at
+//! full resolution.
+
+use super::grid::{create_grid_from_data, Grid, ImageData, ThresholdMode};
+use super::marching_squares::IncrementalMarchingSquares;
+use super::processor::CutoutContourProcessor;
+use crate::error::{cutout_error, CutoutError};
+use godot::classes::image::Format;
+use godot::classes::Image;
+use godot::prelude::*;
+
+/// Contours of a single image that can be updated region by region.
+#[derive(GodotClass)]
+#[class(no_init, base=RefCounted)]
+pub struct CutoutIncrementalContour {
+    #[base]
+    base: Base<RefCounted>,
+    state: IncrementalMarchingSquares,
+    alpha_threshold: f32,
+}
+
+#[godot_api]
+impl CutoutIncrementalContour {
+    /// Build the contour state for an image.
+    ///
+    /// # Arguments
+    /// * `image` - Source image (any format; compressed images are decompressed)
+    /// * `alpha_threshold` - Alpha value (0.0 - 1.0) above which a pixel is solid
+    #[func]
+    pub fn create(image: Gd<Image>, alpha_threshold: f32) -> Gd<Self> {
+        let full = Rect2i::new(
+            Vector2i::ZERO,
+            Vector2i::new(image.get_width(), image.get_height()),
+        );
+        let grid = Self::read_grid(&image, full, alpha_threshold);
+
+        Gd::from_init_fn(|base| Self {
+            base,
+            state: IncrementalMarchingSquares::new(grid),
+            alpha_threshold,
+        })
+    }
+
+    /// Re-read a changed region of the image and update the contours around it.
+    ///
+    /// Only cells overlapping `dirty` plus a 1px border are re-evaluated.
+    ///
+    /// # Arguments
+    /// * `image` - The edited image; must be the same size as the one passed to `create`
+    /// * `dirty` - Region of pixels that changed, clamped to the image
+    ///
+    /// # Returns
+    /// False if the image size changed (call `create` again instead)
+    #[func]
+    pub fn update_region(&mut self, image: Gd<Image>, dirty: Rect2i) -> bool {
+        let size = Vector2i::new(image.get_width(), image.get_height());
+        let grid = self.state.grid();
+        if size != Vector2i::new(grid.width() as i32, grid.height() as i32) {
+            cutout_error!(
+                CutoutError::InvalidInput,
+                "update_region: image size {:?} doesn't match the contour grid ({}x{})",
+                size,
+                grid.width(),
+                grid.height()
+            );
+            return false;
+        }
+
+        let Some(region) = Rect2i::new(Vector2i::ZERO, size).intersection(dirty) else {
+            return true; // Nothing inside the image changed
+        };
+
+        let patch = Self::read_grid(&image, region, self.alpha_threshold);
+        self.state.update_region(
+            region.position.x as usize,
+            region.position.y as usize,
+            &patch,
+        );
+        true
+    }
+
+    /// Get the contours of the current image.
+    ///
+    /// # Returns
+    /// Array of contours, each outer contour (CCW) followed by its holes (CW),
+    /// in the same layout as `CutoutContourProcessor`
+    #[func]
+    pub fn get_contours(&self) -> Array<PackedVector2Array> {
+        CutoutContourProcessor::to_godot_array(self.state.contours())
+    }
+
+    /// Threshold a region of an image into a binary grid
+    pub(crate) fn read_grid(image: &Gd<Image>, region: Rect2i, alpha_threshold: f32) -> Grid {
+        // `get_region` refuses compressed images, so decompress a copy first
+        let source = if image.is_compressed() {
+            let mut copy = image.duplicate_resource();
+            copy.decompress();
+            copy
+        } else {
+            image.clone()
+        };
+
+        // `get_region` returns a new image, so converting it never touches the caller's
+        let Some(mut pixels) = source.get_region(region) else {
+            return Grid::new(region.size.x.max(0) as usize, region.size.y.max(0) as usize);
+        };
+        pixels.convert(Format::RGBA8);
+
+        create_grid_from_data(
+            &ImageData::from_image(&pixels),
+            alpha_threshold,
+            ThresholdMode::Alpha,
+        )
+    }
+}
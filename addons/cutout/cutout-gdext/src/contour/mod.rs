@@ -1,17 +1,34 @@
 //! Contour detection algorithms for extracting polygon boundaries from images
 //!
 //! This module provides implementations of:
-//! - Marching Squares (pixel-perfect contours with sub-pixel accuracy)
+//! - Marching Squares (pixel-perfect contours with sub-pixel accuracy), including an
+//!   incremental variant that re-contours only edited regions
 //! - Moore Neighbor (pixel-based boundary tracing)
+//! - Morphological clean-up of the binary grid before tracing
+//! - Chaikin smoothing of detected contours
+//! - SVG export of detected contours
+//! - Triangulated `NavigationPolygon` output for navigation regions
+//! - `ContourResult`, a typed per-image result for batch detection
 
 pub mod algorithm;
+pub mod budget;
+pub mod classify;
 pub mod grid;
+pub mod incremental;
 pub mod marching_squares;
 pub mod moore_neighbour;
+pub mod morphology;
 pub mod processor;
+pub mod result;
 pub mod settings;
+pub mod smooth;
+pub mod svg;
 
 // Re-export key types for convenient access
+pub use budget::PointBudget;
+pub use classify::ContourShape;
 pub use grid::Grid;
+pub use incremental::CutoutIncrementalContour;
 pub use processor::CutoutContourProcessor;
-pub use settings::ContourSettings;
+pub use result::ContourResult;
+pub use settings::{AlgorithmKind, ContourSettings};
@@ -4,13 +4,37 @@
 //!
 //! This algorithm works by:
 //! 1. Taking a pre-built binary grid (solid/empty based on alpha)
-//! 2. Finding the bottommost-leftmost solid pixel as starting point
-//! 3. Tracing the boundary clockwise using Moore neighborhood (8 directions)
-//! 4. Stopping when returning to the starting pixel
+//! 2. Labelling 8-connected components of solid pixels with a flood fill
+//! 3. Finding the bottommost-leftmost pixel of each component as its starting point
+//! 4. Tracing each boundary clockwise using Moore neighborhood (8 directions)
+//! 5. Stopping when about to repeat the first step from the starting pixel
+//! 6. Tracing every enclosed empty region (one that never touches the image border)
+//!    the same way to produce hole contours
+//!
+//! `calculate_with_connectivity` can instead trace 4-connected components with
+//! the square-tracing convention: only N/E/S/W steps, turning left first. The
+//! modes differ on diagonal touches. A staircase such as
+//!
+//! ```text
+//! #..
+//! .#.
+//! ..#
+//! ```
+//!
+//! is one shape with 8-connectivity, traced corner to corner, but three separate
+//! single-pixel shapes with 4-connectivity. Empty regions always use the other
+//! connectivity, so a hole is closed exactly when its solid wall is connected.
+//!
+//! Output is grouped and oriented by `classify`: each outer contour (CCW) is
+//! followed by its holes (CW).
 
+use super::budget::PointBudget;
+use super::classify::{classify_contours, flatten_shapes};
 use super::grid::*;
+use crate::common::Grid2D;
 use godot::prelude::*;
 
+/// Moore neighbourhood in clockwise order, starting west
 const NEIGHBOR_DIRECTIONS: [Vector2i; 8] = [
     Vector2i::new(-1, 0),  // 0: W
     Vector2i::new(-1, -1), // 1: NW
@@ -22,86 +46,358 @@ const NEIGHBOR_DIRECTIONS: [Vector2i; 8] = [
     Vector2i::new(-1, 1),  // 7: SW
 ];
 
-/// Maximum iterations to prevent infinite loops in pathological cases
-const MAX_CONTOUR_POINTS: usize = 1_000_000;
+/// Von Neumann neighbourhood in clockwise order, starting west
+const NEIGHBOR_DIRECTIONS_4: [Vector2i; 4] = [
+    Vector2i::new(-1, 0), // 0: W
+    Vector2i::new(0, -1), // 1: N
+    Vector2i::new(1, 0),  // 2: E
+    Vector2i::new(0, 1),  // 3: S
+];
 
 /// Pure Rust function for Moore Neighbor contour detection
 ///
+/// Every 8-connected component of solid pixels is traced separately, so images
+/// containing several disconnected shapes produce one contour per shape. Enclosed
+/// transparent regions are traced as holes with the opposite winding.
+///
 /// # Arguments
 /// * `grid` - Binary grid of solid/empty pixels
 ///
 /// # Returns
-/// Vector of contours, each contour is a vector of points
+/// Vector of contours, each outer contour (CCW) followed by its holes (CW)
 pub fn calculate(grid: &Grid) -> Vec<Vec<Vector2>> {
-    let Some(start_pixel) = first_bottom_left_solid_pixel(grid) else {
+    calculate_with_connectivity(grid, Connectivity::Eight, &mut PointBudget::unlimited())
+}
+
+/// Moore Neighbor contour detection with a choice of pixel connectivity
+///
+/// With `Connectivity::Four`, pixels touching only at a corner are separate
+/// shapes and boundaries are traced with N/E/S/W steps only. See the module
+/// docs for how the two modes differ.
+///
+/// Every traced point is spent from `budget`. Once it runs out, tracing stops
+/// and only the contours finished so far are returned.
+///
+/// # Arguments
+/// * `grid` - Binary grid of solid/empty pixels
+/// * `connectivity` - Which neighbours of a solid pixel count as connected
+/// * `budget` - Points left to trace
+///
+/// # Returns
+/// Vector of contours, each outer contour (CCW) followed by its holes (CW)
+pub fn calculate_with_connectivity(
+    grid: &Grid,
+    connectivity: Connectivity,
+    budget: &mut PointBudget,
+) -> Vec<Vec<Vector2>> {
+    let (labels, component_count) = label_components(grid, connectivity);
+    if component_count == 0 {
         return Vec::new(); // No solid pixels, return empty contour list
-    };
+    }
 
+    // Shared across components: a pixel consumed by one trace is never reconsidered
     let mut visited = vec![vec![false; grid.width()]; grid.height()];
-    let mut points = vec![start_pixel];
-    let mut current_pixel = start_pixel;
-    let mut last_dir = 0; // Start searching from W (index 0), so next iteration starts at 1
 
-    const N_DIRECTIONS: usize = NEIGHBOR_DIRECTIONS.len();
+    let mut contours: Vec<Vec<Vector2>> = component_start_pixels(&labels, component_count)
+        .into_iter()
+        .map_while(|(label, start_pixel)| match connectivity {
+            Connectivity::Four => {
+                trace_component_4(&labels, label, start_pixel, &mut visited, budget)
+            }
+            Connectivity::Eight => {
+                trace_component(&labels, label, start_pixel, &mut visited, budget)
+            }
+        })
+        .collect();
 
-    // Do at least one iteration to find the first neighbor
-    for i in 0..N_DIRECTIONS {
-        let dir_idx = (last_dir + 1 + i) % N_DIRECTIONS;
-        let dir = NEIGHBOR_DIRECTIONS[dir_idx];
-        let next_pixel = Vector2::new(
-            current_pixel.x + dir.x as f32,
-            current_pixel.y + dir.y as f32,
-        );
+    let (empty_labels, empty_count) = label_empty_regions(grid, connectivity);
+    let exterior = border_labels(&empty_labels, empty_count);
 
-        let nx = next_pixel.x as i32;
-        let ny = next_pixel.y as i32;
+    contours.extend(
+        component_start_pixels(&empty_labels, empty_count)
+            .into_iter()
+            .filter(|(label, _)| !exterior[*label as usize])
+            .map_while(|(label, start_pixel)| {
+                trace_component(&empty_labels, label, start_pixel, &mut visited, budget)
+            }),
+    );
 
-        if nx >= 0 && nx < grid.width() as i32 && ny >= 0 && ny < grid.height() as i32 {
-            if let Some(&true) = grid.get_at(nx as usize, ny as usize) {
-                current_pixel = next_pixel;
-                points.push(current_pixel);
-                visited[ny as usize][nx as usize] = true;
-                last_dir = dir_idx;
-                break;
-            }
+    // Group holes with their outer ring and fix winding, matching Marching Squares output
+    flatten_shapes(classify_contours(contours))
+}
+
+/// Flag every label that touches the image border.
+///
+/// Empty regions touching the border are the background, not holes.
+fn border_labels(labels: &Grid2D<u32>, label_count: usize) -> Vec<bool> {
+    let mut touches_border = vec![false; label_count + 1];
+    let (width, height) = (labels.width(), labels.height());
+
+    let border = (0..width)
+        .flat_map(|x| [(x, 0), (x, height.saturating_sub(1))])
+        .chain((0..height).flat_map(|y| [(0, y), (width.saturating_sub(1), y)]));
+
+    for (x, y) in border {
+        if let Some(&label) = labels.get_at(x, y) {
+            touches_border[label as usize] = true;
         }
     }
 
-    // Continue tracing until we return to the start pixel
-    while current_pixel != start_pixel && points.len() < MAX_CONTOUR_POINTS {
-        let mut found_next = false;
+    touches_border
+}
 
-        for i in 0..N_DIRECTIONS {
-            let dir_idx = (last_dir + 1 + i) % N_DIRECTIONS;
-            let dir = NEIGHBOR_DIRECTIONS[dir_idx];
-            let next_pixel = Vector2::new(
-                current_pixel.x + dir.x as f32,
-                current_pixel.y + dir.y as f32,
-            );
+/// Find the bottommost-leftmost pixel of every labelled component.
+///
+/// Returns `(label, pixel)` pairs ordered by label.
+fn component_start_pixels(labels: &Grid2D<u32>, component_count: usize) -> Vec<(u32, Vector2)> {
+    let mut starts: Vec<Option<Vector2>> = vec![None; component_count + 1];
 
-            let nx = next_pixel.x as i32;
-            let ny = next_pixel.y as i32;
-
-            if nx >= 0 && nx < grid.width() as i32 && ny >= 0 && ny < grid.height() as i32 {
-                let nxu = nx as usize;
-                let nyu = ny as usize;
-
-                if !visited[nyu][nxu] && grid.get_at(nxu, nyu) == Some(&true) {
-                    current_pixel = next_pixel;
-                    points.push(current_pixel);
-                    visited[nyu][nxu] = true;
-                    // record the incoming direction
-                    last_dir = dir_idx + N_DIRECTIONS / 2;
-                    found_next = true;
-                    break;
-                }
+    for y in (0..labels.height()).rev() {
+        for x in 0..labels.width() {
+            let label = *labels.get_at(x, y).unwrap_or(&0) as usize;
+            if label != 0 && starts[label].is_none() {
+                starts[label] = Some(Vector2::new(x as f32, y as f32));
             }
         }
+    }
+
+    starts
+        .into_iter()
+        .enumerate()
+        .filter_map(|(label, start)| start.map(|s| (label as u32, s)))
+        .collect()
+}
+
+/// Trace the outer boundary of a single labelled component.
+///
+/// Only pixels carrying `label` are treated as solid, so neighbouring components
+/// never leak into each other's trace. Each step sweeps clockwise from the pixel
+/// we arrived from, so pixels on one-pixel-wide strands are passed twice, once in
+/// each direction. Tracing stops when the start pixel is about to be left in the
+/// same direction as the first step (Jacob's criterion). An isolated pixel gives a
+/// single-point contour.
+///
+/// Returns `None` if `budget` runs out before the contour is closed.
+fn trace_component(
+    labels: &Grid2D<u32>,
+    label: u32,
+    start_pixel: Vector2,
+    visited: &mut [Vec<bool>],
+    budget: &mut PointBudget,
+) -> Option<Vec<Vector2>> {
+    let is_solid = |x: i32, y: i32| -> bool {
+        x >= 0 && y >= 0 && labels.get_at(x as usize, y as usize) == Some(&label)
+    };
+
+    const N_DIRECTIONS: usize = NEIGHBOR_DIRECTIONS.len();
+
+    // Find the next step from `pixel`, sweeping clockwise from `backtrack`
+    let next_step = |pixel: Vector2i, backtrack: usize| -> Option<usize> {
+        (1..=N_DIRECTIONS)
+            .map(|i| (backtrack + i) % N_DIRECTIONS)
+            .find(|&dir_idx| {
+                let next = pixel + NEIGHBOR_DIRECTIONS[dir_idx];
+                is_solid(next.x, next.y)
+            })
+    };
+
+    let start = Vector2i::new(start_pixel.x as i32, start_pixel.y as i32);
+    if !budget.spend(1) {
+        return None;
+    }
+    let mut points = vec![start_pixel];
+
+    // The start pixel is bottommost-leftmost, so the pixel to its west is empty
+    let Some(first_dir) = next_step(start, 0) else {
+        return Some(points); // Isolated pixel
+    };
+
+    let mut current = start;
+    let mut dir_idx = first_dir;
+    loop {
+        current = current + NEIGHBOR_DIRECTIONS[dir_idx];
+        visited[current.y as usize][current.x as usize] = true;
+
+        // The pixel we came from is solid, so the sweep always finds a step
+        let backtrack = (dir_idx + N_DIRECTIONS / 2) % N_DIRECTIONS;
+        dir_idx = next_step(current, backtrack).unwrap_or(backtrack);
+        if current == start && dir_idx == first_dir {
+            return Some(points);
+        }
+        if !budget.spend(1) {
+            return None;
+        }
+        points.push(Vector2::new(current.x as f32, current.y as f32));
+    }
+}
+
+/// Trace the outer boundary of a single 4-connected labelled component.
+///
+/// Square tracing: each step tries a left turn, straight on, a right turn and
+/// finally turning back, using only N/E/S/W moves. Pixels on one-pixel-wide
+/// strands are passed twice, once in each direction. Tracing stops when the start
+/// pixel is about to be left in the same direction as the first step (Jacob's
+/// criterion), so shapes that pass through their start pixel are still closed.
+///
+/// Returns `None` if `budget` runs out before the contour is closed.
+fn trace_component_4(
+    labels: &Grid2D<u32>,
+    label: u32,
+    start_pixel: Vector2,
+    visited: &mut [Vec<bool>],
+    budget: &mut PointBudget,
+) -> Option<Vec<Vector2>> {
+    let is_solid = |x: i32, y: i32| -> bool {
+        x >= 0 && y >= 0 && labels.get_at(x as usize, y as usize) == Some(&label)
+    };
+
+    const N_DIRECTIONS: usize = NEIGHBOR_DIRECTIONS_4.len();
+
+    // Find the next step from `pixel` after arriving heading `heading`
+    let next_step = |pixel: Vector2i, heading: usize| -> Option<usize> {
+        (0..N_DIRECTIONS)
+            .map(|i| (heading + N_DIRECTIONS - 1 + i) % N_DIRECTIONS)
+            .find(|&dir_idx| {
+                let next = pixel + NEIGHBOR_DIRECTIONS_4[dir_idx];
+                is_solid(next.x, next.y)
+            })
+    };
+
+    let start = Vector2i::new(start_pixel.x as i32, start_pixel.y as i32);
+    if !budget.spend(1) {
+        return None;
+    }
+    let mut points = vec![start_pixel];
+
+    // The start pixel is bottommost-leftmost, so pretend we arrived heading N
+    // with empty space to the west
+    let Some(first_dir) = next_step(start, 1) else {
+        return Some(points); // Isolated pixel
+    };
+
+    let mut current = start;
+    let mut dir_idx = first_dir;
+    loop {
+        current = current + NEIGHBOR_DIRECTIONS_4[dir_idx];
+        visited[current.y as usize][current.x as usize] = true;
+
+        // A pixel always has a solid neighbour: the one we came from
+        dir_idx = next_step(current, dir_idx).unwrap_or((dir_idx + 2) % N_DIRECTIONS);
+        if current == start && dir_idx == first_dir {
+            return Some(points);
+        }
+        if !budget.spend(1) {
+            return None;
+        }
+        points.push(Vector2::new(current.x as f32, current.y as f32));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contour::grid::points;
 
-        if !found_next {
-            break; // No unvisited solid neighbor found, stop tracing
+    #[test]
+    fn single_pixel_is_a_point() {
+        assert_eq!(
+            calculate(&Grid::from_ascii(&["#"])),
+            vec![points(&[(0.0, 0.0)])]
+        );
+    }
+
+    #[test]
+    fn horizontal_line_is_walked_out_and_back() {
+        let contours = calculate(&Grid::from_ascii(&["####"]));
+
+        let expected = points(&[
+            (0.0, 0.0),
+            (1.0, 0.0),
+            (2.0, 0.0),
+            (3.0, 0.0),
+            (2.0, 0.0),
+            (1.0, 0.0),
+        ]);
+        assert_eq!(contours, vec![expected]);
+    }
+
+    #[test]
+    fn thin_l_shape_reaches_both_ends() {
+        let contours = calculate(&Grid::from_ascii(&["#...", "#...", "####"]));
+
+        let expected = points(&[
+            (0.0, 0.0),
+            (0.0, 1.0),
+            (1.0, 2.0),
+            (2.0, 2.0),
+            (3.0, 2.0),
+            (2.0, 2.0),
+            (1.0, 2.0),
+            (0.0, 2.0),
+            (0.0, 1.0),
+        ]);
+        assert_eq!(contours, vec![expected]);
+    }
+
+    #[test]
+    fn vertical_line_is_walked_out_and_back() {
+        let contours = calculate(&Grid::from_ascii(&["#", "#", "#", "#"]));
+
+        let expected = points(&[
+            (0.0, 0.0),
+            (0.0, 1.0),
+            (0.0, 2.0),
+            (0.0, 3.0),
+            (0.0, 2.0),
+            (0.0, 1.0),
+        ]);
+        assert_eq!(contours, vec![expected]);
+    }
+
+    #[test]
+    fn thin_cross_reaches_every_arm() {
+        let cross = Grid::from_ascii(&["..#..", "..#..", "#####", "..#..", "..#.."]);
+        let contours = calculate(&cross);
+
+        assert_eq!(contours.len(), 1);
+        for tip in points(&[(2.0, 0.0), (4.0, 2.0), (2.0, 4.0), (0.0, 2.0)]) {
+            assert!(contours[0].contains(&tip), "missing {tip:?}");
+        }
+        for point in &contours[0] {
+            assert_eq!(
+                cross.get_at(point.x as usize, point.y as usize),
+                Some(&true)
+            );
         }
     }
 
-    vec![points]
+    #[test]
+    fn diagonal_pixels_split_with_four_connectivity() {
+        let stair = Grid::from_ascii(&["#..", ".#.", "..#"]);
+        let mut budget = PointBudget::unlimited();
+
+        assert_eq!(calculate(&stair).len(), 1);
+        assert_eq!(
+            calculate_with_connectivity(&stair, Connectivity::Four, &mut budget).len(),
+            3
+        );
+    }
+
+    #[test]
+    fn enclosed_gap_is_traced_as_hole() {
+        let contours = calculate(&Grid::from_ascii(&["###", "#.#", "###"]));
+
+        assert_eq!(contours.len(), 2);
+        assert_eq!(contours[1], points(&[(1.0, 1.0)]));
+    }
+
+    #[test]
+    fn budget_stops_tracing() {
+        let blobs = Grid::from_ascii(&["##...##", "##...##"]);
+
+        let mut budget = PointBudget::new(6);
+        let contours = calculate_with_connectivity(&blobs, Connectivity::Eight, &mut budget);
+        assert!(budget.is_exhausted());
+        assert_eq!(contours.len(), 1);
+    }
 }
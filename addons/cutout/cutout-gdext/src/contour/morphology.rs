@@ -0,0 +1,105 @@
+//! Morphological operations on binary grids
+//!
+//! Used to clean up noisy masks before contouring:
+//! - Erode: shrink solid regions, deleting features thinner than the kernel
+//! - Dilate: grow solid regions, bridging gaps narrower than the kernel
+//! - Open (erode then dilate): remove isolated speckles, keep shape size
+//! - Close (dilate then erode): fill pinholes and cracks, keep shape size
+//!
+//! The kernel is a square of side `2 * radius + 1`. Square kernels are
+//! separable, so each operation runs as a horizontal then a vertical pass with
+//! a sliding window count, independent of the radius. Pixels outside the grid
+//! count as empty, matching how the contour algorithms see the border.
+
+use super::grid::Grid;
+
+/// Shrink solid regions: a pixel stays solid only if its whole kernel is solid.
+pub fn erode(grid: &Grid, radius: usize) -> Grid {
+    if radius == 0 {
+        return grid.clone();
+    }
+    let window = 2 * radius + 1;
+    sweep(
+        &sweep(grid, radius, Axis::X, |count| count == window),
+        radius,
+        Axis::Y,
+        |count| count == window,
+    )
+}
+
+/// Grow solid regions: a pixel becomes solid if any pixel in its kernel is.
+pub fn dilate(grid: &Grid, radius: usize) -> Grid {
+    if radius == 0 {
+        return grid.clone();
+    }
+    sweep(
+        &sweep(grid, radius, Axis::X, |count| count > 0),
+        radius,
+        Axis::Y,
+        |count| count > 0,
+    )
+}
+
+/// Erode then dilate: removes solid specks smaller than the kernel.
+pub fn open(grid: &Grid, radius: usize) -> Grid {
+    dilate(&erode(grid, radius), radius)
+}
+
+/// Dilate then erode: fills holes and gaps smaller than the kernel.
+///
+/// Shapes closer than the kernel to the grid border may lose pixels there,
+/// since the border counts as empty during the erosion.
+pub fn close(grid: &Grid, radius: usize) -> Grid {
+    erode(&dilate(grid, radius), radius)
+}
+
+#[derive(Clone, Copy)]
+enum Axis {
+    X,
+    Y,
+}
+
+/// One separable pass: count solid pixels in a `2 * radius + 1` window along an
+/// axis and keep the pixel if `keep(count)` holds.
+fn sweep(grid: &Grid, radius: usize, axis: Axis, keep: impl Fn(usize) -> bool) -> Grid {
+    let (width, height) = (grid.width(), grid.height());
+    let (lines, length) = match axis {
+        Axis::X => (height, width),
+        Axis::Y => (width, height),
+    };
+    let at = |line: usize, i: usize| match axis {
+        Axis::X => (i, line),
+        Axis::Y => (line, i),
+    };
+    let solid = |line: usize, i: usize| {
+        let (x, y) = at(line, i);
+        grid.get_at(x, y) == Some(&true)
+    };
+
+    let mut result = Grid::new(width, height);
+
+    for line in 0..lines {
+        // Count for the window centred on position 0
+        let mut count = (0..=radius.min(length.saturating_sub(1)))
+            .filter(|&i| solid(line, i))
+            .count();
+
+        for i in 0..length {
+            if keep(count) {
+                let (x, y) = at(line, i);
+                result.set(x, y, true);
+            }
+
+            // Slide the window one step: add the entering pixel, drop the leaving one
+            let entering = i + radius + 1;
+            if entering < length && solid(line, entering) {
+                count += 1;
+            }
+            if i >= radius && solid(line, i - radius) {
+                count -= 1;
+            }
+        }
+    }
+
+    result
+}
@@ -2,15 +2,46 @@
 //!
 //! This module provides high-level APIs for processing multiple images with
 //! different settings, handling all downscaling/upscaling and grid conversion.
+//!
+//! Batch methods split the work in two: image preparation (resize, format
+//! conversion, pixel readback) touches `Gd<Image>` and runs on the calling thread,
+//! while grid building and contour detection run in parallel on the rayon pool.
 
-use super::grid::create_grid_from_image;
-use super::marching_squares;
+use super::classify::{classify_contours, fill_holes, largest_shape, rotate_to_anchor};
+use super::grid::{
+    component_otsu_grid, create_alpha_grid_from_data, create_grid_from_data,
+    create_luminance_grid_from_data, grid_to_image, hysteresis, invert_grid, max_pool,
+    otsu_alpha_threshold, remove_small_components, AlphaGrid, Connectivity, Grid, ImageData,
+    PixelLayout, ThresholdMode,
+};
+use super::incremental::CutoutIncrementalContour;
+use super::marching_squares::{self, StripMarchingSquares};
 use super::moore_neighbour;
-use super::settings::{ContourSettings, NO_RESOLUTION_LIMIT};
+use super::morphology;
+use super::result::ContourResult;
+use super::settings::{
+    AlgorithmKind, ContourConfig, ContourSettings, BORDER_CLAMP, BORDER_PAD, CONNECTIVITY_4,
+    CONNECTIVITY_8, DEFAULT_KEY_COLOR, DEFAULT_KEY_TOLERANCE, DEFAULT_MAX_CONTOUR_POINTS,
+    DEFAULT_SIMPLIFY_EPSILON, DOWNSCALE_MAX_POOL, DOWNSCALE_RESIZE, MORPHOLOGY_CLOSE,
+    MORPHOLOGY_DILATE, MORPHOLOGY_ERODE, MORPHOLOGY_NONE, MORPHOLOGY_OPEN, NO_LOW_THRESHOLD,
+    NO_RESOLUTION_LIMIT, SIMPLIFY_RDP, SIMPLIFY_VISVALINGAM_WHYATT, THRESHOLD_ALPHA,
+    THRESHOLD_CHROMA_KEY, THRESHOLD_LUMINANCE,
+};
+use super::smooth;
+use super::svg;
+use crate::error::{self, cutout_error, CapturedError, CutoutError};
+use crate::fracture::clipper_utils::clipper2_offset;
+use crate::fracture::geometry::{
+    calculate_bounds, polygon_area, polygon_centroid, polygon_perimeter,
+};
+use crate::geometry::triangulate::triangulate;
+use crate::simplify::{rdp, visvalingam_whyatt};
+use clipper2::JoinType;
 use godot::builtin::VarDictionary as Dictionary;
 use godot::classes::image::Format;
-use godot::classes::Image;
+use godot::classes::{Image, NavigationPolygon};
 use godot::prelude::*;
+use rayon::prelude::*;
 
 /// Main processor for batch contour detection
 ///
@@ -22,6 +53,205 @@ pub struct CutoutContourProcessor;
 
 #[godot_api]
 impl CutoutContourProcessor {
+    /// Process a single image
+    ///
+    /// # Arguments
+    /// * `image` - Image to process
+    /// * `algorithm` - Algorithm to use (0 = Moore, 1 = Marching Squares)
+    /// * `alpha_threshold` - Alpha threshold for solid pixels
+    /// * `max_resolution` - Maximum resolution (NO_RESOLUTION_LIMIT = no limit)
+    ///
+    /// # Returns
+    /// Array of contours for the image
+    #[func]
+    pub fn calculate_single(
+        image: Gd<Image>,
+        algorithm: i32,
+        alpha_threshold: f32,
+        max_resolution: Vector2,
+    ) -> Array<PackedVector2Array> {
+        let config = ContourConfig {
+            algorithm,
+            alpha_threshold,
+            max_resolution,
+            ..Default::default()
+        };
+
+        Self::to_godot_array(Self::process_single_image(&image, &config))
+    }
+
+    /// Process a single image with antialiased (feathered) edges
+    ///
+    /// Uses Marching Squares and places each edge crossing where the alpha,
+    /// linearly interpolated between the two pixels on either side, equals
+    /// `alpha_threshold`. `calculate_single` instead puts every crossing at the
+    /// midpoint between the two pixels, so on a soft edge the contour can be up
+    /// to half a pixel off and steps between pixel midpoints. On a radial
+    /// gradient the binary contour is a staircase around the circle, while this
+    /// one follows the circle within a few hundredths of a pixel.
+    ///
+    /// Same as `calculate_single` with a ContourSettings whose `interpolate` is on.
+    ///
+    /// # Arguments
+    /// * `image` - Image to process
+    /// * `alpha_threshold` - Alpha iso-value (0.0 - 1.0) the contour follows
+    /// * `max_resolution` - Maximum resolution (NO_RESOLUTION_LIMIT = no limit)
+    ///
+    /// # Returns
+    /// Array of contours for the image
+    #[func]
+    pub fn calculate_antialiased(
+        image: Gd<Image>,
+        alpha_threshold: f32,
+        max_resolution: Vector2,
+    ) -> Array<PackedVector2Array> {
+        let config = ContourConfig {
+            algorithm: AlgorithmKind::MarchingSquares as i32,
+            alpha_threshold,
+            max_resolution,
+            interpolate: true,
+            ..Default::default()
+        };
+
+        Self::to_godot_array(Self::process_single_image(&image, &config))
+    }
+
+    /// Process a very large image a strip of rows at a time
+    ///
+    /// `calculate_single` copies the whole image into Rust at once, which for an
+    /// 8192x8192 texture is 256 MB. This instead reads `strip_height` rows at a
+    /// time with `get_region` and links each strip's Marching Squares segments
+    /// before reading the next, keeping only the contour edges and one row of
+    /// pixels between strips. Compressed images still have to be decompressed
+    /// whole first.
+    ///
+    /// Gives the same contours as `calculate_single` with Marching Squares and no
+    /// resolution limit.
+    ///
+    /// # Arguments
+    /// * `image` - Image to process
+    /// * `alpha_threshold` - Alpha threshold for solid pixels
+    /// * `strip_height` - Rows read per strip (at least 1)
+    ///
+    /// # Returns
+    /// Array of contours for the image
+    #[func]
+    pub fn calculate_tiled(
+        image: Gd<Image>,
+        alpha_threshold: f32,
+        strip_height: i32,
+    ) -> Array<PackedVector2Array> {
+        if strip_height < 1 {
+            cutout_error!(
+                CutoutError::InvalidInput,
+                "calculate_tiled: strip_height must be at least 1 (got {})",
+                strip_height
+            );
+            return Array::new();
+        }
+
+        // `get_region` refuses compressed images, so decompress once rather than per strip
+        let source = if image.is_compressed() {
+            let mut copy = image.duplicate_resource();
+            copy.decompress();
+            copy
+        } else {
+            image
+        };
+
+        Self::to_godot_array(Self::trace_strips(
+            source.get_width(),
+            source.get_height(),
+            strip_height,
+            |region| CutoutIncrementalContour::read_grid(&source, region, alpha_threshold),
+        ))
+    }
+
+    /// Find the main silhouette of an image: the contour with the largest area
+    ///
+    /// Same as `calculate_single` with a ContourSettings whose `keep_largest` is on,
+    /// dropping specks and any other blobs.
+    ///
+    /// # Arguments
+    /// * `image` - Image to process
+    /// * `algorithm` - Algorithm to use (0 = Moore, 1 = Marching Squares)
+    /// * `alpha_threshold` - Alpha threshold for solid pixels
+    /// * `max_resolution` - Maximum resolution (NO_RESOLUTION_LIMIT = no limit)
+    /// * `with_holes` - Also return the holes inside the largest contour
+    ///
+    /// # Returns
+    /// The largest contour followed by its holes (if requested), or an empty array
+    /// if the image has no solid pixels
+    #[func]
+    pub fn largest_contour(
+        image: Gd<Image>,
+        algorithm: i32,
+        alpha_threshold: f32,
+        max_resolution: Vector2,
+        with_holes: bool,
+    ) -> Array<PackedVector2Array> {
+        let config = ContourConfig {
+            algorithm,
+            alpha_threshold,
+            max_resolution,
+            keep_largest: true,
+            keep_largest_holes: with_holes,
+            ..Default::default()
+        };
+
+        Self::to_godot_array(Self::process_single_image(&image, &config))
+    }
+
+    /// Build a navigation mesh from an image's solid regions
+    ///
+    /// Detects contours with `settings`, groups them into outer rings and holes,
+    /// and triangulates each shape. Every triangle becomes one navigation polygon
+    /// indexing into the shared vertex list, which holds each shape's outer ring
+    /// followed by its holes. The rings are also added as outlines, so the
+    /// resource can be re-baked with an agent radius later.
+    ///
+    /// # Arguments
+    /// * `image` - Image to process
+    /// * `settings` - Contour detection settings
+    ///
+    /// # Returns
+    /// A NavigationPolygon ready to assign to a NavigationRegion2D (empty if the
+    /// image has no solid pixels)
+    #[func]
+    pub fn navigation_polygon(
+        image: Gd<Image>,
+        settings: Gd<ContourSettings>,
+    ) -> Gd<NavigationPolygon> {
+        let config = settings.bind().to_config();
+        let contours = Self::process_single_image(&image, &config);
+
+        let mut navigation = NavigationPolygon::new_gd();
+        let mut vertices: Vec<Vector2> = Vec::new();
+        let mut polygons: Vec<PackedInt32Array> = Vec::new();
+
+        for shape in classify_contours(contours) {
+            let base = vertices.len() as i32;
+            let indices = triangulate(&shape.outer, &shape.holes);
+
+            for triangle in indices.chunks_exact(3) {
+                let offset = [triangle[0] + base, triangle[1] + base, triangle[2] + base];
+                polygons.push(PackedInt32Array::from(offset.as_slice()));
+            }
+
+            for ring in shape.into_rings() {
+                navigation.add_outline(&PackedVector2Array::from(ring.as_slice()));
+                vertices.extend(ring);
+            }
+        }
+
+        navigation.set_vertices(&PackedVector2Array::from(vertices.as_slice()));
+        for polygon in &polygons {
+            navigation.add_polygon(polygon);
+        }
+
+        navigation
+    }
+
     /// Process multiple images with uniform settings
     ///
     /// # Arguments
@@ -39,19 +269,20 @@ impl CutoutContourProcessor {
         alpha_threshold: f32,
         max_resolution: Vector2,
     ) -> Array<Variant> {
-        let mut results = Array::new();
-
-        for image in images.iter_shared() {
-            let contours = Self::process_single_image(&image, algorithm, alpha_threshold, max_resolution);
-            let contour_array = Self::to_godot_array(contours);
-            results.push(&contour_array.to_variant());
-        }
+        let config = ContourConfig {
+            algorithm,
+            alpha_threshold,
+            max_resolution,
+            ..Default::default()
+        };
 
-        results
+        Self::process_batch(images.iter_shared().map(|image| (image, config)))
     }
 
     /// Process multiple images with individual settings
     ///
+    /// Prefer `calculate_batch_results`, which returns typed results.
+    ///
     /// # Arguments
     /// * `images` - Array of images to process
     /// * `settings` - Array of ContourSettings (must match images length)
@@ -64,7 +295,8 @@ impl CutoutContourProcessor {
         settings: Array<Gd<ContourSettings>>,
     ) -> Array<Variant> {
         if images.len() != settings.len() {
-            godot_error!(
+            cutout_error!(
+                CutoutError::MismatchedCounts,
                 "Image count ({}) doesn't match settings count ({})",
                 images.len(),
                 settings.len()
@@ -72,31 +304,90 @@ impl CutoutContourProcessor {
             return Array::new();
         }
 
-        let mut results = Array::new();
+        let jobs = images
+            .iter_shared()
+            .zip(settings.iter_shared())
+            .map(|(image, setting)| (image, setting.bind().to_config()));
 
-        for i in 0..images.len() {
-            if let (Some(image), Some(setting)) = (images.get(i), settings.get(i)) {
-                let setting_bind = setting.bind();
-
-                let contours = Self::process_single_image(
-                    &image,
-                    setting_bind.algorithm,
-                    setting_bind.alpha_threshold,
-                    setting_bind.max_resolution,
-                );
-                let contour_array = Self::to_godot_array(contours);
-                results.push(&contour_array.to_variant());
-            }
+        Self::process_batch(jobs)
+    }
+
+    /// Process multiple images with individual settings, returning typed results
+    ///
+    /// Same processing as `calculate_batch`, but each image's contours come back
+    /// as a `ContourResult` grouped into shapes, so no casting is needed. Each
+    /// result also reports whether its image hit `max_contour_points` and the
+    /// scale it was downscaled by.
+    ///
+    /// # Arguments
+    /// * `images` - Array of images to process
+    /// * `settings` - Array of ContourSettings (must match images length)
+    ///
+    /// # Returns
+    /// One ContourResult per image, in input order
+    #[func]
+    pub fn calculate_batch_results(
+        images: Array<Gd<Image>>,
+        settings: Array<Gd<ContourSettings>>,
+    ) -> Array<Gd<ContourResult>> {
+        if images.len() != settings.len() {
+            cutout_error!(
+                CutoutError::MismatchedCounts,
+                "Image count ({}) doesn't match settings count ({})",
+                images.len(),
+                settings.len()
+            );
+            return Array::new();
+        }
+
+        let jobs = images
+            .iter_shared()
+            .zip(settings.iter_shared())
+            .map(|(image, setting)| (image, setting.bind().to_config()));
+
+        let mut results = Array::new();
+        for detection in Self::detect_batch(jobs) {
+            results.push(&Self::to_result(detection));
         }
 
         results
     }
 
+    /// Process a single image with a ContourSettings, returning a typed result
+    ///
+    /// Besides the contours in image space, the result carries the scale the
+    /// image was downscaled by for `max_resolution`, and can return the contours
+    /// at that reduced grid resolution for use with a downscaled texture.
+    ///
+    /// # Arguments
+    /// * `image` - Image to process
+    /// * `settings` - ContourSettings to use
+    ///
+    /// # Returns
+    /// The image's ContourResult
+    #[func]
+    pub fn calculate_single_result(
+        image: Gd<Image>,
+        settings: Gd<ContourSettings>,
+    ) -> Gd<ContourResult> {
+        let config = settings.bind().to_config();
+        Self::to_result(Self::detect_contours(
+            &Self::prepare_image(&image, &config),
+            &config,
+        ))
+    }
+
     /// Process multiple images with settings from dictionaries
     ///
     /// # Arguments
     /// * `images` - Array of images to process
-    /// * `settings` - Array of Dictionaries with keys: algorithm, alpha_threshold, max_resolution
+    /// * `settings` - Array of Dictionaries with keys: algorithm, alpha_threshold,
+    ///   alpha_threshold_low, adaptive_threshold, invert, max_resolution,
+    ///   interpolate, border_mode, downscale_mode, threshold_mode, key_color,
+    ///   key_tolerance, premultiplied_alpha, morphology_mode, morphology_radius,
+    ///   min_component_pixels, component_connectivity, trace_connectivity,
+    ///   simplify, simplify_epsilon, simplify_algorithm, keep_largest,
+    ///   keep_largest_holes, fill_holes, max_contour_points
     ///
     /// # Returns
     /// Array of contour arrays (one per image)
@@ -106,7 +397,8 @@ impl CutoutContourProcessor {
         settings: Array<Variant>,
     ) -> Array<Variant> {
         if images.len() != settings.len() {
-            godot_error!(
+            cutout_error!(
+                CutoutError::MismatchedCounts,
                 "Image count ({}) doesn't match settings count ({})",
                 images.len(),
                 settings.len()
@@ -114,7 +406,7 @@ impl CutoutContourProcessor {
             return Array::new();
         }
 
-        let mut results = Array::new();
+        let mut jobs = Vec::with_capacity(images.len());
 
         for i in 0..images.len() {
             if let (Some(image), Some(dict_variant)) = (images.get(i), settings.get(i)) {
@@ -131,32 +423,496 @@ impl CutoutContourProcessor {
                     .get("alpha_threshold")
                     .map(|v| v.try_to::<f32>().unwrap_or(0.5))
                     .unwrap_or(0.5);
+                let alpha_threshold_low = dict
+                    .get("alpha_threshold_low")
+                    .map(|v| v.try_to::<f32>().unwrap_or(NO_LOW_THRESHOLD))
+                    .unwrap_or(NO_LOW_THRESHOLD);
+                let adaptive_threshold = dict
+                    .get("adaptive_threshold")
+                    .map(|v| v.try_to::<bool>().unwrap_or(false))
+                    .unwrap_or(false);
+                let invert = dict
+                    .get("invert")
+                    .map(|v| v.try_to::<bool>().unwrap_or(false))
+                    .unwrap_or(false);
                 let max_resolution = dict
                     .get("max_resolution")
                     .map(|v| v.try_to::<Vector2>().unwrap_or(NO_RESOLUTION_LIMIT))
                     .unwrap_or(NO_RESOLUTION_LIMIT);
+                let interpolate = dict
+                    .get("interpolate")
+                    .map(|v| v.try_to::<bool>().unwrap_or(false))
+                    .unwrap_or(false);
+                let border_mode = dict
+                    .get("border_mode")
+                    .map(|v| v.try_to::<i32>().unwrap_or(BORDER_PAD))
+                    .unwrap_or(BORDER_PAD);
+                let downscale_mode = dict
+                    .get("downscale_mode")
+                    .map(|v| v.try_to::<i32>().unwrap_or(DOWNSCALE_RESIZE))
+                    .unwrap_or(DOWNSCALE_RESIZE);
+                let threshold_mode = dict
+                    .get("threshold_mode")
+                    .map(|v| v.try_to::<i32>().unwrap_or(THRESHOLD_ALPHA))
+                    .unwrap_or(THRESHOLD_ALPHA);
+                let key_color = dict
+                    .get("key_color")
+                    .map(|v| v.try_to::<Color>().unwrap_or(DEFAULT_KEY_COLOR))
+                    .unwrap_or(DEFAULT_KEY_COLOR);
+                let key_tolerance = dict
+                    .get("key_tolerance")
+                    .map(|v| v.try_to::<f32>().unwrap_or(DEFAULT_KEY_TOLERANCE))
+                    .unwrap_or(DEFAULT_KEY_TOLERANCE);
+                let premultiplied_alpha = dict
+                    .get("premultiplied_alpha")
+                    .map(|v| v.try_to::<bool>().unwrap_or(false))
+                    .unwrap_or(false);
+                let morphology_mode = dict
+                    .get("morphology_mode")
+                    .map(|v| v.try_to::<i32>().unwrap_or(MORPHOLOGY_NONE))
+                    .unwrap_or(MORPHOLOGY_NONE);
+                let morphology_radius = dict
+                    .get("morphology_radius")
+                    .map(|v| v.try_to::<i32>().unwrap_or(1))
+                    .unwrap_or(1);
+                let min_component_pixels = dict
+                    .get("min_component_pixels")
+                    .map(|v| v.try_to::<i32>().unwrap_or(0))
+                    .unwrap_or(0);
+                let component_connectivity = dict
+                    .get("component_connectivity")
+                    .map(|v| v.try_to::<i32>().unwrap_or(CONNECTIVITY_8))
+                    .unwrap_or(CONNECTIVITY_8);
+                let trace_connectivity = dict
+                    .get("trace_connectivity")
+                    .map(|v| v.try_to::<i32>().unwrap_or(CONNECTIVITY_8))
+                    .unwrap_or(CONNECTIVITY_8);
+                let simplify = dict
+                    .get("simplify")
+                    .map(|v| v.try_to::<bool>().unwrap_or(false))
+                    .unwrap_or(false);
+                let simplify_epsilon = dict
+                    .get("simplify_epsilon")
+                    .map(|v| v.try_to::<f32>().unwrap_or(DEFAULT_SIMPLIFY_EPSILON))
+                    .unwrap_or(DEFAULT_SIMPLIFY_EPSILON);
+                let simplify_algorithm = dict
+                    .get("simplify_algorithm")
+                    .map(|v| v.try_to::<i32>().unwrap_or(SIMPLIFY_RDP))
+                    .unwrap_or(SIMPLIFY_RDP);
+                let keep_largest = dict
+                    .get("keep_largest")
+                    .map(|v| v.try_to::<bool>().unwrap_or(false))
+                    .unwrap_or(false);
+                let keep_largest_holes = dict
+                    .get("keep_largest_holes")
+                    .map(|v| v.try_to::<bool>().unwrap_or(false))
+                    .unwrap_or(false);
+                let fill_holes = dict
+                    .get("fill_holes")
+                    .map(|v| v.try_to::<bool>().unwrap_or(false))
+                    .unwrap_or(false);
+                let max_contour_points = dict
+                    .get("max_contour_points")
+                    .map(|v| v.try_to::<i32>().unwrap_or(DEFAULT_MAX_CONTOUR_POINTS))
+                    .unwrap_or(DEFAULT_MAX_CONTOUR_POINTS);
+
+                let config = ContourConfig {
+                    algorithm,
+                    alpha_threshold,
+                    alpha_threshold_low,
+                    adaptive_threshold,
+                    invert,
+                    max_resolution,
+                    interpolate,
+                    border_mode,
+                    downscale_mode,
+                    threshold_mode,
+                    key_color,
+                    key_tolerance,
+                    premultiplied_alpha,
+                    morphology_mode,
+                    morphology_radius,
+                    min_component_pixels,
+                    component_connectivity,
+                    trace_connectivity,
+                    simplify,
+                    simplify_epsilon,
+                    simplify_algorithm,
+                    keep_largest,
+                    keep_largest_holes,
+                    fill_holes,
+                    max_contour_points,
+                };
 
-                let contours =
-                    Self::process_single_image(&image, algorithm, alpha_threshold, max_resolution);
-                let contour_array = Self::to_godot_array(contours);
-                results.push(&contour_array.to_variant());
+                jobs.push((image, config));
             }
         }
 
+        Self::process_batch(jobs)
+    }
+
+    /// Suggest an alpha threshold for an image using Otsu's method
+    ///
+    /// Splits the alpha histogram where the variance between transparent and
+    /// opaque pixels is largest, which finds the natural edge of sprites with
+    /// clean alpha.
+    ///
+    /// # Arguments
+    /// * `image` - Image to analyse (any format; compressed images are decompressed)
+    ///
+    /// # Returns
+    /// A threshold (0.0 - 1.0) for `alpha_threshold`, or 0.5 if every pixel has
+    /// the same alpha
+    #[func]
+    pub fn suggest_alpha_threshold(image: Gd<Image>) -> f32 {
+        let prepared = Self::prepare_image(&image, &ContourConfig::default());
+
+        match otsu_alpha_threshold(&prepared.pixels) {
+            // Centre on the byte so the grid's byte truncation lands exactly on it
+            Some(byte) => (byte as f32 + 0.5) / 255.0,
+            None => ContourConfig::default().alpha_threshold,
+        }
+    }
+
+    /// Get the binary mask contour detection would trace, for debugging
+    ///
+    /// Thresholds and downscales the image exactly as `calculate_single` does,
+    /// so a wrong-looking contour can be checked against what the algorithm
+    /// actually saw.
+    ///
+    /// # Arguments
+    /// * `image` - Image to process
+    /// * `alpha_threshold` - Alpha threshold for solid pixels
+    /// * `max_resolution` - Maximum resolution (NO_RESOLUTION_LIMIT = no limit)
+    ///
+    /// # Returns
+    /// A new RGBA8 image at the (possibly downscaled) grid resolution, white
+    /// where solid and transparent where empty
+    #[func]
+    pub fn debug_mask(
+        image: Gd<Image>,
+        alpha_threshold: f32,
+        max_resolution: Vector2,
+    ) -> Gd<Image> {
+        let config = ContourConfig {
+            alpha_threshold,
+            max_resolution,
+            ..Default::default()
+        };
+
+        let prepared = Self::prepare_image(&image, &config);
+        grid_to_image(&Self::build_grid(&prepared, &config))
+    }
+
+    /// Smooth a contour with Chaikin's corner-cutting algorithm
+    ///
+    /// Rounds off the jagged pixel steps of detected contours. The contour is
+    /// treated as closed. Each iteration doubles the vertex count, so consider
+    /// simplifying the result afterwards.
+    ///
+    /// # Arguments
+    /// * `polygon` - Contour to smooth
+    /// * `iterations` - Number of smoothing passes (clamped to 0-8)
+    ///
+    /// # Returns
+    /// The smoothed contour
+    #[func]
+    pub fn smooth_contour(polygon: PackedVector2Array, iterations: i32) -> PackedVector2Array {
+        let smoothed = smooth::smooth(polygon.as_slice(), iterations);
+        PackedVector2Array::from(smoothed.as_slice())
+    }
+
+    /// Turn a contour into point lists ready for a Line2D
+    ///
+    /// The contour can first be pushed outward (or pulled inward) so the line sits
+    /// just outside the sprite rather than over its edge pixels. Offset corners are
+    /// rounded. Outsetting a concave contour can merge parts of it and leave loops
+    /// around enclosed gaps, and insetting a thin one can split it; every resulting
+    /// loop is returned.
+    ///
+    /// # Arguments
+    /// * `polygon` - Contour, explicitly closed or not
+    /// * `offset` - Distance to move the outline; positive outsets, negative insets,
+    ///   0 keeps the contour as is
+    /// * `closed` - Repeat the first point at the end so the line draws the closing
+    ///   segment (leave off when using Line2D's own `closed` property)
+    ///
+    /// # Returns
+    /// Array of outlines, empty if the contour has fewer than 3 points or an inset
+    /// removes it entirely
+    #[func]
+    pub fn contour_to_outline(
+        polygon: PackedVector2Array,
+        offset: f32,
+        closed: bool,
+    ) -> Array<PackedVector2Array> {
+        let mut ring = polygon.to_vec();
+        if ring.len() > 1 && ring.first() == ring.last() {
+            ring.pop();
+        }
+        if ring.len() < 3 {
+            return Array::new();
+        }
+
+        let mut loops = if offset == 0.0 {
+            vec![ring]
+        } else {
+            clipper2_offset(&ring, offset, JoinType::Round)
+        };
+
+        if closed {
+            for outline in &mut loops {
+                if let Some(&first) = outline.first() {
+                    outline.push(first);
+                }
+            }
+        }
+
+        Self::to_godot_array(loops)
+    }
+
+    /// Group a flat contour list into shapes of one outer ring plus its holes
+    ///
+    /// Nesting is worked out by containment rather than contour size, so a small
+    /// shape is never mistaken for a hole of a larger one. Outer rings are
+    /// re-oriented CCW and holes CW.
+    ///
+    /// # Arguments
+    /// * `contours` - Contours from any of the calculate methods
+    ///
+    /// # Returns
+    /// Array of shapes, each an `Array[PackedVector2Array]` of `[outer, holes...]`
+    /// ready to pass to the fracture functions
+    #[func]
+    pub fn classify_contours(contours: Array<PackedVector2Array>) -> Array<Variant> {
+        let rings: Vec<Vec<Vector2>> = contours.iter_shared().map(|c| c.to_vec()).collect();
+
+        let mut results = Array::new();
+        for shape in classify_contours(rings) {
+            let shape_array = Self::to_godot_array(shape.into_rings());
+            results.push(&shape_array.to_variant());
+        }
+
         results
     }
+
+    /// Get the area enclosed by a contour
+    ///
+    /// # Arguments
+    /// * `polygon` - Contour, any winding
+    ///
+    /// # Returns
+    /// The area, always positive (0 for fewer than 3 points)
+    #[func]
+    pub fn contour_area(polygon: PackedVector2Array) -> f32 {
+        polygon_area(polygon.as_slice()).abs()
+    }
+
+    /// Get the length of a contour's outline, including the closing edge
+    ///
+    /// # Arguments
+    /// * `polygon` - Contour, explicitly closed or not
+    ///
+    /// # Returns
+    /// The perimeter
+    #[func]
+    pub fn contour_perimeter(polygon: PackedVector2Array) -> f32 {
+        polygon_perimeter(polygon.as_slice())
+    }
+
+    /// Measure every contour in one call
+    ///
+    /// Handy for sorting shapes or filtering out tiny ones without looping over
+    /// their points in GDScript.
+    ///
+    /// # Arguments
+    /// * `contours` - Contours from any of the calculate methods
+    ///
+    /// # Returns
+    /// One Dictionary per contour, in the same order, with keys:
+    /// * `area` - As from `contour_area`
+    /// * `perimeter` - As from `contour_perimeter`
+    /// * `centroid` - Area-weighted centroid (the bounds' centre if the area is zero)
+    /// * `bounds` - Rect2 bounding box
+    #[func]
+    pub fn contour_metrics(contours: Array<PackedVector2Array>) -> Array<Dictionary> {
+        let mut results = Array::new();
+
+        for contour in contours.iter_shared() {
+            let points = contour.as_slice();
+            let bounds = calculate_bounds(points);
+
+            let mut metrics = Dictionary::new();
+            metrics.set("area", polygon_area(points).abs());
+            metrics.set("perimeter", polygon_perimeter(points));
+            metrics.set(
+                "centroid",
+                polygon_centroid(points).unwrap_or_else(|| bounds.center()),
+            );
+            metrics.set("bounds", bounds);
+            results.push(&metrics);
+        }
+
+        results
+    }
+
+    /// Export contours as an SVG path `d` string
+    ///
+    /// Each contour becomes an `M x y L ... Z` subpath. Holes are wound opposite
+    /// to the outer ring containing them, so the default `nonzero` fill rule
+    /// renders them as holes.
+    ///
+    /// # Arguments
+    /// * `contours` - Contours from any of the calculate methods
+    ///
+    /// # Returns
+    /// The path data, or an empty string if there are no contours
+    #[func]
+    pub fn contours_to_svg_path(contours: Array<PackedVector2Array>) -> GString {
+        let rings: Vec<Vec<Vector2>> = contours.iter_shared().map(|c| c.to_vec()).collect();
+        GString::from(svg::contours_to_path(rings).as_str())
+    }
+
+    /// Export contours as a standalone SVG document
+    ///
+    /// Wraps `contours_to_svg_path` in an `<svg>` element whose viewBox is the
+    /// bounding box of the contours.
+    ///
+    /// # Arguments
+    /// * `contours` - Contours from any of the calculate methods
+    ///
+    /// # Returns
+    /// The SVG document as a string
+    #[func]
+    pub fn contours_to_svg_document(contours: Array<PackedVector2Array>) -> GString {
+        let rings: Vec<Vec<Vector2>> = contours.iter_shared().map(|c| c.to_vec()).collect();
+        GString::from(svg::contours_to_document(rings).as_str())
+    }
+
+    /// Parse an SVG path into polygons ready for fracturing
+    ///
+    /// Supports M/L/H/V/C/Q/Z in absolute and relative form. Curves are
+    /// flattened into line segments, every subpath is treated as closed and
+    /// unsupported or malformed commands are skipped.
+    ///
+    /// # Arguments
+    /// * `d` - Path data, as found in a `<path>` element's `d` attribute
+    /// * `curve_resolution` - Line segments per bezier curve (minimum 1)
+    ///
+    /// # Returns
+    /// Polygons as `[outer, holes...]`, outer CCW and holes CW. A path with
+    /// several disjoint shapes yields each shape's group in turn, largest first;
+    /// use `classify_contours` to split them.
+    #[func]
+    pub fn svg_path_to_polygons(d: GString, curve_resolution: i32) -> Array<PackedVector2Array> {
+        Self::to_godot_array(svg::path_to_polygons(&d.to_string(), curve_resolution))
+    }
+}
+
+/// An image that has been downscaled and read back into Rust, ready for detection
+struct PreparedImage {
+    pixels: ImageData,
+    /// How pixels are classified as solid
+    threshold_mode: ThresholdMode,
+    /// Per-axis scale (downscaled size / original size) applied during
+    /// downscaling, if any
+    scale: Option<Vector2>,
+    /// Target grid size when downscaling by max-pooling instead of resizing
+    pooled_size: Option<(usize, usize)>,
+}
+
+/// Contours detected in one image
+struct Detection {
+    contours: Vec<Vec<Vector2>>,
+    /// Tracing stopped at `max_contour_points`, so later contours are missing
+    truncated: bool,
+    /// Per-axis scale from image space to grid space (ONE if not downscaled)
+    scale: Vector2,
 }
 
 impl CutoutContourProcessor {
+    /// Process a batch of images into untyped contour arrays
+    fn process_batch(jobs: impl IntoIterator<Item = (Gd<Image>, ContourConfig)>) -> Array<Variant> {
+        let mut results = Array::new();
+        for detection in Self::detect_batch(jobs) {
+            let contour_array = Self::to_godot_array(detection.contours);
+            results.push(&contour_array.to_variant());
+        }
+
+        results
+    }
+
+    /// Detect the contours of a batch of images, running detection in parallel
+    ///
+    /// Images are prepared sequentially on the calling thread since `Gd<Image>`
+    /// can't cross threads, then the CPU-bound detection is spread across the
+    /// rayon pool. Result order matches input order.
+    fn detect_batch(jobs: impl IntoIterator<Item = (Gd<Image>, ContourConfig)>) -> Vec<Detection> {
+        let prepared: Vec<(PreparedImage, ContourConfig)> = jobs
+            .into_iter()
+            .map(|(image, config)| (Self::prepare_image(&image, &config), config))
+            .collect();
+
+        Self::detect_prepared(&prepared)
+    }
+
+    /// Detect the contours of prepared images in parallel
+    ///
+    /// Errors raised on the workers are held back and reported here, on the
+    /// calling thread, once every image is done.
+    fn detect_prepared(prepared: &[(PreparedImage, ContourConfig)]) -> Vec<Detection> {
+        let detections: Vec<(Detection, Vec<CapturedError>)> = prepared
+            .par_iter()
+            .map(|(image, config)| error::capture(|| Self::detect_contours(image, config)))
+            .collect();
+
+        detections
+            .into_iter()
+            .map(|(detection, errors)| {
+                error::report(errors);
+                detection
+            })
+            .collect()
+    }
+
+    /// Run Marching Squares over an image one horizontal strip at a time
+    ///
+    /// `read_strip` thresholds the given region of the image, so only one strip
+    /// of pixels needs to be in memory at once.
+    fn trace_strips(
+        width: i32,
+        height: i32,
+        strip_height: i32,
+        mut read_strip: impl FnMut(Rect2i) -> Grid,
+    ) -> Vec<Vec<Vector2>> {
+        let mut strips = StripMarchingSquares::new(width.max(0) as usize, height.max(0) as usize);
+
+        for y in (0..height).step_by(strip_height.max(1) as usize) {
+            let size = Vector2i::new(width, strip_height.min(height - y));
+            strips.push_strip(&read_strip(Rect2i::new(Vector2i::new(0, y), size)));
+        }
+
+        strips.finish()
+    }
+
+    /// Wrap a detection in a typed result
+    fn to_result(detection: Detection) -> Gd<ContourResult> {
+        ContourResult::from_contours(detection.contours, detection.truncated, detection.scale)
+    }
+
     /// Process a single image with given settings
     ///
     /// Handles downscaling, grid conversion, algorithm dispatch, and upscaling
-    fn process_single_image(
-        image: &Gd<Image>,
-        algorithm: i32,
-        alpha_threshold: f32,
-        max_resolution: Vector2,
-    ) -> Vec<Vec<Vector2>> {
+    fn process_single_image(image: &Gd<Image>, config: &ContourConfig) -> Vec<Vec<Vector2>> {
+        Self::detect_contours(&Self::prepare_image(image, config), config).contours
+    }
+
+    /// Downscale and convert an image, then copy its pixels into Rust
+    ///
+    /// This is the only step that touches the Godot image, so it must run on the
+    /// calling thread.
+    fn prepare_image(image: &Gd<Image>, config: &ContourConfig) -> PreparedImage {
+        let max_resolution = config.max_resolution;
         let width = image.get_width();
         let height = image.get_height();
 
@@ -177,65 +933,452 @@ impl CutoutContourProcessor {
             1.0
         };
 
-        // Use the smaller scale factor to ensure both dimensions stay within limits
-        let scale_factor = scale_x.min(scale_y);
+        // Each axis is scaled on its own, so an axis within its limit keeps full detail
+        let new_width = ((width as f32 * scale_x) as i32).max(1);
+        let new_height = ((height as f32 * scale_y) as i32).max(1);
+        let use_max_pool = config.downscale_mode == DOWNSCALE_MAX_POOL;
 
-        // Deep-copy the image so we never mutate the caller's original.
-        // `Gd::clone()` only increments the ref-count for RefCounted types,
-        // so we must use `duplicate_resource()` to get an independent copy.
-        let mut working_image = image.duplicate_resource();
+        if config.downscale_mode != DOWNSCALE_RESIZE && !use_max_pool {
+            cutout_error!(
+                CutoutError::UnknownOption,
+                "Unknown downscale mode: {}, defaulting to Resize",
+                config.downscale_mode
+            );
+        }
 
-        if needs_downscaling {
-            let new_width = (width as f32 * scale_factor) as i32;
-            let new_height = (height as f32 * scale_factor) as i32;
-            working_image.resize(new_width, new_height);
+        // Max-pooling happens on the full-resolution grid in `detect_contours`
+        let needs_resize = needs_downscaling && !use_max_pool;
+
+        let is_readable =
+            !image.is_compressed() && PixelLayout::from_format(image.get_format()).is_some();
+
+        // Single get_data() FFI call either way
+        let mut pixels = if !needs_resize && is_readable {
+            // Already in a layout we read (L8, LA8, RGB8 or RGBA8), so skip the
+            // full-image copy and conversion
+            ImageData::from_image(image)
+        } else {
+            // Deep-copy the image so we never mutate the caller's original.
+            // `Gd::clone()` only increments the ref-count for RefCounted types,
+            // so we must use `duplicate_resource()` to get an independent copy.
+            let mut working_image = image.duplicate_resource();
+
+            if needs_resize {
+                working_image.resize(new_width, new_height);
+            }
+
+            working_image.decompress();
+            if PixelLayout::from_format(working_image.get_format()).is_none() {
+                working_image.convert(Format::RGBA8);
+            }
+            ImageData::from_image(&working_image)
+        };
+
+        // Only the colour modes read RGB; alpha is the same either way
+        let threshold_mode = Self::threshold_mode(config).for_image(&pixels);
+        if config.premultiplied_alpha && threshold_mode != ThresholdMode::Alpha {
+            pixels.unpremultiply();
+        }
+
+        PreparedImage {
+            pixels,
+            threshold_mode,
+            // The truncated size, not the requested factor, is what the grid covers
+            scale: needs_downscaling.then(|| {
+                Vector2::new(
+                    new_width as f32 / width as f32,
+                    new_height as f32 / height as f32,
+                )
+            }),
+            pooled_size: (needs_downscaling && use_max_pool)
+                .then_some((new_width as usize, new_height as usize)),
         }
+    }
 
-        working_image.decompress();
-        working_image.convert(Format::RGBA8);
+    /// Resolve the configured threshold mode, reporting unknown values
+    fn threshold_mode(config: &ContourConfig) -> ThresholdMode {
+        match config.threshold_mode {
+            THRESHOLD_ALPHA => ThresholdMode::Alpha,
+            THRESHOLD_LUMINANCE => ThresholdMode::Luminance,
+            THRESHOLD_CHROMA_KEY => ThresholdMode::ChromaKey {
+                key: config.key_color,
+                tolerance: config.key_tolerance,
+            },
+            unknown => {
+                cutout_error!(
+                    CutoutError::UnknownOption,
+                    "Unknown threshold mode: {}, defaulting to Alpha",
+                    unknown
+                );
+                ThresholdMode::Alpha
+            }
+        }
+    }
 
-        // Create grid from prepared image (single get_data() FFI call internally)
-        let grid = create_grid_from_image(&working_image, alpha_threshold);
+    /// Build the grid, dispatch to the configured algorithm, upscale and simplify the result
+    ///
+    /// Works purely on Rust data, so it is safe to call from worker threads.
+    fn detect_contours(image: &PreparedImage, config: &ContourConfig) -> Detection {
+        let grid = Self::build_grid(image, config);
+        let mut budget = config.point_budget();
 
         // Dispatch to appropriate algorithm
-        let mut contours = match algorithm {
-            0 => moore_neighbour::calculate(&grid),
-            1 => marching_squares::calculate(&grid),
-            _ => {
-                godot_error!(
+        let mut contours = match AlgorithmKind::from_i32(config.algorithm) {
+            Some(AlgorithmKind::MooreNeighbour) => {
+                let connectivity = Self::connectivity(config.trace_connectivity, "trace");
+                moore_neighbour::calculate_with_connectivity(&grid, connectivity, &mut budget)
+            }
+            // There is no single threshold to interpolate against in adaptive mode
+            Some(AlgorithmKind::MarchingSquares)
+                if config.interpolate && !config.adaptive_threshold =>
+            {
+                let threshold = config.low_threshold().unwrap_or(config.alpha_threshold);
+                let threshold = if config.invert {
+                    1.0 - threshold
+                } else {
+                    threshold
+                };
+                match Self::value_grid(image, config.invert) {
+                    Some(values) => marching_squares::calculate_interpolated(
+                        &grid,
+                        &values,
+                        threshold,
+                        &mut budget,
+                    ),
+                    None => marching_squares::calculate_with_budget(&grid, &mut budget),
+                }
+            }
+            Some(AlgorithmKind::MarchingSquares) => {
+                marching_squares::calculate_with_budget(&grid, &mut budget)
+            }
+            None => {
+                cutout_error!(
+                    CutoutError::UnknownOption,
                     "Unknown algorithm: {}, defaulting to Marching Squares",
-                    algorithm
+                    config.algorithm
                 );
-                marching_squares::calculate(&grid)
+                marching_squares::calculate_with_budget(&grid, &mut budget)
             }
         };
 
+        let truncated = budget.is_exhausted();
+        if truncated {
+            cutout_error!(
+                CutoutError::ContourLimit,
+                "Contour detection hit max_contour_points ({}) after {} contours",
+                config.max_contour_points,
+                contours.len()
+            );
+        }
+
+        // Moore Neighbour traces pixel centres, which are always inside the image
+        match config.border_mode {
+            BORDER_PAD => {}
+            BORDER_CLAMP => {
+                contours = marching_squares::clamp_to_border(contours, grid.width(), grid.height());
+            }
+            unknown => {
+                cutout_error!(
+                    CutoutError::UnknownOption,
+                    "Unknown border mode: {}, defaulting to Pad",
+                    unknown
+                );
+            }
+        }
+
         // Upscale contour points if we downscaled
-        if needs_downscaling {
-            let upscale_factor = 1.0 / scale_factor;
+        if let Some(scale) = image.scale {
+            let upscale_x = 1.0 / scale.x;
+            let upscale_y = 1.0 / scale.y;
             for contour in &mut contours {
                 for point in contour {
-                    point.x *= upscale_factor;
-                    point.y *= upscale_factor;
+                    point.x *= upscale_x;
+                    point.y *= upscale_y;
                 }
             }
         }
 
+        if config.simplify {
+            contours = Self::simplify_contours(contours, config);
+        }
+
+        // Clamping and simplification can drop the anchor vertex, so pick it again
+        for contour in &mut contours {
+            rotate_to_anchor(contour);
+        }
+
+        if config.fill_holes {
+            contours = fill_holes(contours);
+        }
+
+        if config.keep_largest {
+            contours = largest_shape(contours, config.keep_largest_holes);
+        }
+
+        Detection {
+            contours,
+            truncated,
+            scale: image.scale.unwrap_or(Vector2::ONE),
+        }
+    }
+
+    /// Simplify every contour with the configured algorithm
+    ///
+    /// Contours that collapse below 3 distinct points are dropped.
+    fn simplify_contours(contours: Vec<Vec<Vector2>>, config: &ContourConfig) -> Vec<Vec<Vector2>> {
+        let simplify: fn(&[Vector2], f32) -> Vec<Vector2> = match config.simplify_algorithm {
+            SIMPLIFY_RDP => rdp::simplify,
+            SIMPLIFY_VISVALINGAM_WHYATT => {
+                |contour, min_area| visvalingam_whyatt::simplify(contour, min_area, 0)
+            }
+            unknown => {
+                cutout_error!(
+                    CutoutError::UnknownOption,
+                    "Unknown simplify algorithm: {}, defaulting to Ramer-Douglas-Peucker",
+                    unknown
+                );
+                rdp::simplify
+            }
+        };
+
         contours
+            .into_iter()
+            .map(|contour| simplify(&contour, config.simplify_epsilon))
+            .filter(|contour| {
+                let closing = usize::from(contour.len() > 1 && contour.first() == contour.last());
+                contour.len() - closing >= 3
+            })
+            .collect()
+    }
+
+    /// Clean up the binary grid with the configured morphological operation
+    fn apply_morphology(grid: Grid, config: &ContourConfig) -> Grid {
+        let radius = config.morphology_radius.max(0) as usize;
+        if radius == 0 {
+            return grid;
+        }
+
+        match config.morphology_mode {
+            MORPHOLOGY_NONE => grid,
+            MORPHOLOGY_OPEN => morphology::open(&grid, radius),
+            MORPHOLOGY_CLOSE => morphology::close(&grid, radius),
+            MORPHOLOGY_ERODE => morphology::erode(&grid, radius),
+            MORPHOLOGY_DILATE => morphology::dilate(&grid, radius),
+            unknown => {
+                cutout_error!(
+                    CutoutError::UnknownOption,
+                    "Unknown morphology mode: {}, skipping clean-up",
+                    unknown
+                );
+                grid
+            }
+        }
+    }
+
+    /// Remove solid blobs below the configured minimum size
+    fn despeckle(grid: Grid, config: &ContourConfig) -> Grid {
+        if config.min_component_pixels <= 1 {
+            return grid;
+        }
+
+        let connectivity = Self::connectivity(config.component_connectivity, "component");
+        remove_small_components(&grid, config.min_component_pixels as usize, connectivity)
+    }
+
+    /// Resolve a configured connectivity (4 or 8), reporting unknown values
+    ///
+    /// `setting` names the setting in the error message.
+    fn connectivity(value: i32, setting: &str) -> Connectivity {
+        match value {
+            CONNECTIVITY_4 => Connectivity::Four,
+            CONNECTIVITY_8 => Connectivity::Eight,
+            unknown => {
+                cutout_error!(
+                    CutoutError::UnknownOption,
+                    "Unknown {} connectivity: {}, defaulting to 8",
+                    setting,
+                    unknown
+                );
+                Connectivity::Eight
+            }
+        }
+    }
+
+    /// Threshold a prepared image into the binary grid the algorithms trace
+    ///
+    /// Applies the adaptive threshold or hysteresis, inversion, max-pooling,
+    /// despeckling and morphology as configured.
+    fn build_grid(image: &PreparedImage, config: &ContourConfig) -> Grid {
+        let mut grid = if config.adaptive_threshold {
+            let connectivity = Self::connectivity(config.component_connectivity, "component");
+            component_otsu_grid(&image.pixels, image.threshold_mode, connectivity)
+        } else {
+            let grid =
+                create_grid_from_data(&image.pixels, config.alpha_threshold, image.threshold_mode);
+            match config.low_threshold() {
+                Some(low) => {
+                    let weak = create_grid_from_data(&image.pixels, low, image.threshold_mode);
+                    let connectivity =
+                        Self::connectivity(config.component_connectivity, "component");
+                    hysteresis(&grid, &weak, connectivity)
+                }
+                None => grid,
+            }
+        };
+        // Invert before pooling so transparent regions survive downscaling the
+        // way solid ones normally do
+        if config.invert {
+            grid = invert_grid(&grid);
+        }
+        if let Some((width, height)) = image.pooled_size {
+            grid = max_pool(&grid, width, height);
+        }
+        // Drop dust first so closing can't merge it into real shapes
+        let grid = Self::despeckle(grid, config);
+        Self::apply_morphology(grid, config)
+    }
+
+    /// Build the continuous grid the threshold was applied to, for interpolation
+    ///
+    /// With `invert` each value becomes one minus itself, to match an inverted
+    /// grid against one minus the threshold. Returns `None` for chroma keying,
+    /// which has no single value to interpolate.
+    fn value_grid(image: &PreparedImage, invert: bool) -> Option<AlphaGrid> {
+        let mut values = match image.threshold_mode {
+            ThresholdMode::Alpha => create_alpha_grid_from_data(&image.pixels),
+            ThresholdMode::Luminance => create_luminance_grid_from_data(&image.pixels),
+            ThresholdMode::ChromaKey { .. } => return None,
+        };
+        if invert {
+            values = values.map(|&value| 1.0 - value);
+        }
+        if let Some((width, height)) = image.pooled_size {
+            values = max_pool(&values, width, height);
+        }
+        Some(values)
     }
 
     /// Convert Vec<Vec<Vector2>> to Godot Array<Variant>
-    fn to_godot_array(contours: Vec<Vec<Vector2>>) -> Array<PackedVector2Array> {
+    pub(crate) fn to_godot_array(contours: Vec<Vec<Vector2>>) -> Array<PackedVector2Array> {
+        // Size the array once and copy each contour in bulk rather than growing
+        // both one element at a time
         let mut result = Array::new();
+        result.resize(contours.len(), &PackedVector2Array::new());
 
-        for contour in contours {
-            let mut packed = PackedVector2Array::new();
-            for point in contour {
-                packed.push(point);
-            }
-            result.push(&packed);
+        for (i, contour) in contours.iter().enumerate() {
+            result.set(i, &PackedVector2Array::from(contour.as_slice()));
         }
 
         result
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    /// A `size`² LA8 image of a disc riddled with square holes
+    fn holed_disc(size: usize) -> ImageData {
+        let center = size as f32 / 2.0;
+        let data = (0..size * size)
+            .flat_map(|i| {
+                let (x, y) = (i % size, i / size);
+                let in_disc = Vector2::new(x as f32, y as f32)
+                    .distance_to(Vector2::new(center, center))
+                    < center * 0.9;
+                let in_hole = (x / 8) % 3 == 1 && (y / 8) % 3 == 1;
+                [255, if in_disc && !in_hole { 255 } else { 0 }]
+            })
+            .collect();
+        ImageData::from_raw(size, size, data, PixelLayout::La8).unwrap()
+    }
+
+    #[test]
+    fn tiled_contours_match_whole_image() {
+        let image = holed_disc(64);
+        let whole = PreparedImage {
+            pixels: image.clone(),
+            threshold_mode: ThresholdMode::Alpha,
+            scale: None,
+            pooled_size: None,
+        };
+        let expected =
+            CutoutContourProcessor::detect_contours(&whole, &ContourConfig::default()).contours;
+        assert!(expected.len() > 1);
+
+        // Strip heights that divide the image, don't, and split every row
+        for strip_height in [16, 7, 1] {
+            let tiled = CutoutContourProcessor::trace_strips(64, 64, strip_height, |region| {
+                let (y, rows) = (region.position.y as usize, region.size.y as usize);
+                let bytes = image.data[y * 64 * 2..(y + rows) * 64 * 2].to_vec();
+                let strip = ImageData::from_raw(64, rows, bytes, PixelLayout::La8).unwrap();
+                create_grid_from_data(&strip, 0.5, ThresholdMode::Alpha)
+            });
+            assert_eq!(tiled, expected, "strip height {}", strip_height);
+        }
+    }
+
+    #[test]
+    fn non_square_downscale_is_undone_per_axis() {
+        // A 40x10 band over rows 2..8, pooled to 10x10 as a max_resolution of
+        // (10, -1) would: x is scaled by 0.25 and y not at all
+        let data = (0..40 * 10)
+            .flat_map(|i| [255, if (2..8).contains(&(i / 40)) { 255 } else { 0 }])
+            .collect();
+        let image = PreparedImage {
+            pixels: ImageData::from_raw(40, 10, data, PixelLayout::La8).unwrap(),
+            threshold_mode: ThresholdMode::Alpha,
+            scale: Some(Vector2::new(0.25, 1.0)),
+            pooled_size: Some((10, 10)),
+        };
+
+        let detection = CutoutContourProcessor::detect_contours(&image, &ContourConfig::default());
+        assert_eq!(detection.contours.len(), 1);
+        assert_eq!(detection.scale, Vector2::new(0.25, 1.0));
+
+        let bounds = calculate_bounds(&detection.contours[0]);
+        // Pad mode closes half a grid cell outside the band on every side
+        assert_eq!(bounds.position, Vector2::new(-2.0, 1.5));
+        assert_eq!(bounds.size, Vector2::new(40.0, 6.0));
+    }
+
+    #[test]
+    #[ignore = "benchmark; run with `cargo test --release -- --ignored --nocapture`"]
+    fn batch_detection_scales_across_cores() {
+        let batch: Vec<(PreparedImage, ContourConfig)> = (0..32)
+            .map(|_| {
+                let image = PreparedImage {
+                    pixels: holed_disc(512),
+                    threshold_mode: ThresholdMode::Alpha,
+                    scale: None,
+                    pooled_size: None,
+                };
+                (image, ContourConfig::default())
+            })
+            .collect();
+
+        let max_threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+        let mut baseline = None;
+        let mut threads = 1;
+        while threads <= max_threads {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap();
+            let start = Instant::now();
+            let detections = pool.install(|| CutoutContourProcessor::detect_prepared(&batch));
+            let elapsed = start.elapsed();
+
+            assert_eq!(detections.len(), batch.len());
+            let baseline = *baseline.get_or_insert(elapsed);
+            println!(
+                "{:>3} threads: {:>8.1?} ({:.2}x)",
+                threads,
+                elapsed,
+                baseline.as_secs_f64() / elapsed.as_secs_f64()
+            );
+            threads *= 2;
+        }
+    }
+}
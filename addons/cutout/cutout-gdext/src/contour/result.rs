@@ -0,0 +1,210 @@
+//! ContourResult - Typed contour detection result for one image
+//!
+//! The untyped batch methods return an `Array` of `Array[PackedVector2Array]`,
+//! which GDScript has to cast at every step. A `ContourResult` holds the same
+//! contours grouped into shapes of one outer ring plus its holes, with typed
+//! accessors for each.
+
+use super::classify::{classify_contours, flatten_shapes, ContourShape};
+use super::processor::CutoutContourProcessor;
+use crate::error::{cutout_error, CutoutError};
+use godot::prelude::*;
+
+/// Contours detected in one image, grouped into shapes.
+///
+/// Shapes are ordered by outer ring area, largest first. Outer rings are CCW
+/// and holes CW.
+#[derive(GodotClass)]
+#[class(no_init, base=RefCounted)]
+pub struct ContourResult {
+    #[base]
+    base: Base<RefCounted>,
+    shapes: Vec<ContourShape>,
+    /// Detection stopped at `max_contour_points`
+    truncated: bool,
+    /// Per-axis scale from image space to the grid detection ran on
+    scale: Vector2,
+}
+
+impl ContourResult {
+    /// Wrap detected contours, as laid out by the contour algorithms
+    ///
+    /// Shapes are grouped by containment rather than winding: Moore Neighbour
+    /// traces single pixels and 1px lines as contours with no area, which have
+    /// no winding to tell outers from holes. `scale` maps the contours back onto
+    /// the downscaled grid, and is ONE if there was none.
+    pub(crate) fn from_contours(
+        contours: Vec<Vec<Vector2>>,
+        truncated: bool,
+        scale: Vector2,
+    ) -> Gd<Self> {
+        let shapes = classify_contours(contours);
+
+        Gd::from_init_fn(|base| Self {
+            base,
+            shapes,
+            truncated,
+            scale,
+        })
+    }
+
+    /// Look up a shape, reporting an out-of-range index
+    fn shape(&self, shape_index: i32) -> Option<&ContourShape> {
+        let shape = usize::try_from(shape_index)
+            .ok()
+            .and_then(|i| self.shapes.get(i));
+
+        if shape.is_none() {
+            cutout_error!(
+                CutoutError::InvalidInput,
+                "Shape index {} out of range (outer_count = {})",
+                shape_index,
+                self.shapes.len()
+            );
+        }
+
+        shape
+    }
+}
+
+#[godot_api]
+impl ContourResult {
+    /// Get every contour in `[outer, holes..., outer, holes...]` order.
+    ///
+    /// # Returns
+    /// The same layout `CutoutContourProcessor.calculate_single` returns
+    #[func]
+    pub fn get_contours(&self) -> Array<PackedVector2Array> {
+        CutoutContourProcessor::to_godot_array(flatten_shapes(self.shapes.clone()))
+    }
+
+    /// Get every contour at the resolution detection ran at.
+    ///
+    /// When `max_resolution` downscaled the image, this is `get_contours()`
+    /// multiplied by `get_scale()`, matching a texture downscaled the same way.
+    /// Otherwise it is the same as `get_contours()`.
+    ///
+    /// # Returns
+    /// The contours in grid space, in `get_contours()` order
+    #[func]
+    pub fn get_grid_contours(&self) -> Array<PackedVector2Array> {
+        let mut contours = flatten_shapes(self.shapes.clone());
+        for point in contours.iter_mut().flatten() {
+            point.x *= self.scale.x;
+            point.y *= self.scale.y;
+        }
+        CutoutContourProcessor::to_godot_array(contours)
+    }
+
+    /// Per-axis scale from image space to grid space.
+    ///
+    /// This is the downscaled size over the original size, computed from the
+    /// truncated pixel dimensions rather than the requested `max_resolution`, so
+    /// it may differ slightly between axes. `Vector2.ONE` if the image was not
+    /// downscaled.
+    #[func]
+    pub fn get_scale(&self) -> Vector2 {
+        self.scale
+    }
+
+    /// Number of contours, outer rings and holes together.
+    #[func]
+    pub fn contour_count(&self) -> i32 {
+        self.shapes
+            .iter()
+            .map(|shape| 1 + shape.holes.len() as i32)
+            .sum()
+    }
+
+    /// Number of shapes, i.e. outer rings.
+    #[func]
+    pub fn outer_count(&self) -> i32 {
+        self.shapes.len() as i32
+    }
+
+    /// Number of holes across all shapes.
+    #[func]
+    pub fn hole_count(&self) -> i32 {
+        self.shapes
+            .iter()
+            .map(|shape| shape.holes.len() as i32)
+            .sum()
+    }
+
+    /// True if no contours were found.
+    #[func]
+    pub fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+
+    /// True if detection hit `max_contour_points` and stopped early.
+    ///
+    /// Only the contours traced before the cap are present; downscale the image
+    /// further or raise the cap and detect again to get the rest.
+    #[func]
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Get the outer ring of a shape.
+    ///
+    /// # Arguments
+    /// * `shape_index` - Shape index (0 to `outer_count() - 1`)
+    ///
+    /// # Returns
+    /// The outer ring (CCW), or an empty array if the index is out of range
+    #[func]
+    pub fn get_outer(&self, shape_index: i32) -> PackedVector2Array {
+        self.shape(shape_index)
+            .map(|shape| PackedVector2Array::from(shape.outer.as_slice()))
+            .unwrap_or_default()
+    }
+
+    /// Get the holes of a shape.
+    ///
+    /// # Arguments
+    /// * `shape_index` - Shape index (0 to `outer_count() - 1`)
+    ///
+    /// # Returns
+    /// The holes (CW), or an empty array if the index is out of range
+    #[func]
+    pub fn get_holes(&self, shape_index: i32) -> Array<PackedVector2Array> {
+        self.shape(shape_index)
+            .map(|shape| CutoutContourProcessor::to_godot_array(shape.holes.clone()))
+            .unwrap_or_default()
+    }
+
+    /// Get a shape as `[outer, holes...]`, ready to pass to the fracture functions.
+    ///
+    /// # Arguments
+    /// * `shape_index` - Shape index (0 to `outer_count() - 1`)
+    ///
+    /// # Returns
+    /// The shape's rings, or an empty array if the index is out of range
+    #[func]
+    pub fn get_shape(&self, shape_index: i32) -> Array<PackedVector2Array> {
+        self.shape(shape_index)
+            .map(|shape| CutoutContourProcessor::to_godot_array(shape.clone().into_rings()))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contour::grid::Grid;
+    use crate::contour::moore_neighbour;
+
+    #[test]
+    fn zero_area_contours_are_their_own_shapes() {
+        // A ring with a one-pixel hole, a lone pixel and a 1px line
+        let rows = ["###..#", "#.#...", "###.##"];
+        let contours = moore_neighbour::calculate(&Grid::from_ascii(&rows));
+        assert_eq!(contours.len(), 4);
+
+        let shapes = classify_contours(contours);
+        assert_eq!(shapes.len(), 3);
+        assert_eq!(shapes[0].holes, vec![vec![Vector2::new(1.0, 1.0)]]);
+        assert!(shapes[1..].iter().all(|shape| shape.holes.is_empty()));
+    }
+}
@@ -1,13 +1,89 @@
 //! ContourSettings resource for configuring contour detection
 //!
 //! This resource allows per-image configuration of contour detection parameters
-//! including algorithm choice, alpha threshold, and maximum resolution.
+//! including algorithm choice, thresholding, and maximum resolution.
 
+use super::budget::PointBudget;
 use godot::prelude::*;
 
 /// Constant representing no resolution limit
 pub const NO_RESOLUTION_LIMIT: Vector2 = Vector2::new(-1.0, -1.0);
 
+/// Constant disabling the lower (hysteresis) alpha threshold
+pub const NO_LOW_THRESHOLD: f32 = -1.0;
+
+/// Constant representing no limit on traced contour points
+pub const NO_POINT_LIMIT: i32 = 0;
+/// Default cap on contour points traced per image
+pub const DEFAULT_MAX_CONTOUR_POINTS: i32 = 1_000_000;
+
+/// Contour detection algorithm
+///
+/// Stored as an `i32` so it round-trips through `ContourSettings.algorithm` and
+/// the `algorithm` arguments of `CutoutContourProcessor`.
+#[derive(GodotConvert, Var, Export, Debug, Clone, Copy, PartialEq, Eq)]
+#[godot(via = i32)]
+pub enum AlgorithmKind {
+    MooreNeighbour = 0,
+    MarchingSquares = 1,
+}
+
+impl AlgorithmKind {
+    /// Map an algorithm int to its kind, or `None` if it's unknown
+    pub fn from_i32(value: i32) -> Option<Self> {
+        match value {
+            0 => Some(Self::MooreNeighbour),
+            1 => Some(Self::MarchingSquares),
+            _ => None,
+        }
+    }
+}
+
+/// Shapes touching the image edge are closed half a pixel outside it
+pub const BORDER_PAD: i32 = 0;
+/// Shapes touching the image edge are closed along the edge itself
+pub const BORDER_CLAMP: i32 = 1;
+
+/// Downscale by resizing the image (bilinear alpha blending)
+pub const DOWNSCALE_RESIZE: i32 = 0;
+/// Downscale the binary grid with a max (OR) reduction over each block
+pub const DOWNSCALE_MAX_POOL: i32 = 1;
+
+/// Pixels are solid where alpha is above the threshold
+pub const THRESHOLD_ALPHA: i32 = 0;
+/// Pixels are solid where luminance is above the threshold
+pub const THRESHOLD_LUMINANCE: i32 = 1;
+/// Pixels matching the key colour are empty, the rest use the alpha threshold
+pub const THRESHOLD_CHROMA_KEY: i32 = 2;
+
+/// No morphological clean-up
+pub const MORPHOLOGY_NONE: i32 = 0;
+/// Erode then dilate, removing isolated speckles
+pub const MORPHOLOGY_OPEN: i32 = 1;
+/// Dilate then erode, filling pinholes
+pub const MORPHOLOGY_CLOSE: i32 = 2;
+/// Shrink solid regions
+pub const MORPHOLOGY_ERODE: i32 = 3;
+/// Grow solid regions
+pub const MORPHOLOGY_DILATE: i32 = 4;
+
+/// Components touching only diagonally are separate
+pub const CONNECTIVITY_4: i32 = 4;
+/// Components touching diagonally are joined
+pub const CONNECTIVITY_8: i32 = 8;
+
+/// Simplify with Ramer-Douglas-Peucker (epsilon is a distance in pixels)
+pub const SIMPLIFY_RDP: i32 = 0;
+/// Simplify with Visvalingam-Whyatt (epsilon is an area in square pixels)
+pub const SIMPLIFY_VISVALINGAM_WHYATT: i32 = 1;
+/// Default simplification tolerance
+pub const DEFAULT_SIMPLIFY_EPSILON: f32 = 1.0;
+
+/// Default chroma-key colour (magenta, a common sprite sheet background)
+pub const DEFAULT_KEY_COLOR: Color = Color::from_rgb(1.0, 0.0, 1.0);
+/// Default chroma-key tolerance
+pub const DEFAULT_KEY_TOLERANCE: f32 = 0.1;
+
 /// Configuration settings for contour detection
 #[derive(GodotClass)]
 #[class(base=Resource)]
@@ -16,19 +92,278 @@ pub struct ContourSettings {
     base: Base<Resource>,
 
     /// Algorithm to use: 0 = Moore Neighbour, 1 = Marching Squares
+    ///
+    /// Unknown values fall back to Marching Squares when set.
+    #[export(enum = (MooreNeighbour = 0, MarchingSquares = 1))]
+    #[var(get, set = set_algorithm)]
+    pub algorithm: i32,
+
+    /// Alpha threshold for determining solid pixels (0.0 - 1.0, clamped when set)
+    ///
+    /// Applies to luminance instead when `threshold_mode` is Luminance.
+    #[export(range = (0.0, 1.0))]
+    #[var(get, set = set_alpha_threshold)]
+    pub alpha_threshold: f32,
+
+    /// Lower threshold for hysteresis (NO_LOW_THRESHOLD = single threshold)
+    ///
+    /// When between 0 and `alpha_threshold`, pixels above `alpha_threshold` seed
+    /// the solid regions, which then grow through connected pixels above this
+    /// lower threshold (connected as set by `component_connectivity`). Gives
+    /// cleaner, unbroken silhouettes on noisy or dithered alpha. Any other value
+    /// keeps the single threshold.
     #[export]
     #[var]
-    pub algorithm: i32,
+    pub alpha_threshold_low: f32,
 
-    /// Alpha threshold for determining solid pixels (0.0 - 1.0)
+    /// Pick a threshold per connected component with Otsu's method
+    ///
+    /// Each region of pixels that aren't fully transparent (connected as set by
+    /// `component_connectivity`) is split on its own alpha, or luminance in
+    /// Luminance mode, so faint glows and soft edges standing apart from the main
+    /// body get a cutoff suited to them. Replaces `alpha_threshold` and
+    /// `alpha_threshold_low`, and `interpolate` falls back to midpoints.
     #[export]
     #[var]
-    pub alpha_threshold: f32,
+    pub adaptive_threshold: bool,
+
+    /// Trace the transparent regions instead of the solid ones
+    ///
+    /// Flips the threshold test, so pixels at or below the threshold are solid.
+    /// Useful for finding cut-out windows in a mask. With the Pad border mode the
+    /// transparent area around a sprite comes out as one shape framing the image,
+    /// with the sprite as its hole.
+    #[export]
+    #[var]
+    pub invert: bool,
 
     /// Maximum resolution for downscaling (NO_RESOLUTION_LIMIT = no limit)
+    ///
+    /// Any non-positive component means no limit on that axis and is stored as -1.
+    /// Each axis is downscaled on its own and contours are scaled back per axis,
+    /// so an image over its limit in x only keeps full detail in y.
+    #[export]
+    #[var(get, set = set_max_resolution)]
+    pub max_resolution: Vector2,
+
+    /// Place Marching Squares edge crossings by interpolating the real alpha (or
+    /// luminance) values instead of at pixel midpoints (ignored by Moore Neighbour
+    /// and in Chroma Key mode)
+    #[export]
+    #[var]
+    pub interpolate: bool,
+
+    /// How Marching Squares closes shapes that run off the image:
+    /// 0 = Pad, 1 = Clamp
+    ///
+    /// Pad treats pixels outside the image as empty, so the contour passes half
+    /// a pixel beyond the edge. Clamp pulls those points onto the edge, keeping
+    /// the polygon inside the texture.
+    #[export(enum = (Pad = 0, Clamp = 1))]
+    #[var]
+    pub border_mode: i32,
+
+    /// How to downscale when over `max_resolution`: 0 = Resize, 1 = Max Pool
+    ///
+    /// Resize blends alpha and can erase features thinner than a block. Max Pool
+    /// thresholds at full resolution first, so any solid pixel keeps its block solid.
+    #[export(enum = (Resize = 0, MaxPool = 1))]
+    #[var]
+    pub downscale_mode: i32,
+
+    /// What decides a solid pixel: 0 = Alpha, 1 = Luminance, 2 = Chroma Key
+    ///
+    /// Luminance traces opaque images such as scans or masks by brightness.
+    /// Chroma Key treats pixels close to `key_color` as empty, for sprites on
+    /// a solid background.
+    #[export(enum = (Alpha = 0, Luminance = 1, ChromaKey = 2))]
+    #[var]
+    pub threshold_mode: i32,
+
+    /// Background colour removed in Chroma Key mode
+    #[export]
+    #[var]
+    pub key_color: Color,
+
+    /// How far (0.0 - 1.0, as normalised RGB distance) a pixel may be from
+    /// `key_color` and still count as background in Chroma Key mode
+    #[export]
+    #[var]
+    pub key_tolerance: f32,
+
+    /// Treat the image's RGB as premultiplied by alpha and divide it back out
+    /// before Luminance or Chroma Key thresholding (ignored in Alpha mode)
+    ///
+    /// Without this, the darkened RGB of premultiplied semi-transparent pixels
+    /// reads as darker than it is.
+    #[export]
+    #[var]
+    pub premultiplied_alpha: bool,
+
+    /// Clean-up applied to the binary grid before tracing:
+    /// 0 = None, 1 = Open, 2 = Close, 3 = Erode, 4 = Dilate
+    ///
+    /// Open removes specks smaller than the kernel, Close fills pinholes. Runs
+    /// at the downscaled resolution when `max_resolution` applies.
+    #[export(enum = (None = 0, Open = 1, Close = 2, Erode = 3, Dilate = 4))]
+    #[var]
+    pub morphology_mode: i32,
+
+    /// Kernel radius in pixels for `morphology_mode` (kernel side = 2 * radius + 1)
+    #[export]
+    #[var]
+    pub morphology_radius: i32,
+
+    /// Solid blobs with fewer pixels than this are removed before tracing (0 = keep all)
+    ///
+    /// Counted at the downscaled resolution when `max_resolution` applies.
+    #[export]
+    #[var]
+    pub min_component_pixels: i32,
+
+    /// Pixel connectivity used to group blobs for `min_component_pixels`: 4 or 8
+    ///
+    /// With 8, pixels touching only at a corner belong to the same blob.
+    #[export(enum = (Four = 4, Eight = 8))]
+    #[var]
+    pub component_connectivity: i32,
+
+    /// Pixel connectivity the Moore Neighbour tracer follows: 4 or 8
+    ///
+    /// With 4, shapes touching only at a corner get separate contours and
+    /// boundaries step N/E/S/W only. Marching Squares ignores this setting.
+    #[export(enum = (Four = 4, Eight = 8))]
+    #[var]
+    pub trace_connectivity: i32,
+
+    /// Simplify each contour after detection, in the same pass
+    #[export]
+    #[var]
+    pub simplify: bool,
+
+    /// Tolerance for `simplify`, measured on the original image (after upscaling)
+    ///
+    /// A distance in pixels for Ramer-Douglas-Peucker, an area in square pixels
+    /// for Visvalingam-Whyatt.
+    #[export]
+    #[var]
+    pub simplify_epsilon: f32,
+
+    /// Simplification algorithm: 0 = Ramer-Douglas-Peucker, 1 = Visvalingam-Whyatt
+    #[export(enum = (RamerDouglasPeucker = 0, VisvalingamWhyatt = 1))]
+    #[var]
+    pub simplify_algorithm: i32,
+
+    /// Return only the contour with the largest area, dropping holes and every
+    /// other blob
+    #[export]
+    #[var]
+    pub keep_largest: bool,
+
+    /// With `keep_largest`, also keep the holes inside the largest contour
     #[export]
     #[var]
+    pub keep_largest_holes: bool,
+
+    /// Return only the outer silhouettes, as if every hole were filled in
+    ///
+    /// Holes are dropped along with any islands inside them. Applied before
+    /// `keep_largest`, so both together give the filled main shape.
+    #[export]
+    #[var]
+    pub fill_holes: bool,
+
+    /// Most contour points traced per image before detection gives up
+    /// (NO_POINT_LIMIT = no limit)
+    ///
+    /// Counts every outer ring and hole together, at the downscaled resolution.
+    /// When the cap is hit, only the contours finished before it are returned and
+    /// `CutoutErrors.ERROR_CONTOUR_LIMIT` is recorded, so callers can lower
+    /// `max_resolution` and retry. Any non-positive value means no limit.
+    #[export]
+    #[var]
+    pub max_contour_points: i32,
+}
+
+/// Plain-data snapshot of `ContourSettings` used by the processing pipeline
+///
+/// Decoupled from the Godot object so it can be built from function arguments
+/// or dictionaries as well as from a settings resource.
+#[derive(Debug, Clone, Copy)]
+pub struct ContourConfig {
+    pub algorithm: i32,
+    pub alpha_threshold: f32,
+    pub alpha_threshold_low: f32,
+    pub adaptive_threshold: bool,
+    pub invert: bool,
     pub max_resolution: Vector2,
+    pub interpolate: bool,
+    pub border_mode: i32,
+    pub downscale_mode: i32,
+    pub threshold_mode: i32,
+    pub key_color: Color,
+    pub key_tolerance: f32,
+    pub premultiplied_alpha: bool,
+    pub morphology_mode: i32,
+    pub morphology_radius: i32,
+    pub min_component_pixels: i32,
+    pub component_connectivity: i32,
+    pub trace_connectivity: i32,
+    pub simplify: bool,
+    pub simplify_epsilon: f32,
+    pub simplify_algorithm: i32,
+    pub keep_largest: bool,
+    pub keep_largest_holes: bool,
+    pub fill_holes: bool,
+    pub max_contour_points: i32,
+}
+
+impl Default for ContourConfig {
+    fn default() -> Self {
+        Self {
+            algorithm: AlgorithmKind::MarchingSquares as i32,
+            alpha_threshold: 0.5,
+            alpha_threshold_low: NO_LOW_THRESHOLD,
+            adaptive_threshold: false,
+            invert: false,
+            max_resolution: NO_RESOLUTION_LIMIT,
+            interpolate: false,
+            border_mode: BORDER_PAD,
+            downscale_mode: DOWNSCALE_RESIZE,
+            threshold_mode: THRESHOLD_ALPHA,
+            key_color: DEFAULT_KEY_COLOR,
+            key_tolerance: DEFAULT_KEY_TOLERANCE,
+            premultiplied_alpha: false,
+            morphology_mode: MORPHOLOGY_NONE,
+            morphology_radius: 1,
+            min_component_pixels: 0,
+            component_connectivity: CONNECTIVITY_8,
+            trace_connectivity: CONNECTIVITY_8,
+            simplify: false,
+            simplify_epsilon: DEFAULT_SIMPLIFY_EPSILON,
+            simplify_algorithm: SIMPLIFY_RDP,
+            keep_largest: false,
+            keep_largest_holes: false,
+            fill_holes: false,
+            max_contour_points: DEFAULT_MAX_CONTOUR_POINTS,
+        }
+    }
+}
+
+impl ContourConfig {
+    /// The lower hysteresis threshold, if it is in use
+    pub fn low_threshold(&self) -> Option<f32> {
+        (self.alpha_threshold_low >= 0.0 && self.alpha_threshold_low < self.alpha_threshold)
+            .then_some(self.alpha_threshold_low)
+    }
+
+    /// A fresh point budget for tracing one image
+    pub fn point_budget(&self) -> PointBudget {
+        match usize::try_from(self.max_contour_points) {
+            Ok(max_points) if max_points > 0 => PointBudget::new(max_points),
+            _ => PointBudget::unlimited(),
+        }
+    }
 }
 
 #[godot_api]
@@ -36,23 +371,158 @@ impl IResource for ContourSettings {
     fn init(base: Base<Resource>) -> Self {
         Self {
             base,
-            algorithm: 1,                        // Default to Marching Squares
-            alpha_threshold: 0.5,                // Default threshold
-            max_resolution: NO_RESOLUTION_LIMIT, // No downscaling by default
+            algorithm: AlgorithmKind::MarchingSquares as i32,
+            alpha_threshold: 0.5,                  // Default threshold
+            alpha_threshold_low: NO_LOW_THRESHOLD, // No hysteresis by default
+            adaptive_threshold: false,             // One global threshold by default
+            invert: false,                         // Trace solid pixels by default
+            max_resolution: NO_RESOLUTION_LIMIT,   // No downscaling by default
+            interpolate: false,                    // Binary midpoint placement by default
+            border_mode: BORDER_PAD,               // Close shapes outside the image by default
+            downscale_mode: DOWNSCALE_RESIZE,      // Resize the image by default
+            threshold_mode: THRESHOLD_ALPHA,       // Threshold on alpha by default
+            key_color: DEFAULT_KEY_COLOR,
+            key_tolerance: DEFAULT_KEY_TOLERANCE,
+            premultiplied_alpha: false,       // Straight alpha by default
+            morphology_mode: MORPHOLOGY_NONE, // No grid clean-up by default
+            morphology_radius: 1,
+            min_component_pixels: 0, // Keep every blob by default
+            component_connectivity: CONNECTIVITY_8,
+            trace_connectivity: CONNECTIVITY_8, // Classic Moore tracing by default
+            simplify: false,                    // Return raw contours by default
+            simplify_epsilon: DEFAULT_SIMPLIFY_EPSILON,
+            simplify_algorithm: SIMPLIFY_RDP,
+            keep_largest: false, // Keep every contour by default
+            keep_largest_holes: false,
+            fill_holes: false,
+            max_contour_points: DEFAULT_MAX_CONTOUR_POINTS, // Guard against runaway traces
         }
     }
 }
 
 #[godot_api]
 impl ContourSettings {
+    #[constant]
+    pub const ALGORITHM_MOORE_NEIGHBOUR: i32 = AlgorithmKind::MooreNeighbour as i32;
+    #[constant]
+    pub const ALGORITHM_MARCHING_SQUARES: i32 = AlgorithmKind::MarchingSquares as i32;
+
     /// Create a new ContourSettings with custom values
+    ///
+    /// Values are stored as given; use `create_validated` to clamp them.
     #[func]
     pub fn create(algorithm: i32, alpha_threshold: f32, max_resolution: Vector2) -> Gd<Self> {
         Gd::from_init_fn(|base| Self {
             base,
             algorithm,
             alpha_threshold,
+            alpha_threshold_low: NO_LOW_THRESHOLD,
+            adaptive_threshold: false,
+            invert: false,
             max_resolution,
+            interpolate: false,
+            border_mode: BORDER_PAD,
+            downscale_mode: DOWNSCALE_RESIZE,
+            threshold_mode: THRESHOLD_ALPHA,
+            key_color: DEFAULT_KEY_COLOR,
+            key_tolerance: DEFAULT_KEY_TOLERANCE,
+            premultiplied_alpha: false,
+            morphology_mode: MORPHOLOGY_NONE,
+            morphology_radius: 1,
+            min_component_pixels: 0,
+            component_connectivity: CONNECTIVITY_8,
+            trace_connectivity: CONNECTIVITY_8,
+            simplify: false,
+            simplify_epsilon: DEFAULT_SIMPLIFY_EPSILON,
+            simplify_algorithm: SIMPLIFY_RDP,
+            keep_largest: false,
+            keep_largest_holes: false,
+            fill_holes: false,
+            max_contour_points: DEFAULT_MAX_CONTOUR_POINTS,
         })
     }
+
+    /// Create a new ContourSettings, validating each value like the setters do
+    #[func]
+    pub fn create_validated(
+        algorithm: i32,
+        alpha_threshold: f32,
+        max_resolution: Vector2,
+    ) -> Gd<Self> {
+        let mut settings = Self::create(algorithm, alpha_threshold, max_resolution);
+        {
+            let mut bound = settings.bind_mut();
+            bound.set_algorithm(algorithm);
+            bound.set_alpha_threshold(alpha_threshold);
+            bound.set_max_resolution(max_resolution);
+        }
+        settings
+    }
+
+    /// Set the algorithm, falling back to Marching Squares with a warning if unknown
+    #[func]
+    pub fn set_algorithm(&mut self, algorithm: i32) {
+        self.algorithm = match AlgorithmKind::from_i32(algorithm) {
+            Some(kind) => kind as i32,
+            None => {
+                godot_warn!(
+                    "Unknown algorithm: {}, defaulting to Marching Squares",
+                    algorithm
+                );
+                AlgorithmKind::MarchingSquares as i32
+            }
+        };
+    }
+
+    /// Set the alpha threshold, clamped to 0.0 - 1.0
+    #[func]
+    pub fn set_alpha_threshold(&mut self, alpha_threshold: f32) {
+        self.alpha_threshold = alpha_threshold.clamp(0.0, 1.0);
+    }
+
+    /// Set the maximum resolution; non-positive components become no limit
+    #[func]
+    pub fn set_max_resolution(&mut self, max_resolution: Vector2) {
+        let limit = |value: f32| {
+            if value > 0.0 {
+                value
+            } else {
+                NO_RESOLUTION_LIMIT.x
+            }
+        };
+        self.max_resolution = Vector2::new(limit(max_resolution.x), limit(max_resolution.y));
+    }
+}
+
+impl ContourSettings {
+    /// Snapshot the settings into a plain config for processing
+    pub fn to_config(&self) -> ContourConfig {
+        ContourConfig {
+            algorithm: self.algorithm,
+            alpha_threshold: self.alpha_threshold,
+            alpha_threshold_low: self.alpha_threshold_low,
+            adaptive_threshold: self.adaptive_threshold,
+            invert: self.invert,
+            max_resolution: self.max_resolution,
+            interpolate: self.interpolate,
+            border_mode: self.border_mode,
+            downscale_mode: self.downscale_mode,
+            threshold_mode: self.threshold_mode,
+            key_color: self.key_color,
+            key_tolerance: self.key_tolerance,
+            premultiplied_alpha: self.premultiplied_alpha,
+            morphology_mode: self.morphology_mode,
+            morphology_radius: self.morphology_radius,
+            min_component_pixels: self.min_component_pixels,
+            component_connectivity: self.component_connectivity,
+            trace_connectivity: self.trace_connectivity,
+            simplify: self.simplify,
+            simplify_epsilon: self.simplify_epsilon,
+            simplify_algorithm: self.simplify_algorithm,
+            keep_largest: self.keep_largest,
+            keep_largest_holes: self.keep_largest_holes,
+            fill_holes: self.fill_holes,
+            max_contour_points: self.max_contour_points,
+        }
+    }
 }
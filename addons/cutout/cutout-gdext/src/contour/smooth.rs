@@ -0,0 +1,64 @@
+//! Chaikin corner-cutting smoothing for contours
+//!
+//! Marching Squares contours step along pixel edges at 45° and 90° angles. Each
+//! Chaikin iteration replaces every edge `p -> q` with the two points at 1/4 and
+//! 3/4 along it, which cuts off every corner and doubles the vertex count. A few
+//! iterations converge towards a smooth quadratic B-spline through the contour.
+//!
+//! Smoothing adds many vertices, so it pairs naturally with a simplify pass
+//! afterwards (smooth, then simplify).
+
+use godot::prelude::*;
+
+/// Largest accepted iteration count; each iteration doubles the vertex count
+pub const MAX_SMOOTH_ITERATIONS: i32 = 8;
+
+/// Smooth a closed polygon with Chaikin's corner-cutting algorithm.
+///
+/// The polygon is treated as closed, so the smoothing wraps around. If the input
+/// is explicitly closed (last point equal to the first), the output is closed too.
+///
+/// # Arguments
+/// * `polygon` - Input polygon vertices
+/// * `iterations` - Number of subdivision passes, clamped to `0..=MAX_SMOOTH_ITERATIONS`
+///
+/// # Returns
+/// The smoothed polygon, or the input unchanged if it has fewer than 3 points
+pub fn smooth(polygon: &[Vector2], iterations: i32) -> Vec<Vector2> {
+    let is_closed = polygon.len() > 1 && polygon.first() == polygon.last();
+    let ring = if is_closed {
+        &polygon[..polygon.len() - 1]
+    } else {
+        polygon
+    };
+
+    if ring.len() < 3 {
+        return polygon.to_vec();
+    }
+
+    let mut smoothed = ring.to_vec();
+    for _ in 0..iterations.clamp(0, MAX_SMOOTH_ITERATIONS) {
+        smoothed = chaikin_step(&smoothed);
+    }
+
+    if is_closed {
+        smoothed.push(smoothed[0]);
+    }
+
+    smoothed
+}
+
+/// Run a single corner-cutting pass over a closed ring.
+fn chaikin_step(ring: &[Vector2]) -> Vec<Vector2> {
+    let n = ring.len();
+    let mut result = Vec::with_capacity(n * 2);
+
+    for i in 0..n {
+        let p = ring[i];
+        let q = ring[(i + 1) % n];
+        result.push(p.lerp(q, 0.25));
+        result.push(p.lerp(q, 0.75));
+    }
+
+    result
+}
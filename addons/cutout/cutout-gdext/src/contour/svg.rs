@@ -0,0 +1,263 @@
+//! SVG path import and export
+//!
+//! Export writes contours as a single path `d` attribute: one `M x y L ... Z`
+//! subpath per ring. Rings go through `classify_contours` first, so outer rings
+//! and holes have opposite winding and render correctly with the default
+//! `nonzero` fill rule.
+//!
+//! Import parses the common subset of path commands (M/L/H/V/C/Q/Z, absolute
+//! and relative), flattens curves into line segments and classifies the
+//! resulting rings the same way. Godot and SVG are both y-down, so coordinates
+//! are used unchanged in both directions.
+
+use super::classify::classify_contours;
+use crate::fracture::geometry::calculate_bounds;
+use godot::prelude::*;
+use std::fmt::Write;
+
+/// Build an SVG path `d` string from a flat contour list.
+///
+/// Rings with fewer than 3 points are skipped.
+pub fn contours_to_path(contours: Vec<Vec<Vector2>>) -> String {
+    let contours: Vec<Vec<Vector2>> = contours.into_iter().filter(|c| c.len() >= 3).collect();
+    let mut path = String::new();
+
+    for shape in classify_contours(contours) {
+        for ring in shape.into_rings() {
+            if !path.is_empty() {
+                path.push(' ');
+            }
+            write_subpath(&mut path, &ring);
+        }
+    }
+
+    path
+}
+
+/// Wrap the contours in a standalone `<svg>` document.
+///
+/// The viewBox is the bounding box of all contour points.
+pub fn contours_to_document(contours: Vec<Vec<Vector2>>) -> String {
+    let points: Vec<Vector2> = contours.iter().flatten().copied().collect();
+    let bounds = calculate_bounds(&points);
+    let path = contours_to_path(contours);
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\">\n  <path d=\"{}\" fill-rule=\"nonzero\"/>\n</svg>\n",
+        bounds.position.x, bounds.position.y, bounds.size.x, bounds.size.y, path
+    )
+}
+
+/// Append one closed `M x y L x y ... Z` subpath.
+fn write_subpath(path: &mut String, ring: &[Vector2]) {
+    for (i, point) in ring.iter().enumerate() {
+        let command = if i == 0 { 'M' } else { 'L' };
+        // Writing to a String can't fail
+        let _ = write!(path, "{} {} {} ", command, point.x, point.y);
+    }
+    path.push('Z');
+}
+
+/// Parse an SVG path `d` string into closed polygons.
+///
+/// Every subpath is treated as closed, whether or not it ends with `Z`. Bezier
+/// curves are flattened into `curve_resolution` line segments each. Unsupported
+/// commands and commands with missing arguments are skipped along with their
+/// arguments.
+///
+/// # Returns
+/// Rings grouped as `[outer, holes...]` per shape, largest shape first, with
+/// outers CCW and holes CW. Subpaths with fewer than 3 distinct points are dropped.
+pub fn path_to_polygons(d: &str, curve_resolution: i32) -> Vec<Vec<Vector2>> {
+    let rings = parse_path(d, curve_resolution.max(1) as usize);
+    classify_contours(rings)
+        .into_iter()
+        .flat_map(|shape| shape.into_rings())
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token {
+    Command(char),
+    Number(f32),
+}
+
+/// Split path data into command letters and numbers.
+///
+/// Handles the compact forms SVG allows, such as `10-5` (two numbers) and
+/// `1.5.5` (1.5 and 0.5). Separators and unrecognised characters are skipped.
+fn tokenize(d: &str) -> Vec<Token> {
+    let bytes = d.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+
+        if c.is_ascii_alphabetic() && c != b'e' && c != b'E' {
+            tokens.push(Token::Command(c as char));
+            i += 1;
+            continue;
+        }
+
+        if !(c.is_ascii_digit() || c == b'-' || c == b'+' || c == b'.') {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        if c == b'-' || c == b'+' {
+            i += 1;
+        }
+        let mut seen_dot = false;
+        while i < bytes.len() && (bytes[i].is_ascii_digit() || (bytes[i] == b'.' && !seen_dot)) {
+            seen_dot |= bytes[i] == b'.';
+            i += 1;
+        }
+        // Exponent, only if digits actually follow
+        if i < bytes.len() && (bytes[i] == b'e' || bytes[i] == b'E') {
+            let mut j = i + 1;
+            if j < bytes.len() && (bytes[j] == b'-' || bytes[j] == b'+') {
+                j += 1;
+            }
+            if j < bytes.len() && bytes[j].is_ascii_digit() {
+                while j < bytes.len() && bytes[j].is_ascii_digit() {
+                    j += 1;
+                }
+                i = j;
+            }
+        }
+
+        match d[start..i].parse::<f32>() {
+            Ok(value) if value.is_finite() => tokens.push(Token::Number(value)),
+            // Lone sign or dot; make sure we always advance
+            _ => i = i.max(start + 1),
+        }
+    }
+
+    tokens
+}
+
+/// Number of arguments each supported command consumes per repetition
+fn command_arity(command: char) -> Option<usize> {
+    match command {
+        'M' | 'L' => Some(2),
+        'H' | 'V' => Some(1),
+        'C' => Some(6),
+        'Q' => Some(4),
+        'Z' => Some(0),
+        _ => None,
+    }
+}
+
+/// Walk the tokens and collect every subpath as a ring of points.
+fn parse_path(d: &str, curve_resolution: usize) -> Vec<Vec<Vector2>> {
+    let tokens = tokenize(d);
+    let mut rings = Vec::new();
+    let mut ring: Vec<Vector2> = Vec::new();
+    let mut current = Vector2::ZERO;
+    let mut start = Vector2::ZERO;
+    let mut i = 0;
+
+    while i < tokens.len() {
+        // Stray numbers without a command are skipped
+        let Token::Command(letter) = tokens[i] else {
+            i += 1;
+            continue;
+        };
+        i += 1;
+
+        let relative = letter.is_ascii_lowercase();
+        let mut command = letter.to_ascii_uppercase();
+
+        let args_end = tokens[i..]
+            .iter()
+            .position(|t| matches!(t, Token::Command(_)))
+            .map_or(tokens.len(), |p| i + p);
+        let args: Vec<f32> = tokens[i..args_end]
+            .iter()
+            .map(|t| match t {
+                Token::Number(n) => *n,
+                Token::Command(_) => unreachable!(),
+            })
+            .collect();
+        i = args_end;
+
+        let Some(arity) = command_arity(command) else {
+            continue;
+        };
+
+        if arity == 0 {
+            finish_ring(&mut rings, &mut ring);
+            current = start;
+            continue;
+        }
+
+        // Repeated argument groups repeat the command; trailing partial groups are dropped
+        for group in args.chunks_exact(arity) {
+            let origin = if relative { current } else { Vector2::ZERO };
+            let point = |k: usize| origin + Vector2::new(group[k], group[k + 1]);
+
+            if command == 'M' {
+                finish_ring(&mut rings, &mut ring);
+                current = point(0);
+                start = current;
+                ring.push(current);
+                // Further pairs after a moveto are implicit linetos
+                command = 'L';
+                continue;
+            }
+
+            // Drawing after a closepath continues from the subpath start
+            if ring.is_empty() {
+                ring.push(current);
+            }
+
+            match command {
+                'L' => ring.push(point(0)),
+                'H' => ring.push(Vector2::new(group[0] + origin.x, current.y)),
+                'V' => ring.push(Vector2::new(current.x, group[0] + origin.y)),
+                'C' => {
+                    let (c1, c2, end) = (point(0), point(2), point(4));
+                    ring.extend((1..=curve_resolution).map(|k| {
+                        cubic_point(current, c1, c2, end, k as f32 / curve_resolution as f32)
+                    }));
+                }
+                'Q' => {
+                    let (c, end) = (point(0), point(2));
+                    ring.extend((1..=curve_resolution).map(|k| {
+                        quadratic_point(current, c, end, k as f32 / curve_resolution as f32)
+                    }));
+                }
+                _ => {}
+            }
+
+            current = *ring.last().unwrap();
+        }
+    }
+
+    finish_ring(&mut rings, &mut ring);
+    rings
+}
+
+/// Move a finished subpath into `rings`, dropping repeated points and degenerate rings.
+fn finish_ring(rings: &mut Vec<Vec<Vector2>>, ring: &mut Vec<Vector2>) {
+    let mut points = std::mem::take(ring);
+    points.dedup();
+    if points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    if points.len() >= 3 {
+        rings.push(points);
+    }
+}
+
+fn quadratic_point(p0: Vector2, p1: Vector2, p2: Vector2, t: f32) -> Vector2 {
+    let u = 1.0 - t;
+    p0 * (u * u) + p1 * (2.0 * u * t) + p2 * (t * t)
+}
+
+fn cubic_point(p0: Vector2, p1: Vector2, p2: Vector2, p3: Vector2, t: f32) -> Vector2 {
+    let u = 1.0 - t;
+    p0 * (u * u * u) + p1 * (3.0 * u * u * t) + p2 * (3.0 * u * t * t) + p3 * (t * t * t)
+}
@@ -0,0 +1,207 @@
+//! CutoutErrors - Observable errors for the processor classes
+//!
+//! Processor methods report failures to the Godot console and fall back to a
+//! safe result (empty or unchanged data). They also record the failure here,
+//! so GDScript can check for it in code:
+//!
+//! ```gdscript
+//! CutoutErrors.clear_error()
+//! var fragments = CutoutDestructionProcessor.fracture_voronoi(polygons, seeds)
+//! assert(CutoutErrors.last_error() == CutoutErrors.ERROR_OK)
+//! ```
+//!
+//! The last error is shared by all processors and threads, and stays set until
+//! `clear_error()` is called.
+//!
+//! Godot's logger must only be called from the main thread, so work spread over
+//! the rayon pool runs inside `capture` and hands its errors back to `report`
+//! once the workers have joined.
+
+use godot::prelude::*;
+use std::cell::RefCell;
+use std::sync::Mutex;
+
+/// Kind of failure recorded by a processor method
+///
+/// Stored as an `i32` so it can be returned to GDScript and compared against
+/// the `CutoutErrors.ERROR_*` constants.
+#[derive(GodotConvert, Var, Export, Debug, Clone, Copy, PartialEq, Eq)]
+#[godot(via = i32)]
+pub enum CutoutError {
+    /// No error since the last `clear_error()`
+    Ok = 0,
+    /// Two arrays that must be the same length aren't (e.g. images and settings)
+    MismatchedCounts = 1,
+    /// An unknown algorithm, mode or other enum-like setting; a default was used
+    UnknownOption = 2,
+    /// An argument can't be used (wrong type, zero area, ...)
+    InvalidInput = 3,
+    /// Delaunay triangulation of the seed points failed
+    TriangulationFailed = 4,
+    /// A fracture produced no fragments; the input was returned unchanged
+    NoFragments = 5,
+    /// A Clipper2 polygon operation failed
+    ClipperFailed = 6,
+    /// Contour detection hit `max_contour_points`; only the contours traced
+    /// before it are returned
+    ContourLimit = 7,
+}
+
+static LAST_ERROR: Mutex<(CutoutError, String)> = Mutex::new((CutoutError::Ok, String::new()));
+
+/// An error raised inside `capture`, waiting to be reported
+pub type CapturedError = (CutoutError, String);
+
+thread_local! {
+    /// Errors raised on this thread while a `capture` is running
+    static CAPTURED: RefCell<Option<Vec<CapturedError>>> = const { RefCell::new(None) };
+}
+
+/// Record an error as the last error
+pub fn record(error: CutoutError, message: String) {
+    // A poisoned lock only means another thread panicked mid-write; the data is still usable
+    let mut last = LAST_ERROR.lock().unwrap_or_else(|e| e.into_inner());
+    *last = (error, message);
+}
+
+/// The last recorded error and its message
+pub fn last() -> (CutoutError, String) {
+    LAST_ERROR.lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Reset the last error to `CutoutError::Ok`
+pub fn clear() {
+    record(CutoutError::Ok, String::new());
+}
+
+/// Print an error to the Godot console and record it, or hold it back if a
+/// `capture` is running on this thread
+pub fn emit(error: CutoutError, message: String) {
+    let uncaptured = CAPTURED.with(|captured| match captured.borrow_mut().as_mut() {
+        Some(errors) => {
+            errors.push((error, message));
+            None
+        }
+        None => Some(message),
+    });
+
+    if let Some(message) = uncaptured {
+        godot_error!("{}", message);
+        record(error, message);
+    }
+}
+
+/// Run `f`, holding back the errors it raises instead of printing them
+///
+/// For work on rayon worker threads, which must not call into Godot. Pass the
+/// errors to `report` on the calling thread afterwards.
+///
+/// # Returns
+/// The result of `f` and the errors it raised, in order
+pub fn capture<R>(f: impl FnOnce() -> R) -> (R, Vec<CapturedError>) {
+    // Keep any outer capture's errors; a worker waiting on a nested join can
+    // pick up another captured job on the same thread
+    let outer = CAPTURED.with(|captured| captured.replace(Some(Vec::new())));
+    let result = f();
+    let errors = CAPTURED.with(|captured| captured.replace(outer));
+    (result, errors.unwrap_or_default())
+}
+
+/// Print and record errors held back by `capture`, in order
+pub fn report(errors: Vec<CapturedError>) {
+    for (error, message) in errors {
+        emit(error, message);
+    }
+}
+
+/// Print an error to the Godot console and record it as the last error.
+///
+/// Inside `capture` the error is held back for the caller to report instead.
+///
+/// ```ignore
+/// cutout_error!(CutoutError::InvalidInput, "Island {} is not an array", i);
+/// ```
+macro_rules! cutout_error {
+    ($error:expr, $($arg:tt)*) => {{
+        $crate::error::emit($error, format!($($arg)*));
+    }};
+}
+
+pub(crate) use cutout_error;
+
+/// Stateless access to the last processor error.
+///
+/// All methods can be called directly without instantiation.
+#[derive(GodotClass)]
+#[class(no_init)]
+pub struct CutoutErrors;
+
+#[godot_api]
+impl CutoutErrors {
+    #[constant]
+    pub const ERROR_OK: i32 = CutoutError::Ok as i32;
+    #[constant]
+    pub const ERROR_MISMATCHED_COUNTS: i32 = CutoutError::MismatchedCounts as i32;
+    #[constant]
+    pub const ERROR_UNKNOWN_OPTION: i32 = CutoutError::UnknownOption as i32;
+    #[constant]
+    pub const ERROR_INVALID_INPUT: i32 = CutoutError::InvalidInput as i32;
+    #[constant]
+    pub const ERROR_TRIANGULATION_FAILED: i32 = CutoutError::TriangulationFailed as i32;
+    #[constant]
+    pub const ERROR_NO_FRAGMENTS: i32 = CutoutError::NoFragments as i32;
+    #[constant]
+    pub const ERROR_CLIPPER_FAILED: i32 = CutoutError::ClipperFailed as i32;
+    #[constant]
+    pub const ERROR_CONTOUR_LIMIT: i32 = CutoutError::ContourLimit as i32;
+
+    /// Get the last error recorded by any processor method.
+    ///
+    /// # Returns
+    /// One of the `ERROR_*` constants, `ERROR_OK` if nothing failed since the
+    /// last `clear_error()`
+    #[func]
+    pub fn last_error() -> CutoutError {
+        last().0
+    }
+
+    /// Get the console message of the last error.
+    ///
+    /// # Returns
+    /// The message, or an empty string if there is no error
+    #[func]
+    pub fn last_error_message() -> GString {
+        GString::from(last().1.as_str())
+    }
+
+    /// Reset the last error to `ERROR_OK`.
+    #[func]
+    pub fn clear_error() {
+        clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn captured_errors_are_held_back_in_order() {
+        let (value, errors) = capture(|| {
+            cutout_error!(CutoutError::InvalidInput, "first {}", 1);
+            let ((), inner) = capture(|| cutout_error!(CutoutError::NoFragments, "inner"));
+            assert_eq!(inner, vec![(CutoutError::NoFragments, "inner".to_string())]);
+            cutout_error!(CutoutError::ClipperFailed, "second");
+            42
+        });
+
+        assert_eq!(value, 42);
+        assert_eq!(
+            errors,
+            vec![
+                (CutoutError::InvalidInput, "first 1".to_string()),
+                (CutoutError::ClipperFailed, "second".to_string()),
+            ]
+        );
+    }
+}
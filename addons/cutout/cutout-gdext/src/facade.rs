@@ -0,0 +1,218 @@
+//! CutoutFacade - The `Cutout` engine singleton
+//!
+//! The processor classes are `no_init` and only have static methods, which
+//! GDScript users find unfamiliar: they can't be `.new()`'d, and the static
+//! call syntax is easy to miss. This singleton forwards the most common
+//! operations of every subsystem, so they read like any other engine singleton:
+//!
+//! ```gdscript
+//! var contours = Cutout.calculate_single(image, Cutout.ALGORITHM_MARCHING_SQUARES, 0.5, Vector2(-1, -1))
+//! var fragments = Cutout.fracture_voronoi(contours, seeds)
+//! ```
+//!
+//! It holds no state. Anything not forwarded here is still available on the
+//! processor classes themselves.
+
+use crate::contour::{AlgorithmKind, ContourSettings, CutoutContourProcessor};
+use crate::fracture::CutoutDestructionProcessor;
+use crate::geometry::CutoutGeometry;
+use crate::simplify::CutoutSimplifyProcessor;
+use godot::classes::Image;
+use godot::prelude::*;
+
+/// Name the singleton is registered under
+pub const SINGLETON_NAME: &str = "Cutout";
+
+/// Engine singleton forwarding to the stateless processors.
+///
+/// Registered as `Cutout` when the extension loads; don't instantiate it.
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+pub struct CutoutFacade;
+
+#[godot_api]
+impl CutoutFacade {
+    #[constant]
+    pub const ALGORITHM_MOORE_NEIGHBOUR: i32 = AlgorithmKind::MooreNeighbour as i32;
+    #[constant]
+    pub const ALGORITHM_MARCHING_SQUARES: i32 = AlgorithmKind::MarchingSquares as i32;
+
+    // ========================================================================
+    // Contours
+    // ========================================================================
+
+    /// See `CutoutContourProcessor.calculate_single`.
+    #[func]
+    pub fn calculate_single(
+        &self,
+        image: Gd<Image>,
+        algorithm: i32,
+        alpha_threshold: f32,
+        max_resolution: Vector2,
+    ) -> Array<PackedVector2Array> {
+        CutoutContourProcessor::calculate_single(image, algorithm, alpha_threshold, max_resolution)
+    }
+
+    /// See `CutoutContourProcessor.calculate_batch`.
+    #[func]
+    pub fn calculate_batch(
+        &self,
+        images: Array<Gd<Image>>,
+        settings: Array<Gd<ContourSettings>>,
+    ) -> Array<Variant> {
+        CutoutContourProcessor::calculate_batch(images, settings)
+    }
+
+    /// See `CutoutContourProcessor.classify_contours`.
+    #[func]
+    pub fn classify_contours(&self, contours: Array<PackedVector2Array>) -> Array<Variant> {
+        CutoutContourProcessor::classify_contours(contours)
+    }
+
+    /// See `CutoutContourProcessor.suggest_alpha_threshold`.
+    #[func]
+    pub fn suggest_alpha_threshold(&self, image: Gd<Image>) -> f32 {
+        CutoutContourProcessor::suggest_alpha_threshold(image)
+    }
+
+    // ========================================================================
+    // Simplification
+    // ========================================================================
+
+    /// See `CutoutSimplifyProcessor.simplify_rdp`.
+    #[func]
+    pub fn simplify_rdp(&self, polygon: PackedVector2Array, epsilon: f32) -> PackedVector2Array {
+        CutoutSimplifyProcessor::simplify_rdp(polygon, epsilon)
+    }
+
+    /// See `CutoutSimplifyProcessor.simplify_vw`.
+    #[func]
+    pub fn simplify_vw(
+        &self,
+        polygon: PackedVector2Array,
+        min_area: f32,
+        target_points: i32,
+    ) -> PackedVector2Array {
+        CutoutSimplifyProcessor::simplify_vw(polygon, min_area, target_points)
+    }
+
+    // ========================================================================
+    // Fracture
+    // ========================================================================
+
+    /// See `CutoutDestructionProcessor.fracture_voronoi`.
+    #[func]
+    pub fn fracture_voronoi(
+        &self,
+        polygons: Array<PackedVector2Array>,
+        seed_points: PackedVector2Array,
+    ) -> Array<PackedVector2Array> {
+        CutoutDestructionProcessor::fracture_voronoi(polygons, seed_points)
+    }
+
+    /// See `CutoutDestructionProcessor.fracture_slice`.
+    #[func]
+    pub fn fracture_slice(
+        &self,
+        polygons: Array<PackedVector2Array>,
+        line_start: Vector2,
+        line_end: Vector2,
+    ) -> Array<PackedVector2Array> {
+        CutoutDestructionProcessor::fracture_slice(polygons, line_start, line_end)
+    }
+
+    /// See `CutoutDestructionProcessor.fracture_slices_radial`.
+    #[func]
+    pub fn fracture_slices_radial(
+        &self,
+        polygons: Array<PackedVector2Array>,
+        seed: i64,
+        slice_count: i32,
+        origin: Vector2,
+        radial_randomness: f32,
+    ) -> Array<PackedVector2Array> {
+        CutoutDestructionProcessor::fracture_slices_radial(
+            polygons,
+            seed,
+            slice_count,
+            origin,
+            radial_randomness,
+        )
+    }
+
+    /// See `CutoutDestructionProcessor.is_fractured`.
+    #[func]
+    pub fn is_fractured(
+        &self,
+        polygons: Array<PackedVector2Array>,
+        fragments: Array<PackedVector2Array>,
+    ) -> bool {
+        CutoutDestructionProcessor::is_fractured(polygons, fragments)
+    }
+
+    /// See `CutoutDestructionProcessor.generate_random_seeds`.
+    #[func]
+    pub fn generate_random_seeds(
+        &self,
+        polygon: PackedVector2Array,
+        fragment_count: i32,
+        min_cell_distance: f32,
+        edge_padding: f32,
+        seed: i64,
+    ) -> PackedVector2Array {
+        CutoutDestructionProcessor::generate_random_seeds(
+            polygon,
+            fragment_count,
+            min_cell_distance,
+            edge_padding,
+            seed,
+        )
+    }
+
+    /// See `CutoutDestructionProcessor.generate_poisson_seeds`.
+    #[func]
+    pub fn generate_poisson_seeds(
+        &self,
+        polygon: PackedVector2Array,
+        fragment_count: i32,
+        min_cell_distance: f32,
+        edge_padding: f32,
+        poisson_attempts: i32,
+        seed: i64,
+    ) -> PackedVector2Array {
+        CutoutDestructionProcessor::generate_poisson_seeds(
+            polygon,
+            fragment_count,
+            min_cell_distance,
+            edge_padding,
+            poisson_attempts,
+            seed,
+        )
+    }
+
+    // ========================================================================
+    // Geometry
+    // ========================================================================
+
+    /// See `CutoutGeometry.triangulate`.
+    #[func]
+    pub fn triangulate(
+        &self,
+        outer: PackedVector2Array,
+        holes: Array<PackedVector2Array>,
+    ) -> PackedInt32Array {
+        CutoutGeometry::triangulate(outer, holes)
+    }
+
+    /// See `CutoutGeometry.decompose_convex`.
+    #[func]
+    pub fn decompose_convex(&self, polygon: PackedVector2Array) -> Array<PackedVector2Array> {
+        CutoutGeometry::decompose_convex(polygon)
+    }
+
+    /// See `CutoutGeometry.convex_hull`.
+    #[func]
+    pub fn convex_hull(&self, points: PackedVector2Array) -> PackedVector2Array {
+        CutoutGeometry::convex_hull(points)
+    }
+}
@@ -3,7 +3,8 @@
 //! These utilities convert between Godot's Vector2 format and Clipper2's format,
 //! and provide common polygon clipping operations.
 
-use clipper2::{difference, intersect, FillRule, Paths};
+use crate::error::{cutout_error, CutoutError};
+use clipper2::{difference, inflate, intersect, union, xor, EndType, FillRule, JoinType, Paths};
 use godot::prelude::*;
 
 /// Convert a Godot polygon to Clipper2 format
@@ -23,6 +24,24 @@ pub fn from_clipper_paths(paths: Paths) -> Vec<Vec<Vector2>> {
         .collect()
 }
 
+/// Miter limit used for offsetting (Clipper2's default)
+const OFFSET_MITER_LIMIT: f64 = 2.0;
+
+/// Offset a closed polygon outward (positive delta) or inward (negative delta) using Clipper2
+///
+/// Insetting can split a thin polygon into several pieces or remove it entirely.
+pub fn clipper2_offset(polygon: &[Vector2], delta: f32, join_type: JoinType) -> Vec<Vec<Vector2>> {
+    let paths: Vec<Vec<(f64, f64)>> = vec![to_clipper_path(polygon)];
+
+    from_clipper_paths(inflate(
+        paths,
+        delta as f64,
+        join_type,
+        EndType::Polygon,
+        OFFSET_MITER_LIMIT,
+    ))
+}
+
 /// Compute the intersection of two polygons using Clipper2
 pub fn clipper2_intersect(subject: &[Vector2], clip: &[Vector2]) -> Vec<Vec<Vector2>> {
     let subject_paths: Vec<Vec<(f64, f64)>> = vec![to_clipper_path(subject)];
@@ -31,7 +50,11 @@ pub fn clipper2_intersect(subject: &[Vector2], clip: &[Vector2]) -> Vec<Vec<Vect
     match intersect(subject_paths, clip_paths, FillRule::NonZero) {
         Ok(result) => from_clipper_paths(result),
         Err(e) => {
-            godot_error!("Clipper2 intersect operation failed: {:?}", e);
+            cutout_error!(
+                CutoutError::ClipperFailed,
+                "Clipper2 intersect operation failed: {:?}",
+                e
+            );
             Vec::new()  // Return empty on error (no intersection)
         }
     }
@@ -45,8 +68,94 @@ pub fn clipper2_difference(subject: &[Vector2], clip: &[Vector2]) -> Vec<Vec<Vec
     match difference(subject_paths, clip_paths, FillRule::NonZero) {
         Ok(result) => from_clipper_paths(result),
         Err(e) => {
-            godot_error!("Clipper2 difference operation failed: {:?}", e);
+            cutout_error!(
+                CutoutError::ClipperFailed,
+                "Clipper2 difference operation failed: {:?}",
+                e
+            );
             vec![subject.to_vec()]  // On error, return original polygon unchanged
         }
     }
-}
\ No newline at end of file
+}
+
+/// Compute the union of two polygons using Clipper2
+pub fn clipper2_union(subject: &[Vector2], clip: &[Vector2]) -> Vec<Vec<Vector2>> {
+    let subject_paths: Vec<Vec<(f64, f64)>> = vec![to_clipper_path(subject)];
+    let clip_paths: Vec<Vec<(f64, f64)>> = vec![to_clipper_path(clip)];
+
+    match union(subject_paths, clip_paths, FillRule::NonZero) {
+        Ok(result) => from_clipper_paths(result),
+        Err(e) => {
+            cutout_error!(
+                CutoutError::ClipperFailed,
+                "Clipper2 union operation failed: {:?}",
+                e
+            );
+            vec![subject.to_vec(), clip.to_vec()]  // On error, return both polygons unchanged
+        }
+    }
+}
+
+/// Boolean operation applied by `clipper2_boolean`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BooleanOp {
+    Union,
+    Intersection,
+    Difference,
+    Xor,
+}
+
+/// Apply a boolean operation to two sets of polygons using Clipper2
+///
+/// Each set may hold several paths, combined with `fill_rule`. Holes in the
+/// result are returned as separate rings wound opposite to their outer ring.
+pub fn clipper2_boolean(
+    op: BooleanOp,
+    subject: &[Vec<Vector2>],
+    clip: &[Vec<Vector2>],
+    fill_rule: FillRule,
+) -> Vec<Vec<Vector2>> {
+    let subject_paths: Vec<Vec<(f64, f64)>> = subject.iter().map(|p| to_clipper_path(p)).collect();
+    let clip_paths: Vec<Vec<(f64, f64)>> = clip.iter().map(|p| to_clipper_path(p)).collect();
+
+    let result = match op {
+        BooleanOp::Union => union(subject_paths, clip_paths, fill_rule),
+        BooleanOp::Intersection => intersect(subject_paths, clip_paths, fill_rule),
+        BooleanOp::Difference => difference(subject_paths, clip_paths, fill_rule),
+        BooleanOp::Xor => xor(subject_paths, clip_paths, fill_rule),
+    };
+
+    match result {
+        Ok(result) => from_clipper_paths(result),
+        Err(e) => {
+            cutout_error!(
+                CutoutError::ClipperFailed,
+                "Clipper2 {:?} operation failed: {:?}",
+                op,
+                e
+            );
+            Vec::new() // Return empty on error
+        }
+    }
+}
+
+/// Union a polygon with itself using Clipper2, resolving self-intersections
+///
+/// Returns simple rings: outers for every region the polygon winds around, and
+/// holes for any gaps it encloses.
+pub fn clipper2_self_union(polygon: &[Vector2]) -> Vec<Vec<Vector2>> {
+    let subject_paths: Vec<Vec<(f64, f64)>> = vec![to_clipper_path(polygon)];
+    let clip_paths: Vec<Vec<(f64, f64)>> = Vec::new();
+
+    match union(subject_paths, clip_paths, FillRule::NonZero) {
+        Ok(result) => from_clipper_paths(result),
+        Err(e) => {
+            cutout_error!(
+                CutoutError::ClipperFailed,
+                "Clipper2 self-union operation failed: {:?}",
+                e
+            );
+            vec![polygon.to_vec()] // On error, return the polygon unchanged
+        }
+    }
+}
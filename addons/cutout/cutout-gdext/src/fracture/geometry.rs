@@ -1,10 +1,12 @@
 //! Shared geometry utilities for fracture algorithms
 //!
 //! Provides common geometric operations used across voronoi, slice, and seed
-//! generation: point-in-polygon testing, bounding boxes, polygon area, and
-//! half-plane clipping.
+//! generation: point-in-polygon testing, bounding boxes, polygon area,
+//! half-plane clipping, a spatial hash for minimum-distance checks and a grid
+//! index for rectangle overlap queries.
 
 use godot::prelude::*;
+use std::collections::HashMap;
 
 /// Calculate the bounding rectangle of a polygon.
 pub fn calculate_bounds(polygon: &[Vector2]) -> Rect2 {
@@ -49,13 +51,215 @@ pub fn polygon_area(polygon: &[Vector2]) -> f32 {
     area * 0.5
 }
 
+/// Calculate the perimeter of a closed polygon, including the closing edge.
+///
+/// An explicitly closed polygon (last point equal to the first) gives the same
+/// result, since its closing edge has zero length.
+pub fn polygon_perimeter(polygon: &[Vector2]) -> f32 {
+    let n = polygon.len();
+    if n < 2 {
+        return 0.0;
+    }
+
+    (0..n)
+        .map(|i| polygon[i].distance_to(polygon[(i + 1) % n]))
+        .sum()
+}
+
+/// Calculate the area-weighted centroid of a polygon.
+///
+/// Returns `None` for degenerate polygons with (near) zero area.
+pub fn polygon_centroid(polygon: &[Vector2]) -> Option<Vector2> {
+    let (area, moment) = ring_moments(polygon);
+    centroid_from_moments(area, moment)
+}
+
+/// Find a point representing the polygon's centre of mass.
+///
+/// Uses the area centroid, falling back to the bounds' centre if the centroid is
+/// undefined or lies outside a concave polygon.
+pub fn polygon_center(polygon: &[Vector2]) -> Vector2 {
+    polygon_centroid(polygon)
+        .filter(|&c| point_in_polygon(c, polygon))
+        .unwrap_or_else(|| calculate_bounds(polygon).center())
+}
+
+/// Calculate the combined area-weighted centroid of several rings.
+///
+/// Each ring contributes by its signed area, so holes wound opposite to their
+/// outer ring (as clipper2 returns them) are subtracted.
+///
+/// Returns `None` if the total area is (near) zero.
+pub fn rings_centroid(rings: &[Vec<Vector2>]) -> Option<Vector2> {
+    let (area, moment) = rings
+        .iter()
+        .map(|ring| ring_moments(ring))
+        .fold((0.0, Vector2::ZERO), |(a, m), (ra, rm)| (a + ra, m + rm));
+    centroid_from_moments(area, moment)
+}
+
+/// Calculate the centroid of each fragment in a flat fragment list.
+///
+/// clipper2 can return a hole as a separate ring, wound opposite to the
+/// fragment that contains it. Such rings are subtracted from their containing
+/// fragment's centroid. Degenerate fragments fall back to their vertex average.
+///
+/// # Returns
+/// One centroid per fragment, in the same order
+pub fn fragment_centroids(fragments: &[Vec<Vector2>]) -> Vec<Vector2> {
+    let areas: Vec<f32> = fragments.iter().map(|f| polygon_area(f)).collect();
+    let bounds: Vec<Rect2> = fragments.iter().map(|f| calculate_bounds(f)).collect();
+
+    fragments
+        .iter()
+        .enumerate()
+        .map(|(i, fragment)| {
+            let mut rings = vec![fragment.clone()];
+
+            for (j, other) in fragments.iter().enumerate() {
+                let is_hole = j != i
+                    && areas[j] * areas[i] < 0.0
+                    && areas[j].abs() < areas[i].abs()
+                    && bounds[i].encloses(bounds[j])
+                    && other.first().is_some_and(|&p| point_in_polygon(p, fragment));
+                if is_hole {
+                    rings.push(other.clone());
+                }
+            }
+
+            rings_centroid(&rings).unwrap_or_else(|| vertex_average(fragment))
+        })
+        .collect()
+}
+
+/// Average of a polygon's vertices (zero for an empty polygon).
+fn vertex_average(polygon: &[Vector2]) -> Vector2 {
+    if polygon.is_empty() {
+        return Vector2::ZERO;
+    }
+    polygon.iter().fold(Vector2::ZERO, |sum, p| sum + *p) / polygon.len() as f32
+}
+
+/// Signed area and first moment of area (area * centroid) of a ring.
+fn ring_moments(ring: &[Vector2]) -> (f32, Vector2) {
+    let n = ring.len();
+    if n < 3 {
+        return (0.0, Vector2::ZERO);
+    }
+
+    // Work relative to the first vertex to limit float cancellation
+    let origin = ring[0];
+    let mut area = 0.0;
+    let mut local = Vector2::ZERO;
+
+    for i in 0..n {
+        let a = ring[i] - origin;
+        let b = ring[(i + 1) % n] - origin;
+        let cross = a.x * b.y - b.x * a.y;
+        area += cross;
+        local += (a + b) * cross;
+    }
+
+    area *= 0.5;
+    (area, local / 6.0 + origin * area)
+}
+
+fn centroid_from_moments(area: f32, moment: Vector2) -> Option<Vector2> {
+    if area.abs() < f32::EPSILON {
+        return None;
+    }
+    Some(moment / area)
+}
+
+/// Area, centroid and polar second moment of area of a polygon with holes.
+///
+/// Multiply `area` and `inertia` by a density to get a rigid body's mass and
+/// moment of inertia.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AreaProperties {
+    pub area: f32,
+    pub centroid: Vector2,
+    /// Polar second moment of area about `centroid`
+    pub inertia: f32,
+}
+
+/// Compute the area properties of an outer ring minus its holes.
+///
+/// Windings don't matter: the outer ring always adds and holes always subtract.
+///
+/// Returns `None` if the remaining area is (near) zero.
+pub fn area_properties(outer: &[Vector2], holes: &[Vec<Vector2>]) -> Option<AreaProperties> {
+    let origin = *outer.first()?;
+
+    let outer_integrals = ring_integrals(outer, origin);
+    let outer_sign = outer_integrals.0.signum();
+    let (mut area, mut moment, mut polar) = (
+        outer_integrals.0 * outer_sign,
+        outer_integrals.1 * outer_sign,
+        outer_integrals.2 * outer_sign,
+    );
+
+    for hole in holes {
+        let (hole_area, hole_moment, hole_polar) = ring_integrals(hole, origin);
+        let sign = -hole_area.signum();
+        area += hole_area * sign;
+        moment += hole_moment * sign;
+        polar += hole_polar * sign;
+    }
+
+    if area < f32::EPSILON {
+        return None;
+    }
+
+    // Parallel axis theorem: shift the second moment from `origin` to the centroid
+    let local_centroid = moment / area;
+    Some(AreaProperties {
+        area,
+        centroid: origin + local_centroid,
+        inertia: polar - area * local_centroid.length_squared(),
+    })
+}
+
+/// Signed area, first moment and polar second moment of a ring, relative to `origin`.
+fn ring_integrals(ring: &[Vector2], origin: Vector2) -> (f32, Vector2, f32) {
+    let n = ring.len();
+    if n < 3 {
+        return (0.0, Vector2::ZERO, 0.0);
+    }
+
+    let mut area = 0.0;
+    let mut moment = Vector2::ZERO;
+    let mut polar = 0.0;
+
+    for i in 0..n {
+        let a = ring[i] - origin;
+        let b = ring[(i + 1) % n] - origin;
+        let cross = a.x * b.y - b.x * a.y;
+        area += cross;
+        moment += (a + b) * cross;
+        polar += cross * (a.dot(a) + a.dot(b) + b.dot(b));
+    }
+
+    (area * 0.5, moment / 6.0, polar / 12.0)
+}
+
+/// Distance within which a point counts as lying on a polygon's boundary
+pub const BOUNDARY_EPSILON: f32 = 1e-4;
+
 /// Check if a point is inside a polygon using ray casting.
+///
+/// Points within `BOUNDARY_EPSILON` of an edge or vertex count as inside, so
+/// the result doesn't flip with float rounding along the boundary.
 pub fn point_in_polygon(point: Vector2, polygon: &[Vector2]) -> bool {
     let n = polygon.len();
     if n < 3 {
         return false;
     }
 
+    if point_on_boundary(point, polygon, BOUNDARY_EPSILON) {
+        return true;
+    }
+
     let mut inside = false;
     let mut p1 = polygon[0];
 
@@ -78,6 +282,78 @@ pub fn point_in_polygon(point: Vector2, polygon: &[Vector2]) -> bool {
     inside
 }
 
+/// Check if a point lies within `eps` of any edge (or vertex) of a polygon.
+pub fn point_on_boundary(point: Vector2, polygon: &[Vector2], eps: f32) -> bool {
+    let n = polygon.len();
+    if n == 0 {
+        return false;
+    }
+
+    let eps_squared = eps * eps;
+    (0..n).any(|i| {
+        distance_squared_to_segment(point, polygon[i], polygon[(i + 1) % n]) <= eps_squared
+    })
+}
+
+/// Squared distance from `point` to the segment `a`-`b`
+fn distance_squared_to_segment(point: Vector2, a: Vector2, b: Vector2) -> f32 {
+    point.distance_squared_to(closest_point_on_segment(point, a, b))
+}
+
+/// Point of the segment `a`-`b` closest to `point`
+fn closest_point_on_segment(point: Vector2, a: Vector2, b: Vector2) -> Vector2 {
+    let ab = b - a;
+    let length_squared = ab.length_squared();
+    if length_squared <= f32::EPSILON {
+        return a;
+    }
+
+    let t = ((point - a).dot(ab) / length_squared).clamp(0.0, 1.0);
+    a + ab * t
+}
+
+/// Distance within which a fragment vertex is snapped onto the original outline.
+///
+/// Clipper2 rounds coordinates to 0.01, so clipped vertices drift up to about
+/// 0.007 off the edges they were cut from.
+pub const SNAP_EPSILON: f32 = 0.01;
+
+/// Move vertices lying within `eps` of a boundary onto it.
+///
+/// A vertex near a boundary vertex takes its exact position, otherwise one near
+/// a boundary edge is projected onto the nearest such edge. Used after clipping
+/// so fragments line up with the original silhouette without hairline gaps.
+pub fn snap_to_boundary(ring: &mut [Vector2], boundaries: &[Vec<Vector2>], eps: f32) {
+    let eps_squared = eps * eps;
+    let vertices = || boundaries.iter().flatten().copied();
+    let edges = || {
+        boundaries
+            .iter()
+            .filter(|b| b.len() >= 2)
+            .flat_map(|b| (0..b.len()).map(move |i| (b[i], b[(i + 1) % b.len()])))
+    };
+
+    for point in ring.iter_mut() {
+        let corner = vertices()
+            .map(|v| (point.distance_squared_to(v), v))
+            .filter(|(d, _)| *d <= eps_squared)
+            .min_by(|a, b| a.0.total_cmp(&b.0));
+        if let Some((_, v)) = corner {
+            *point = v;
+            continue;
+        }
+
+        let on_edge = edges()
+            .map(|(a, b)| closest_point_on_segment(*point, a, b))
+            .map(|p| (point.distance_squared_to(p), p))
+            .filter(|(d, _)| *d <= eps_squared)
+            .min_by(|a, b| a.0.total_cmp(&b.0));
+        if let Some((_, p)) = on_edge {
+            *point = p;
+        }
+    }
+}
+
 /// Clip a polygon against a half-plane defined by a point and normal.
 ///
 /// Keeps the side of the polygon in the direction of the normal.
@@ -140,7 +416,10 @@ pub fn circumcenter(a: Vector2, b: Vector2, c: Vector2) -> Option<Vector2> {
 }
 
 /// Check if a point is far enough from all existing points.
-pub fn is_far_enough(point: Vector2, existing: &[Vector2], min_distance: f32) -> bool {
+///
+/// The brute-force scan `SpatialHash` replaces, kept as its test oracle.
+#[cfg(test)]
+fn is_far_enough(point: Vector2, existing: &[Vector2], min_distance: f32) -> bool {
     let min_dist_sq = min_distance * min_distance;
     for p in existing {
         if (point - *p).length_squared() < min_dist_sq {
@@ -150,15 +429,277 @@ pub fn is_far_enough(point: Vector2, existing: &[Vector2], min_distance: f32) ->
     true
 }
 
+/// Uniform grid of accepted points for fast minimum-distance checks.
+///
+/// Cells are `min_distance` wide, so any point closer than `min_distance` to a
+/// candidate lies in the candidate's cell or one of its 8 neighbours. Gives the
+/// same answers as `is_far_enough` without scanning every existing point.
+pub(crate) struct SpatialHash {
+    min_distance: f32,
+    cell_size: f32,
+    cells: HashMap<(i32, i32), Vec<Vector2>>,
+    points: Vec<Vector2>,
+}
+
+impl SpatialHash {
+    pub fn new(min_distance: f32) -> Self {
+        // Slightly oversized cells so float rounding in the cell lookup can
+        // never push a close neighbour two cells away
+        let cell_size = (min_distance * 1.001).max(f32::EPSILON);
+
+        Self {
+            min_distance,
+            cell_size,
+            cells: HashMap::new(),
+            points: Vec::new(),
+        }
+    }
+
+    /// Check if a point is far enough from all inserted points.
+    pub fn is_far_enough(&self, point: Vector2) -> bool {
+        if self.min_distance <= 0.0 {
+            return true;
+        }
+
+        let min_dist_sq = self.min_distance * self.min_distance;
+        let (cx, cy) = self.cell_of(point);
+
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                let Some(cell) = self.cells.get(&(cx + dx, cy + dy)) else {
+                    continue;
+                };
+                if cell.iter().any(|p| (point - *p).length_squared() < min_dist_sq) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Insert a point, keeping insertion order for `into_points`.
+    pub fn insert(&mut self, point: Vector2) {
+        let cell = self.cell_of(point);
+        self.cells.entry(cell).or_default().push(point);
+        self.points.push(point);
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// All inserted points in insertion order.
+    pub fn into_points(self) -> Vec<Vector2> {
+        self.points
+    }
+
+    fn cell_of(&self, point: Vector2) -> (i32, i32) {
+        (
+            (point.x / self.cell_size).floor() as i32,
+            (point.y / self.cell_size).floor() as i32,
+        )
+    }
+}
+
+/// Affine transform mapping `rect` onto the rectangle from the origin to `size`.
+///
+/// Returns `None` if `rect` has no area.
+pub fn rect_mapping(rect: Rect2, size: Vector2) -> Option<Transform2D> {
+    if rect.size.x == 0.0 || rect.size.y == 0.0 {
+        return None;
+    }
+
+    let scale = Vector2::new(size.x / rect.size.x, size.y / rect.size.y);
+    Some(Transform2D::from_cols(
+        Vector2::new(scale.x, 0.0),
+        Vector2::new(0.0, scale.y),
+        -rect.position * scale,
+    ))
+}
+
 /// Grow (or shrink) a Rect2 by a given amount on all sides.
 ///
-/// Positive values expand, negative values shrink.
+/// Positive values expand, negative values shrink. Shrinking stops at the
+/// centre on each axis, so an over-shrunk rect collapses to zero size around
+/// its centre instead of inverting past it.
 pub fn grow_rect(rect: Rect2, amount: f32) -> Rect2 {
-    let pos = rect.position - Vector2::new(amount, amount);
-    let size = rect.size + Vector2::new(amount * 2.0, amount * 2.0);
+    let half = rect.size * 0.5;
+    let grow = Vector2::new(amount.max(-half.x), amount.max(-half.y));
+
+    let pos = rect.position - grow;
+    let size = rect.size + grow * 2.0;
 
-    // Clamp to avoid negative sizes
+    // Clamp away rounding error
     let size = Vector2::new(size.x.max(0.0), size.y.max(0.0));
 
     Rect2::new(pos, size)
 }
+
+/// Uniform grid over a set of rectangles for fast overlap queries.
+///
+/// Each rectangle is registered in every cell it covers, so a query only looks
+/// at rectangles sharing a cell with the query rect. The grid has roughly one
+/// cell per rectangle over their combined bounds.
+pub struct RectIndex {
+    origin: Vector2,
+    cell_size: Vector2,
+    columns: usize,
+    rows: usize,
+    cells: Vec<Vec<usize>>,
+}
+
+impl RectIndex {
+    pub fn new(rects: &[Rect2]) -> Self {
+        let Some(first) = rects.first() else {
+            return Self {
+                origin: Vector2::ZERO,
+                cell_size: Vector2::ONE,
+                columns: 0,
+                rows: 0,
+                cells: Vec::new(),
+            };
+        };
+
+        let bounds = rects.iter().skip(1).fold(*first, |acc, r| acc.merge(*r));
+        let side = (rects.len() as f32).sqrt().ceil().max(1.0) as usize;
+        let cell_size = Vector2::new(
+            (bounds.size.x / side as f32).max(f32::EPSILON),
+            (bounds.size.y / side as f32).max(f32::EPSILON),
+        );
+
+        let mut index = Self {
+            origin: bounds.position,
+            cell_size,
+            columns: side,
+            rows: side,
+            cells: vec![Vec::new(); side * side],
+        };
+
+        for (i, rect) in rects.iter().enumerate() {
+            let (x_range, y_range) = index.cell_range(*rect);
+            for y in y_range {
+                for x in x_range.clone() {
+                    index.cells[y * side + x].push(i);
+                }
+            }
+        }
+
+        index
+    }
+
+    /// Indices of rectangles that may overlap `rect`, in ascending order.
+    ///
+    /// A superset of the overlapping rectangles: callers still need an exact
+    /// test, but never miss a match.
+    pub fn query(&self, rect: Rect2) -> Vec<usize> {
+        if self.cells.is_empty() {
+            return Vec::new();
+        }
+
+        let (x_range, y_range) = self.cell_range(rect);
+        let mut found: Vec<usize> = y_range
+            .flat_map(|y| x_range.clone().map(move |x| (x, y)))
+            .flat_map(|(x, y)| self.cells[y * self.columns + x].iter().copied())
+            .collect();
+        found.sort_unstable();
+        found.dedup();
+        found
+    }
+
+    /// Cells covered by a rect, clamped to the grid
+    fn cell_range(&self, rect: Rect2) -> (std::ops::Range<usize>, std::ops::Range<usize>) {
+        let cell = |value: f32, origin: f32, size: f32, count: usize| {
+            (((value - origin) / size).floor().max(0.0) as usize).min(count - 1)
+        };
+        let end = rect.position + rect.size;
+
+        (
+            cell(rect.position.x, self.origin.x, self.cell_size.x, self.columns)
+                ..cell(end.x, self.origin.x, self.cell_size.x, self.columns) + 1,
+            cell(rect.position.y, self.origin.y, self.cell_size.y, self.rows)
+                ..cell(end.y, self.origin.y, self.cell_size.y, self.rows) + 1,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square() -> Vec<Vector2> {
+        vec![
+            Vector2::new(0.0, 0.0),
+            Vector2::new(10.0, 0.0),
+            Vector2::new(10.0, 10.0),
+            Vector2::new(0.0, 10.0),
+        ]
+    }
+
+    #[test]
+    fn points_on_horizontal_edges_are_inside() {
+        let polygon = square();
+        for point in [Vector2::new(5.0, 0.0), Vector2::new(5.0, 10.0)] {
+            assert!(point_on_boundary(point, &polygon, BOUNDARY_EPSILON));
+            assert!(point_in_polygon(point, &polygon));
+        }
+    }
+
+    #[test]
+    fn points_on_vertices_are_inside() {
+        let polygon = square();
+        for &vertex in &polygon {
+            assert!(point_on_boundary(vertex, &polygon, BOUNDARY_EPSILON));
+            assert!(point_in_polygon(vertex, &polygon));
+        }
+    }
+
+    #[test]
+    fn sub_pixel_offsets_pick_the_right_side() {
+        let polygon = square();
+
+        assert!(point_in_polygon(Vector2::new(5.0, 0.01), &polygon));
+        assert!(point_in_polygon(Vector2::new(9.999, 5.0), &polygon));
+        assert!(!point_in_polygon(Vector2::new(5.0, -0.01), &polygon));
+        assert!(!point_in_polygon(Vector2::new(10.001, 5.0), &polygon));
+
+        // Within the epsilon the boundary wins, on either side
+        assert!(point_in_polygon(Vector2::new(5.0, -0.00005), &polygon));
+        let near = Vector2::new(5.0, 0.01);
+        assert!(!point_on_boundary(near, &polygon, BOUNDARY_EPSILON));
+    }
+
+    #[test]
+    fn ray_along_a_horizontal_edge_stays_outside() {
+        let triangle = vec![
+            Vector2::new(0.0, 0.0),
+            Vector2::new(10.0, 0.0),
+            Vector2::new(5.0, 10.0),
+        ];
+
+        assert!(!point_in_polygon(Vector2::new(-1.0, 0.0), &triangle));
+        assert!(!point_in_polygon(Vector2::new(11.0, 0.0), &triangle));
+        assert!(point_in_polygon(Vector2::new(5.0, 5.0), &triangle));
+    }
+
+    #[test]
+    fn spatial_hash_agrees_with_brute_force() {
+        let mut rng = crate::common::rng::Rng::new(3);
+        for min_distance in [0.0, 0.5, 3.0, 7.25] {
+            let mut hash = SpatialHash::new(min_distance);
+            let mut accepted = Vec::new();
+
+            for _ in 0..2000 {
+                let candidate =
+                    Vector2::new(rng.randf_range(-20.0, 20.0), rng.randf_range(-20.0, 20.0));
+                let expected = is_far_enough(candidate, &accepted, min_distance);
+                assert_eq!(hash.is_far_enough(candidate), expected, "{candidate:?}");
+
+                if expected {
+                    hash.insert(candidate);
+                    accepted.push(candidate);
+                }
+            }
+            assert_eq!(hash.into_points(), accepted);
+        }
+    }
+}
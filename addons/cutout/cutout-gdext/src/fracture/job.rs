@@ -0,0 +1,103 @@
+//! CutoutFractureJob - Voronoi fracture spread over several calls
+//!
+//! Unlike the stateless processor, this keeps a fracture in progress between
+//! calls. GDScript can clip a few cells per frame from `_process` instead of
+//! stalling one frame on a large shatter:
+//!
+//! ```gdscript
+//! var job := CutoutFractureJob.create(polygons, seeds)
+//! while not job.step(8):
+//!     await get_tree().process_frame
+//! var fragments := job.take_fragments()
+//! ```
+
+use super::processor::CutoutDestructionProcessor;
+use super::voronoi::VoronoiJob;
+use godot::prelude::*;
+
+/// A Voronoi fracture clipped a bounded number of cells per call.
+#[derive(GodotClass)]
+#[class(no_init, base=RefCounted)]
+pub struct CutoutFractureJob {
+    #[base]
+    base: Base<RefCounted>,
+    /// The polygons being fractured, returned as-is if nothing is split
+    input: Array<PackedVector2Array>,
+    /// None if the input can't be fractured with the given seeds
+    job: Option<VoronoiJob>,
+}
+
+#[godot_api]
+impl CutoutFractureJob {
+    /// Prepare a Voronoi fracture without clipping any cells yet.
+    ///
+    /// # Arguments
+    /// * `polygons` - Array of polygons (first = outer boundary, rest = holes)
+    /// * `seed_points` - Pre-generated seed points for Voronoi cell centers
+    #[func]
+    pub fn create(
+        polygons: Array<PackedVector2Array>,
+        seed_points: PackedVector2Array,
+    ) -> Gd<Self> {
+        let input = CutoutDestructionProcessor::input(polygons);
+        let rings: Vec<Vec<Vector2>> = input.iter_shared().map(|p| p.to_vec()).collect();
+        let job = VoronoiJob::new(&rings, seed_points.as_slice());
+
+        Gd::from_init_fn(|base| Self { base, input, job })
+    }
+
+    /// Clip up to `max_cells` more Voronoi cells.
+    ///
+    /// # Arguments
+    /// * `max_cells` - Cells to process in this call (at least 1)
+    ///
+    /// # Returns
+    /// True once the fracture is complete
+    #[func]
+    pub fn step(&mut self, max_cells: i32) -> bool {
+        match &mut self.job {
+            Some(job) => job.step(max_cells.max(1) as usize),
+            None => true,
+        }
+    }
+
+    /// Whether the fracture is complete
+    #[func]
+    pub fn is_done(&self) -> bool {
+        self.job.as_ref().is_none_or(VoronoiJob::is_done)
+    }
+
+    /// Fraction of cells clipped so far (0.0 - 1.0)
+    #[func]
+    pub fn get_progress(&self) -> f32 {
+        match &self.job {
+            Some(job) if job.cell_count() > 0 => job.cells_done() as f32 / job.cell_count() as f32,
+            _ => 1.0,
+        }
+    }
+
+    /// Collect the fragments produced since the last call.
+    ///
+    /// Can be called between steps to add fragments as they are ready. Once the
+    /// fracture is complete, a fracture that split nothing returns the input
+    /// polygons unchanged, as `fracture_voronoi` does.
+    ///
+    /// # Returns
+    /// Array of polygon fragments, in the same order as from `fracture_voronoi`
+    #[func]
+    pub fn take_fragments(&mut self) -> Array<PackedVector2Array> {
+        let Some(job) = &mut self.job else {
+            return self.input.clone();
+        };
+
+        if job.is_done() && job.produced() == 0 {
+            return self.input.clone();
+        }
+
+        let mut fragments = Array::new();
+        for fragment in job.take_fragments() {
+            fragments.push(&PackedVector2Array::from(fragment.as_slice()));
+        }
+        fragments
+    }
+}
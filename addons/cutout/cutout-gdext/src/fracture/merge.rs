@@ -0,0 +1,196 @@
+//! Post-fracture cleanup of tiny sliver fragments
+//!
+//! Voronoi and slice fracture can leave slivers of a few px² along hole
+//! boundaries. This module folds each one into a neighbour:
+//! 1. Fragments below `min_area` are visited smallest first
+//! 2. Its neighbours are the fragments sharing a stretch of boundary with it
+//! 3. It is unioned (via `clipper2`) into the largest neighbour
+//! 4. A fragment with no neighbour, or whose union doesn't come out as a single
+//!    polygon, is dropped
+//!
+//! Slivers along a concave hole edge often touch several fragments through very
+//! short edges, so any shared boundary counts as adjacency, not just long edges.
+//!
+//! The same adjacency test is exposed on its own as `fragment_adjacency`, for
+//! gameplay that needs to know which fragments touch.
+
+use super::clipper_utils::clipper2_union;
+use super::geometry::{calculate_bounds, grow_rect, polygon_area, RectIndex};
+use godot::prelude::*;
+
+/// How far apart two edges may be and still count as a shared boundary
+const ADJACENCY_TOLERANCE: f32 = 0.05;
+
+/// Merge fragments smaller than `min_area` into their largest adjacent neighbour.
+///
+/// # Arguments
+/// * `fragments` - Fragments from any fracture function
+/// * `min_area` - Fragments with an absolute area below this are merged away
+///
+/// # Returns
+/// The remaining fragments, in their original relative order
+pub fn merge_small_fragments(fragments: Vec<Vec<Vector2>>, min_area: f32) -> Vec<Vec<Vector2>> {
+    let bounds: Vec<Rect2> = fragments
+        .iter()
+        .map(|f| grow_rect(calculate_bounds(f), ADJACENCY_TOLERANCE))
+        .collect();
+    let index = RectIndex::new(&bounds);
+
+    // A merged fragment covers the original bounds of everything it absorbed,
+    // and index hits on an absorbed fragment lead to the one that absorbed it
+    let mut covers = bounds;
+    let mut owners: Vec<usize> = (0..fragments.len()).collect();
+
+    let mut fragments: Vec<Option<Vec<Vector2>>> = fragments
+        .into_iter()
+        .map(|f| if f.len() >= 3 { Some(f) } else { None })
+        .collect();
+    let mut areas: Vec<f32> = fragments
+        .iter()
+        .map(|f| f.as_deref().map_or(0.0, |f| polygon_area(f).abs()))
+        .collect();
+
+    let mut small: Vec<usize> = (0..fragments.len())
+        .filter(|&i| fragments[i].is_some() && areas[i] < min_area)
+        .collect();
+    small.sort_by(|&a, &b| areas[a].total_cmp(&areas[b]));
+
+    for i in small {
+        // May have grown past the threshold by absorbing an earlier sliver
+        if areas[i] >= min_area {
+            continue;
+        }
+        let Some(sliver) = fragments[i].take() else {
+            continue;
+        };
+
+        let mut candidates: Vec<usize> = index
+            .query(covers[i])
+            .into_iter()
+            .map(|j| owner_of(&mut owners, j))
+            .collect();
+        candidates.sort_unstable();
+        candidates.dedup();
+
+        let neighbour = candidates
+            .into_iter()
+            .filter(|&j| {
+                fragments[j]
+                    .as_deref()
+                    .is_some_and(|other| shares_boundary(&sliver, other))
+            })
+            .max_by(|&a, &b| areas[a].total_cmp(&areas[b]));
+
+        let Some(j) = neighbour else {
+            continue; // Isolated sliver, drop it
+        };
+
+        let target = fragments[j].as_deref().unwrap_or_default();
+        let mut merged = clipper2_union(target, &sliver);
+        merged.retain(|p| p.len() >= 3);
+
+        if let [piece] = merged.as_slice() {
+            areas[j] = polygon_area(piece).abs();
+            fragments[j] = merged.pop();
+            covers[j] = covers[j].merge(covers[i]);
+            owners[i] = j;
+        }
+    }
+
+    fragments.into_iter().flatten().collect()
+}
+
+/// Follow merges from an original fragment index to the fragment now holding it.
+fn owner_of(owners: &mut [usize], mut i: usize) -> usize {
+    while owners[i] != i {
+        // Path halving keeps later lookups short
+        owners[i] = owners[owners[i]];
+        i = owners[i];
+    }
+    i
+}
+
+/// Find which fragments share a stretch of boundary with each other.
+///
+/// Adjacency is worked out from the fragments themselves rather than from the
+/// seeds that made them: one seed's cell can be split into several fragments,
+/// and two neighbouring seeds' fragments only touch if their shared cell edge
+/// survives clipping.
+///
+/// # Returns
+/// For each fragment, the indices of its neighbours in ascending order
+pub fn fragment_adjacency(fragments: &[Vec<Vector2>]) -> Vec<Vec<usize>> {
+    let bounds: Vec<Rect2> = fragments
+        .iter()
+        .map(|f| grow_rect(calculate_bounds(f), ADJACENCY_TOLERANCE))
+        .collect();
+    let index = RectIndex::new(&bounds);
+    let mut adjacency = vec![Vec::new(); fragments.len()];
+
+    for (i, fragment) in fragments.iter().enumerate() {
+        if fragment.len() < 3 {
+            continue;
+        }
+        // Each pair is tested once, from its lower index
+        for j in index.query(bounds[i]).into_iter().filter(|&j| j > i) {
+            if fragments[j].len() >= 3 && shares_boundary(fragment, &fragments[j]) {
+                adjacency[i].push(j);
+                adjacency[j].push(i);
+            }
+        }
+    }
+
+    for neighbours in &mut adjacency {
+        neighbours.sort_unstable();
+    }
+    adjacency
+}
+
+/// Check whether two polygons share a stretch of boundary.
+fn shares_boundary(a: &[Vector2], b: &[Vector2]) -> bool {
+    let a_bounds = grow_rect(calculate_bounds(a), ADJACENCY_TOLERANCE);
+    let b_bounds = calculate_bounds(b);
+
+    let overlaps = a_bounds.position.x <= b_bounds.position.x + b_bounds.size.x
+        && a_bounds.position.x + a_bounds.size.x >= b_bounds.position.x
+        && a_bounds.position.y <= b_bounds.position.y + b_bounds.size.y
+        && a_bounds.position.y + a_bounds.size.y >= b_bounds.position.y;
+
+    overlaps && shared_edge_length(a, b) > ADJACENCY_TOLERANCE
+}
+
+/// Total length over which edges of `a` and `b` lie on top of each other.
+fn shared_edge_length(a: &[Vector2], b: &[Vector2]) -> f32 {
+    let mut shared = 0.0;
+
+    for i in 0..a.len() {
+        let p0 = a[i];
+        let p1 = a[(i + 1) % a.len()];
+        let length = (p1 - p0).length();
+        if length <= f32::EPSILON {
+            continue;
+        }
+        let dir = (p1 - p0) / length;
+        let normal = Vector2::new(-dir.y, dir.x);
+
+        for j in 0..b.len() {
+            let q0 = b[j] - p0;
+            let q1 = b[(j + 1) % b.len()] - p0;
+
+            // Both endpoints must sit on the line through this edge
+            if q0.dot(normal).abs() > ADJACENCY_TOLERANCE
+                || q1.dot(normal).abs() > ADJACENCY_TOLERANCE
+            {
+                continue;
+            }
+
+            let (t0, t1) = (q0.dot(dir), q1.dot(dir));
+            let overlap = t0.max(t1).min(length) - t0.min(t1).max(0.0);
+            if overlap > 0.0 {
+                shared += overlap;
+            }
+        }
+    }
+
+    shared
+}
@@ -2,15 +2,28 @@
 //!
 //! This module provides:
 //! - `CutoutDestructionProcessor` - Stateless Godot API for fracture operations
+//! - `CutoutFractureJob` - Voronoi fracture spread over several calls (frames)
 //! - Voronoi fracturing - Break polygons into irregular pieces using Voronoi diagrams
-//! - Slice fracturing - Cut polygons along lines
-//! - Seed generation - 5 distribution patterns for Voronoi cell placement
+//! - Slice fracturing - Cut polygons along lines, or split open polylines
+//! - Seed generation - 9 distribution patterns for Voronoi cell placement
+//! - Fragment cleanup - Merge tiny sliver fragments into their neighbours, and
+//!   simplify fragments without opening gaps between them
+//! - Input repair - Split self-intersecting input into simple polygons
+//!
+//! Fracture functions never fail destructively: when they can't split the
+//! input (invalid polygons, too few seeds, a cut that misses) they return the
+//! input polygons unchanged.
 
 pub mod clipper_utils;
 pub mod geometry;
+pub mod job;
+pub mod merge;
 pub mod processor;
+pub mod reduce;
+pub mod repair;
 pub mod seeds;
 pub mod slice;
 pub mod voronoi;
 
+pub use job::CutoutFractureJob;
 pub use processor::CutoutDestructionProcessor;
@@ -4,10 +4,17 @@
 //! algorithms and seed patterns. Follows the same stateless Processor pattern
 //! as CutoutContourProcessor.
 
+use super::geometry::{area_properties, fragment_centroids, rect_mapping};
+use super::merge;
+use super::reduce;
+use super::repair;
 use super::seeds;
-use super::voronoi;
+use super::voronoi::{self, VoronoiMetric};
 use super::slice;
+use crate::error::{self, cutout_error, CapturedError, CutoutError};
+use godot::builtin::VarDictionary as Dictionary;
 use godot::prelude::*;
+use rayon::prelude::*;
 
 /// Main processor for polygon fracture/destruction operations.
 ///
@@ -19,9 +26,23 @@ pub struct CutoutDestructionProcessor;
 
 #[godot_api]
 impl CutoutDestructionProcessor {
+    #[constant]
+    pub const METRIC_EUCLIDEAN: i32 = VoronoiMetric::Euclidean as i32;
+    #[constant]
+    pub const METRIC_MANHATTAN: i32 = VoronoiMetric::Manhattan as i32;
+    #[constant]
+    pub const METRIC_CHEBYSHEV: i32 = VoronoiMetric::Chebyshev as i32;
+    /// Random seeds generated for a set with no seed points in `fracture_voronoi_batch`
+    #[constant]
+    pub const BATCH_RANDOM_SEED_COUNT: i32 = 8;
+
     // ========================================================================
     // Fracture Methods
     // ========================================================================
+    //
+    // Every fracture method returns the input polygons unchanged when it can't
+    // fracture them (invalid input, too few seeds, a cut that misses). Use
+    // `is_fractured` to tell that apart from a real split.
 
     /// Fracture polygons using Voronoi diagram from pre-computed seed points.
     ///
@@ -36,7 +57,239 @@ impl CutoutDestructionProcessor {
         polygons: Array<PackedVector2Array>,
         seed_points: PackedVector2Array,
     ) -> Array<PackedVector2Array> {
-        voronoi::fracture(&polygons, &seed_points)
+        voronoi::fracture(&Self::input(polygons), &seed_points)
+    }
+
+    /// Fracture polygons using Voronoi diagram under a chosen distance metric.
+    ///
+    /// Manhattan gives diamond-ish cells and Chebyshev square-ish ones, which
+    /// suit blocky, pixel-art destruction. Their cell boundaries are traced from
+    /// a raster, so they are accurate to about 1/256 of the polygon's size.
+    ///
+    /// # Arguments
+    /// * `polygons` - Array of polygons (first = outer boundary, rest = holes)
+    /// * `seed_points` - Pre-generated seed points for Voronoi cell centers
+    /// * `metric` - One of the `METRIC_*` constants
+    ///
+    /// # Returns
+    /// Array of polygon fragments
+    #[func]
+    pub fn fracture_voronoi_with_metric(
+        polygons: Array<PackedVector2Array>,
+        seed_points: PackedVector2Array,
+        metric: i32,
+    ) -> Array<PackedVector2Array> {
+        let metric = VoronoiMetric::from_i32(metric).unwrap_or_else(|| {
+            cutout_error!(
+                CutoutError::UnknownOption,
+                "Unknown Voronoi metric: {}, defaulting to Euclidean",
+                metric
+            );
+            VoronoiMetric::Euclidean
+        });
+
+        voronoi::fracture_with_metric(&Self::input(polygons), &seed_points, metric)
+    }
+
+    /// Fracture polygons using a weighted Voronoi diagram for varied fragment sizes.
+    ///
+    /// A seed with a larger weight than its neighbours pushes its cell boundaries
+    /// outward, so mixing weights gives a mix of large chunks and small shards.
+    /// Cells are those of a power diagram, an approximation of multiplicative
+    /// weighting that keeps every cell convex. Equal weights give exactly the
+    /// fragments of `fracture_voronoi`.
+    ///
+    /// # Arguments
+    /// * `polygons` - Array of polygons (first = outer boundary, rest = holes)
+    /// * `seed_points` - Pre-generated seed points for Voronoi cell centers
+    /// * `weights` - Relative cell size of each seed (1.0 = neutral), one per seed point
+    ///
+    /// # Returns
+    /// Array of polygon fragments, or the input unchanged if `weights` doesn't
+    /// match `seed_points` in length
+    #[func]
+    pub fn fracture_voronoi_weighted(
+        polygons: Array<PackedVector2Array>,
+        seed_points: PackedVector2Array,
+        weights: PackedFloat32Array,
+    ) -> Array<PackedVector2Array> {
+        if weights.len() != seed_points.len() {
+            cutout_error!(
+                CutoutError::MismatchedCounts,
+                "Weight count ({}) doesn't match seed point count ({})",
+                weights.len(),
+                seed_points.len()
+            );
+            return polygons;
+        }
+
+        voronoi::fracture_weighted(&Self::input(polygons), &seed_points, weights.as_slice())
+    }
+
+    /// Fracture polygons using Voronoi diagram and return fragment centroids.
+    ///
+    /// Centroids are area-weighted (not bounding-box centers), with any holes
+    /// inside a fragment subtracted. Useful for spawning physics bodies and
+    /// applying explosion impulses.
+    ///
+    /// # Arguments
+    /// * `polygons` - Array of polygons (first = outer boundary, rest = holes)
+    /// * `seed_points` - Pre-generated seed points for Voronoi cell centers
+    ///
+    /// # Returns
+    /// Dictionary with keys:
+    /// * `fragments` - Array of polygon fragments, as from `fracture_voronoi`
+    /// * `centroids` - PackedVector2Array with one centroid per fragment
+    #[func]
+    pub fn fracture_voronoi_with_centroids(
+        polygons: Array<PackedVector2Array>,
+        seed_points: PackedVector2Array,
+    ) -> Dictionary {
+        let fragments = voronoi::fracture(&Self::input(polygons), &seed_points);
+        let rings: Vec<Vec<Vector2>> = fragments.iter_shared().map(|f| f.to_vec()).collect();
+        let centroids = fragment_centroids(&rings);
+
+        let mut result = Dictionary::new();
+        result.set("fragments", fragments);
+        result.set("centroids", PackedVector2Array::from(centroids.as_slice()));
+        result
+    }
+
+    /// Fracture polygons using Voronoi diagram and report which seed made each fragment.
+    ///
+    /// Useful for seed-driven gameplay ("the fragment the player clicked belongs to
+    /// seed N") and for debugging a seed pattern.
+    ///
+    /// # Arguments
+    /// * `polygons` - Array of polygons (first = outer boundary, rest = holes)
+    /// * `seed_points` - Pre-generated seed points for Voronoi cell centers
+    ///
+    /// # Returns
+    /// Dictionary with keys:
+    /// * `fragments` - Array of polygon fragments, as from `fracture_voronoi`
+    /// * `seed_ids` - PackedInt32Array with the index into `seed_points` of each
+    ///   fragment's seed. A cell split by the outline or a hole gives several
+    ///   fragments with the same id; -1 if the input was returned unsplit.
+    #[func]
+    pub fn fracture_voronoi_with_seed_ids(
+        polygons: Array<PackedVector2Array>,
+        seed_points: PackedVector2Array,
+    ) -> Dictionary {
+        let (fragments, seed_ids) =
+            voronoi::fracture_with_seed_ids(&Self::input(polygons), &seed_points);
+
+        let mut result = Dictionary::new();
+        result.set("fragments", fragments);
+        result.set("seed_ids", seed_ids);
+        result
+    }
+
+    /// Fracture polygons using Voronoi diagram and report which fragments touch.
+    ///
+    /// For "chip away" destruction, where breaking one fragment should weaken its
+    /// neighbours. Adjacency is per fragment, not per seed, so a cell split by the
+    /// outline or a hole gives fragments with their own neighbour lists.
+    ///
+    /// # Arguments
+    /// * `polygons` - Array of polygons (first = outer boundary, rest = holes)
+    /// * `seed_points` - Pre-generated seed points for Voronoi cell centers
+    ///
+    /// # Returns
+    /// Dictionary with keys:
+    /// * `fragments` - Array of polygon fragments, as from `fracture_voronoi`
+    /// * `adjacency` - Array of PackedInt32Array, one per fragment, holding the
+    ///   indices of the fragments sharing a stretch of boundary with it
+    #[func]
+    pub fn fracture_voronoi_with_adjacency(
+        polygons: Array<PackedVector2Array>,
+        seed_points: PackedVector2Array,
+    ) -> Dictionary {
+        let fragments = voronoi::fracture(&Self::input(polygons), &seed_points);
+
+        let rings: Vec<Vec<Vector2>> = fragments.iter_shared().map(|f| f.to_vec()).collect();
+        let mut adjacency = Array::<PackedInt32Array>::new();
+        for neighbours in merge::fragment_adjacency(&rings) {
+            let indices: Vec<i32> = neighbours.into_iter().map(|j| j as i32).collect();
+            adjacency.push(&PackedInt32Array::from(indices.as_slice()));
+        }
+
+        let mut result = Dictionary::new();
+        result.set("fragments", fragments);
+        result.set("adjacency", adjacency);
+        result
+    }
+
+    /// Fracture polygons using Voronoi diagram, keeping each fragment's holes.
+    ///
+    /// `fracture_voronoi` returns a hole that sits entirely inside one cell as
+    /// just another ring. This groups every fragment with the holes inside it,
+    /// ready for `fragment_physics_properties_with_holes` or `Polygon2D.polygons`.
+    ///
+    /// # Arguments
+    /// * `polygons` - Array of polygons (first = outer boundary, rest = holes)
+    /// * `seed_points` - Pre-generated seed points for Voronoi cell centers
+    ///
+    /// # Returns
+    /// Array of fragments, each an `Array[PackedVector2Array]` of `[outer, holes...]`
+    /// (outer CCW, holes CW)
+    #[func]
+    pub fn fracture_voronoi_with_holes(
+        polygons: Array<PackedVector2Array>,
+        seed_points: PackedVector2Array,
+    ) -> Array<Variant> {
+        voronoi::fracture_with_holes(&Self::input(polygons), &seed_points)
+    }
+
+    /// Fracture polygons into a predictable number of Voronoi fragments.
+    ///
+    /// `fracture_voronoi` often returns fewer fragments than seeds, because
+    /// seeds outside the polygon are rejected and cells can vanish under holes.
+    /// This generates and relaxes random seeds itself, retrying with a corrected
+    /// seed count until the fragment count is within `tolerance` of the target
+    /// or `max_attempts` runs out.
+    ///
+    /// # Arguments
+    /// * `polygons` - Array of polygons (first = outer boundary, rest = holes)
+    /// * `fragment_count` - Desired number of fragments
+    /// * `tolerance` - Accepted difference from `fragment_count` (0 = exact)
+    /// * `max_attempts` - Retry budget
+    /// * `seed` - Random seed
+    ///
+    /// # Returns
+    /// Dictionary with keys:
+    /// * `fragments` - Array of polygon fragments from the closest attempt
+    /// * `fragment_count` - Number of fragments actually produced (holes left
+    ///   inside a fragment come back as extra rings but aren't counted)
+    /// * `target_reached` - True if `fragment_count` is within `tolerance`
+    /// * `attempts` - Number of attempts made
+    #[func]
+    pub fn fracture_voronoi_to_count(
+        polygons: Array<PackedVector2Array>,
+        fragment_count: i32,
+        tolerance: i32,
+        max_attempts: i32,
+        seed: i64,
+    ) -> Dictionary {
+        let rings: Vec<Vec<Vector2>> = polygons.iter_shared().map(|p| p.to_vec()).collect();
+        let rings = repair::repair_input(rings);
+        let target = fragment_count.max(0) as usize;
+        let tolerance = tolerance.max(0) as usize;
+        let max_attempts = max_attempts.max(0) as usize;
+
+        let counted = voronoi::fracture_to_count(&rings, target, tolerance, max_attempts, seed);
+        let count = counted.fragment_count;
+
+        let mut fragments = Array::<PackedVector2Array>::new();
+        for fragment in &counted.fragments {
+            fragments.push(&PackedVector2Array::from(fragment.as_slice()));
+        }
+
+        let mut result = Dictionary::new();
+        result.set("fragments", fragments);
+        result.set("fragment_count", count as i32);
+        result.set("target_reached", count.abs_diff(target) <= tolerance);
+        result.set("attempts", counted.attempts as i32);
+        result
     }
 
     /// Fracture polygons along a line segment.
@@ -54,7 +307,29 @@ impl CutoutDestructionProcessor {
         line_start: Vector2,
         line_end: Vector2,
     ) -> Array<PackedVector2Array> {
-        slice::fracture(&polygons, line_start, line_end)
+        slice::fracture(&Self::input(polygons), line_start, line_end)
+    }
+
+    /// Fracture polygons along a bezier curve.
+    ///
+    /// The curve is flattened into a polyline and a hairline corridor along it is
+    /// cut out of the polygon. Its ends are extended so a curve that crosses the
+    /// polygon always cuts it through.
+    ///
+    /// # Arguments
+    /// * `polygons` - Array of polygons (first = outer boundary, rest = holes)
+    /// * `control_points` - Bezier control points (3 = quadratic, 4 = cubic)
+    /// * `samples` - Line segments to flatten the curve into
+    ///
+    /// # Returns
+    /// Array of polygon fragments (or original if the curve doesn't split it)
+    #[func]
+    pub fn fracture_slice_curve(
+        polygons: Array<PackedVector2Array>,
+        control_points: PackedVector2Array,
+        samples: i32,
+    ) -> Array<PackedVector2Array> {
+        slice::fracture_curve(&Self::input(polygons), control_points.as_slice(), samples)
     }
 
     /// Fracture polygons using radial slice pattern.
@@ -65,7 +340,7 @@ impl CutoutDestructionProcessor {
     /// * `polygons` - Array of polygons (first = outer boundary, rest = holes)
     /// * `seed` - Random seed for angle variation
     /// * `slice_count` - Number of radial slices
-    /// * `origin` - Center point for radial slices (Vector2.ZERO = polygon center)
+    /// * `origin` - Center point for radial slices (Vector2.ZERO = polygon centroid)
     /// * `radial_randomness` - Random angle variation (0-1)
     #[func]
     pub fn fracture_slices_radial(
@@ -76,7 +351,7 @@ impl CutoutDestructionProcessor {
         radial_randomness: f32,
     ) -> Array<PackedVector2Array> {
         slice::fracture_slices_radial(
-            &polygons,
+            &Self::input(polygons),
             seed,
             slice_count,
             origin,
@@ -103,7 +378,7 @@ impl CutoutDestructionProcessor {
         parallel_angle_rand: f32,
     ) -> Array<PackedVector2Array> {
         slice::fracture_slices_parallel(
-            &polygons,
+            &Self::input(polygons),
             seed,
             slice_count,
             parallel_angle,
@@ -141,7 +416,7 @@ impl CutoutDestructionProcessor {
         grid_v_angle_rand: f32,
     ) -> Array<PackedVector2Array> {
         slice::fracture_slices_grid(
-            &polygons,
+            &Self::input(polygons),
             seed,
             grid_h_start,
             grid_v_start,
@@ -169,7 +444,7 @@ impl CutoutDestructionProcessor {
         slice_count: i32,
     ) -> Array<PackedVector2Array> {
         slice::fracture_slices_chaotic(
-            &polygons,
+            &Self::input(polygons),
             seed,
             slice_count,
         )
@@ -188,7 +463,78 @@ impl CutoutDestructionProcessor {
         polygons: Array<PackedVector2Array>,
         segments: Array<PackedVector2Array>,
     ) -> Array<PackedVector2Array> {
-        slice::fracture_slices_manual(&polygons, &segments)
+        slice::fracture_slices_manual(&Self::input(polygons), &segments)
+    }
+
+    /// Split an open polyline, such as a rope or a wall cross-section, along
+    /// manually-provided slice segments.
+    ///
+    /// The polyline is not treated as a polygon: it isn't closed and 2 points are
+    /// enough, so it doesn't need the 3-point outer boundary the slice methods do.
+    ///
+    /// # Arguments
+    /// * `polyline` - Open polyline (at least 2 points)
+    /// * `segments` - Slice lines; each element is a 2-point PackedVector2Array [a, b]
+    ///
+    /// # Returns
+    /// Sub-polylines in order along the input, sharing their cut points, or the
+    /// polyline as the only element if no segment crosses it
+    #[func]
+    pub fn fracture_polyline(
+        polyline: PackedVector2Array,
+        segments: Array<PackedVector2Array>,
+    ) -> Array<PackedVector2Array> {
+        if polyline.len() < 2 {
+            cutout_error!(
+                CutoutError::InvalidInput,
+                "fracture_polyline: a polyline needs at least 2 points (got {})",
+                polyline.len()
+            );
+            return Array::new();
+        }
+
+        let segments: Vec<(Vector2, Vector2)> = segments
+            .iter_shared()
+            .filter(|seg| seg.len() >= 2)
+            .map(|seg| (seg[0], seg[1]))
+            .collect();
+
+        let mut result = Array::new();
+        for piece in slice::split_polyline(polyline.as_slice(), &segments) {
+            result.push(&PackedVector2Array::from(piece.as_slice()));
+        }
+        result
+    }
+
+    /// Fracture polygons with branching cracks spreading from an impact point.
+    ///
+    /// Main cracks wander outward from the impact, randomly splitting off
+    /// shorter sub-branches. Fragments are smallest near the impact.
+    ///
+    /// # Arguments
+    /// * `polygons` - Array of polygons (first = outer boundary, rest = holes)
+    /// * `impact` - Point the cracks spread from
+    /// * `seed` - Random seed
+    /// * `branch_count` - Number of main cracks
+    /// * `branch_depth` - Levels of sub-branches allowed (0 = main cracks only)
+    /// * `branchiness` - Chance (0-1) of a sub-branch forming at each crack step
+    #[func]
+    pub fn fracture_cracks(
+        polygons: Array<PackedVector2Array>,
+        impact: Vector2,
+        seed: i64,
+        branch_count: i32,
+        branch_depth: i32,
+        branchiness: f32,
+    ) -> Array<PackedVector2Array> {
+        slice::fracture_cracks(
+            &Self::input(polygons),
+            impact,
+            seed,
+            branch_count,
+            branch_depth,
+            branchiness,
+        )
     }
 
     /// Optimized parallel slice fracture with projection-bound culling.
@@ -211,7 +557,7 @@ impl CutoutDestructionProcessor {
         parallel_angle_rand: f32,
     ) -> Array<PackedVector2Array> {
         slice::fracture_slices_parallel_optimized(
-            &polygons,
+            &Self::input(polygons),
             seed,
             slice_count,
             parallel_angle,
@@ -219,6 +565,510 @@ impl CutoutDestructionProcessor {
         )
     }
 
+    /// Check whether a fracture method actually split its input.
+    ///
+    /// # Arguments
+    /// * `polygons` - Polygons passed to the fracture method
+    /// * `fragments` - Fragments it returned
+    ///
+    /// # Returns
+    /// False if `fragments` is `polygons` unchanged (the fracture was a no-op)
+    #[func]
+    pub fn is_fractured(
+        polygons: Array<PackedVector2Array>,
+        fragments: Array<PackedVector2Array>,
+    ) -> bool {
+        polygons.len() != fragments.len()
+            || polygons
+                .iter_shared()
+                .zip(fragments.iter_shared())
+                .any(|(polygon, fragment)| polygon != fragment)
+    }
+
+    // ========================================================================
+    // Multi-Island Fracture Methods
+    // ========================================================================
+    //
+    // These take several separate solid shapes at once, each given as its own
+    // `[outer, holes...]` group (the layout `CutoutContourProcessor.classify_contours`
+    // returns). Each island is fractured independently and the fragments are
+    // concatenated in island order.
+
+    /// Fracture several islands using one Voronoi diagram.
+    ///
+    /// # Arguments
+    /// * `islands` - Array of `Array[PackedVector2Array]`, each `[outer, holes...]`
+    /// * `seed_points` - Pre-generated seed points for Voronoi cell centers, shared by all islands
+    ///
+    /// # Returns
+    /// Array of polygon fragments from every island
+    #[func]
+    pub fn fracture_voronoi_multi(
+        islands: Array<Variant>,
+        seed_points: PackedVector2Array,
+    ) -> Array<PackedVector2Array> {
+        Self::fracture_islands(&islands, |polygons| voronoi::fracture(polygons, &seed_points))
+    }
+
+    /// Fracture several islands along a line segment.
+    ///
+    /// # Arguments
+    /// * `islands` - Array of `Array[PackedVector2Array]`, each `[outer, holes...]`
+    /// * `line_start` - Start point of the slice line
+    /// * `line_end` - End point of the slice line
+    ///
+    /// # Returns
+    /// Array of polygon fragments from every island (islands the line misses stay whole)
+    #[func]
+    pub fn fracture_slice_multi(
+        islands: Array<Variant>,
+        line_start: Vector2,
+        line_end: Vector2,
+    ) -> Array<PackedVector2Array> {
+        Self::fracture_islands(&islands, |polygons| {
+            slice::fracture(polygons, line_start, line_end)
+        })
+    }
+
+    /// Fracture several islands using manually provided slice segments.
+    ///
+    /// # Arguments
+    /// * `islands` - Array of `Array[PackedVector2Array]`, each `[outer, holes...]`
+    /// * `segments` - Array of 2-point PackedVector2Arrays [start, end]
+    ///
+    /// # Returns
+    /// Array of polygon fragments from every island
+    #[func]
+    pub fn fracture_slices_manual_multi(
+        islands: Array<Variant>,
+        segments: Array<PackedVector2Array>,
+    ) -> Array<PackedVector2Array> {
+        Self::fracture_islands(&islands, |polygons| {
+            slice::fracture_slices_manual(polygons, &segments)
+        })
+    }
+
+    // ========================================================================
+    // Batch Fracture Methods
+    // ========================================================================
+    //
+    // These fracture many unrelated objects in one call, e.g. every destructible
+    // sprite hit by an explosion. Unlike the multi-island methods each set gets
+    // its own seeds and its own result group, and the sets are fractured in
+    // parallel on the rayon pool.
+
+    /// Fracture many polygon sets using Voronoi diagrams, one call for all of them.
+    ///
+    /// # Arguments
+    /// * `polygon_sets` - Array of `Array[PackedVector2Array]`, each `[outer, holes...]`
+    /// * `seed_point_sets` - Seed points for each polygon set, in the same order.
+    ///   An empty entry gets `BATCH_RANDOM_SEED_COUNT` random seeds instead.
+    /// * `seed` - Random seed for the generated seeds; set `i` uses `seed + i`
+    ///
+    /// # Returns
+    /// One `Array[PackedVector2Array]` of fragments per polygon set, in input
+    /// order, as `fracture_voronoi` would return for it. Entries that aren't an
+    /// `Array[PackedVector2Array]` are reported and get an empty group. Empty if
+    /// the two arrays differ in length.
+    #[func]
+    pub fn fracture_voronoi_batch(
+        polygon_sets: Array<Variant>,
+        seed_point_sets: Array<PackedVector2Array>,
+        seed: i64,
+    ) -> Array<Variant> {
+        if polygon_sets.len() != seed_point_sets.len() {
+            cutout_error!(
+                CutoutError::MismatchedCounts,
+                "Polygon set count ({}) doesn't match seed point set count ({})",
+                polygon_sets.len(),
+                seed_point_sets.len()
+            );
+            return Array::new();
+        }
+
+        // Copy everything into plain vectors so the sets can cross threads. A
+        // skipped set has no polygons, which fractures to nothing.
+        let jobs: Vec<(Vec<Vec<Vector2>>, Vec<Vector2>)> = polygon_sets
+            .iter_shared()
+            .zip(seed_point_sets.iter_shared())
+            .enumerate()
+            .map(|(i, (set, seeds))| {
+                let Ok(polygons) = set.try_to::<Array<PackedVector2Array>>() else {
+                    cutout_error!(
+                        CutoutError::InvalidInput,
+                        "Polygon set {} is not an Array[PackedVector2Array], skipping",
+                        i
+                    );
+                    return (Vec::new(), Vec::new());
+                };
+                let polygons =
+                    repair::repair_input(polygons.iter_shared().map(|p| p.to_vec()).collect());
+                let seeds = match polygons.first() {
+                    Some(outer) if seeds.is_empty() => seeds::generate_random(
+                        outer,
+                        Self::BATCH_RANDOM_SEED_COUNT,
+                        0.0,
+                        0.0,
+                        seed.wrapping_add(i as i64),
+                    ),
+                    _ => seeds.to_vec(),
+                };
+                (polygons, seeds)
+            })
+            .collect();
+
+        // Errors can't be printed from the workers, so hold them back until the join
+        let fragment_sets: Vec<(Vec<Vec<Vector2>>, Vec<CapturedError>)> = jobs
+            .par_iter()
+            .map(|(polygons, seeds)| error::capture(|| voronoi::fracture_rings(polygons, seeds)))
+            .collect();
+
+        let mut result = Array::new();
+        for (fragments, errors) in fragment_sets {
+            error::report(errors);
+            let mut group = Array::<PackedVector2Array>::new();
+            for fragment in fragments {
+                group.push(&PackedVector2Array::from(fragment.as_slice()));
+            }
+            result.push(&group.to_variant());
+        }
+        result
+    }
+
+    // ========================================================================
+    // Fragment Re-fracture Methods
+    // ========================================================================
+    //
+    // These take a single piece from a previous fracture (an outer ring with no
+    // holes) so it can be broken up again, e.g. when the same wall is hit twice.
+    // Fragments may be non-convex; all clipping goes through clipper2.
+
+    /// Re-fracture a single fragment using a Voronoi diagram.
+    ///
+    /// # Arguments
+    /// * `fragment` - A fragment from a previous fracture, treated as an outer with no holes
+    /// * `seed_points` - Pre-generated seed points for Voronoi cell centers
+    ///
+    /// # Returns
+    /// Array of polygon fragments
+    #[func]
+    pub fn fracture_fragment_voronoi(
+        fragment: PackedVector2Array,
+        seed_points: PackedVector2Array,
+    ) -> Array<PackedVector2Array> {
+        voronoi::fracture(&Self::fragment_polygons(fragment), &seed_points)
+    }
+
+    /// Re-fracture a single fragment along a line segment.
+    ///
+    /// See `fracture_slice` for the arguments.
+    #[func]
+    pub fn fracture_fragment_slice(
+        fragment: PackedVector2Array,
+        line_start: Vector2,
+        line_end: Vector2,
+    ) -> Array<PackedVector2Array> {
+        slice::fracture(&Self::fragment_polygons(fragment), line_start, line_end)
+    }
+
+    /// Re-fracture a single fragment using radial slice pattern.
+    ///
+    /// See `fracture_slices_radial` for the arguments.
+    #[func]
+    pub fn fracture_fragment_slices_radial(
+        fragment: PackedVector2Array,
+        seed: i64,
+        slice_count: i32,
+        origin: Vector2,
+        radial_randomness: f32,
+    ) -> Array<PackedVector2Array> {
+        slice::fracture_slices_radial(
+            &Self::fragment_polygons(fragment),
+            seed,
+            slice_count,
+            origin,
+            radial_randomness,
+        )
+    }
+
+    /// Re-fracture a single fragment using parallel slice pattern.
+    ///
+    /// See `fracture_slices_parallel` for the arguments.
+    #[func]
+    pub fn fracture_fragment_slices_parallel(
+        fragment: PackedVector2Array,
+        seed: i64,
+        slice_count: i32,
+        parallel_angle: f32,
+        parallel_angle_rand: f32,
+    ) -> Array<PackedVector2Array> {
+        slice::fracture_slices_parallel(
+            &Self::fragment_polygons(fragment),
+            seed,
+            slice_count,
+            parallel_angle,
+            parallel_angle_rand,
+        )
+    }
+
+    /// Re-fracture a single fragment using grid slice pattern.
+    ///
+    /// See `fracture_slices_grid` for the arguments.
+    #[func]
+    #[allow(clippy::too_many_arguments)] // GDExtension limitation - can't use structs
+    pub fn fracture_fragment_slices_grid(
+        fragment: PackedVector2Array,
+        seed: i64,
+        grid_h_start: f32,
+        grid_v_start: f32,
+        grid_h_slices: i32,
+        grid_v_slices: i32,
+        grid_h_random: f32,
+        grid_v_random: f32,
+        grid_h_angle_rand: f32,
+        grid_v_angle_rand: f32,
+    ) -> Array<PackedVector2Array> {
+        slice::fracture_slices_grid(
+            &Self::fragment_polygons(fragment),
+            seed,
+            grid_h_start,
+            grid_v_start,
+            grid_h_slices,
+            grid_v_slices,
+            grid_h_random,
+            grid_v_random,
+            grid_h_angle_rand,
+            grid_v_angle_rand,
+        )
+    }
+
+    /// Re-fracture a single fragment using chaotic slice pattern.
+    ///
+    /// See `fracture_slices_chaotic` for the arguments.
+    #[func]
+    pub fn fracture_fragment_slices_chaotic(
+        fragment: PackedVector2Array,
+        seed: i64,
+        slice_count: i32,
+    ) -> Array<PackedVector2Array> {
+        slice::fracture_slices_chaotic(&Self::fragment_polygons(fragment), seed, slice_count)
+    }
+
+    /// Re-fracture a single fragment using manually-provided slice segments.
+    ///
+    /// See `fracture_slices_manual` for the arguments.
+    #[func]
+    pub fn fracture_fragment_slices_manual(
+        fragment: PackedVector2Array,
+        segments: Array<PackedVector2Array>,
+    ) -> Array<PackedVector2Array> {
+        slice::fracture_slices_manual(&Self::fragment_polygons(fragment), &segments)
+    }
+
+    // ========================================================================
+    // Fragment Cleanup Methods
+    // ========================================================================
+
+    /// Merge tiny sliver fragments into their neighbours.
+    ///
+    /// Each fragment smaller than `min_fragment_area` is unioned into the largest
+    /// fragment it shares a boundary with, so no visible gap is left. Fragments
+    /// with no neighbour are dropped.
+    ///
+    /// # Arguments
+    /// * `fragments` - Fragments from any fracture method
+    /// * `min_fragment_area` - Minimum area (px²) a fragment must have to be kept as-is
+    ///
+    /// # Returns
+    /// The cleaned fragment array
+    #[func]
+    pub fn merge_small_fragments(
+        fragments: Array<PackedVector2Array>,
+        min_fragment_area: f32,
+    ) -> Array<PackedVector2Array> {
+        let input: Vec<Vec<Vector2>> = fragments.iter_shared().map(|f| f.to_vec()).collect();
+
+        let mut result = Array::new();
+        for fragment in merge::merge_small_fragments(input, min_fragment_area) {
+            result.push(&PackedVector2Array::from(fragment.as_slice()));
+        }
+        result
+    }
+
+    /// Remove redundant vertices from fragments for physics or rendering.
+    ///
+    /// Runs Ramer-Douglas-Peucker on every fragment, but keeps any vertex that
+    /// appears in more than one fragment, so neighbouring fragments stay gap-free.
+    /// Each fragment's epsilon is also capped at 5% of the square root of its
+    /// area, so small fragments keep their shape.
+    ///
+    /// # Arguments
+    /// * `fragments` - Fragments from any fracture method
+    /// * `epsilon` - Distance threshold in pixels; points closer than this to the
+    ///   simplified edge are removed
+    ///
+    /// # Returns
+    /// The simplified fragments, in the same order
+    #[func]
+    pub fn simplify_fragments(
+        fragments: Array<PackedVector2Array>,
+        epsilon: f32,
+    ) -> Array<PackedVector2Array> {
+        let input: Vec<Vec<Vector2>> = fragments.iter_shared().map(|f| f.to_vec()).collect();
+
+        let mut result = Array::new();
+        for fragment in reduce::simplify_fragments(&input, epsilon) {
+            result.push(&PackedVector2Array::from(fragment.as_slice()));
+        }
+        result
+    }
+
+    // ========================================================================
+    // Input Repair Methods
+    // ========================================================================
+
+    /// Split a self-intersecting or degenerate polygon into simple polygons.
+    ///
+    /// Removes repeated and collinear points and resolves self-intersections, so
+    /// Clipper2 doesn't clip the polygon into slivers.
+    ///
+    /// # Arguments
+    /// * `polygon` - Polygon vertices, open or explicitly closed
+    ///
+    /// # Returns
+    /// Each outer polygon (CCW) followed by its holes (CW), largest first; empty
+    /// if the polygon has no area
+    #[func]
+    pub fn repair_polygon(polygon: PackedVector2Array) -> Array<PackedVector2Array> {
+        let mut result = Array::new();
+        for ring in repair::repair_polygon(polygon.as_slice()) {
+            result.push(&PackedVector2Array::from(ring.as_slice()));
+        }
+        result
+    }
+
+    /// Make every fracture method repair its input polygons first.
+    ///
+    /// Off by default. Each outer boundary is reduced to its largest piece after
+    /// repair; to keep every piece, fracture the output of `repair_polygon` with
+    /// the multi-island methods instead. The setting is shared by all threads.
+    ///
+    /// Fracture methods that can't split the input return the repaired polygons,
+    /// so `is_fractured` can report a change even then.
+    #[func]
+    pub fn set_repair_input(enabled: bool) {
+        repair::set_repair_input(enabled);
+    }
+
+    /// Whether fracture methods repair their input (see `set_repair_input`).
+    #[func]
+    pub fn is_repair_input_enabled() -> bool {
+        repair::is_repair_input_enabled()
+    }
+
+    // ========================================================================
+    // Fragment UV Methods
+    // ========================================================================
+    //
+    // UVs are returned as one PackedVector2Array per fragment, parallel to its
+    // vertices, ready for `Polygon2D.uv`.
+
+    /// Compute texture coordinates for fragments through an explicit transform.
+    ///
+    /// # Arguments
+    /// * `fragments` - Fragments from any fracture method
+    /// * `uv_transform` - Maps a fragment vertex position to its texture coordinate
+    ///
+    /// # Returns
+    /// One UV array per fragment
+    #[func]
+    pub fn fragment_uvs(
+        fragments: Array<PackedVector2Array>,
+        uv_transform: Transform2D,
+    ) -> Array<PackedVector2Array> {
+        let mut result = Array::new();
+        for fragment in fragments.iter_shared() {
+            let uvs: Vec<Vector2> = fragment
+                .as_slice()
+                .iter()
+                .map(|p| uv_transform * *p)
+                .collect();
+            result.push(&PackedVector2Array::from(uvs.as_slice()));
+        }
+        result
+    }
+
+    /// Compute texture coordinates for fragments of a textured rectangle.
+    ///
+    /// Maps `source_rect` (the area the texture covers, e.g. the sprite's rect
+    /// or the original polygon's bounds) onto the texture. `Polygon2D.uv` is in
+    /// texture pixels, so pass the texture size there; pass `Vector2(1, 1)` for
+    /// normalized 0-1 UVs.
+    ///
+    /// # Arguments
+    /// * `fragments` - Fragments from any fracture method
+    /// * `source_rect` - Area covered by the texture, in fragment coordinates
+    /// * `texture_size` - Size the rect maps onto
+    ///
+    /// # Returns
+    /// One UV array per fragment, or empty if `source_rect` has no area
+    #[func]
+    pub fn fragment_uvs_from_rect(
+        fragments: Array<PackedVector2Array>,
+        source_rect: Rect2,
+        texture_size: Vector2,
+    ) -> Array<PackedVector2Array> {
+        let Some(uv_transform) = rect_mapping(source_rect, texture_size) else {
+            cutout_error!(
+                CutoutError::InvalidInput,
+                "fragment_uvs_from_rect: source rect {:?} has no area",
+                source_rect
+            );
+            return Array::new();
+        };
+        Self::fragment_uvs(fragments, uv_transform)
+    }
+
+    // ========================================================================
+    // Fragment Physics Methods
+    // ========================================================================
+
+    /// Compute rigid body properties of a fragment.
+    ///
+    /// # Arguments
+    /// * `fragment` - Fragment outline, any winding
+    /// * `density` - Mass per unit area
+    ///
+    /// # Returns
+    /// Dictionary with keys `area`, `mass`, `centroid` and `inertia` (moment of
+    /// inertia about the centroid, for `RigidBody2D.inertia`). Empty if the
+    /// fragment has no area.
+    #[func]
+    pub fn fragment_physics_properties(fragment: PackedVector2Array, density: f32) -> Dictionary {
+        Self::physics_properties(fragment.as_slice(), &[], density)
+    }
+
+    /// Compute rigid body properties of a fragment with holes.
+    ///
+    /// The holes' contributions are subtracted from the outer ring's.
+    ///
+    /// # Arguments
+    /// * `fragment` - Fragment outline, any winding
+    /// * `holes` - Holes inside the fragment, any winding
+    /// * `density` - Mass per unit area
+    ///
+    /// # Returns
+    /// Same dictionary as `fragment_physics_properties`
+    #[func]
+    pub fn fragment_physics_properties_with_holes(
+        fragment: PackedVector2Array,
+        holes: Array<PackedVector2Array>,
+        density: f32,
+    ) -> Dictionary {
+        let holes: Vec<Vec<Vector2>> = holes.iter_shared().map(|h| h.to_vec()).collect();
+        Self::physics_properties(fragment.as_slice(), &holes, density)
+    }
+
     // ========================================================================
     // Seed Generation Methods
     // ========================================================================
@@ -234,14 +1084,16 @@ impl CutoutDestructionProcessor {
         edge_padding: f32,
         seed: i64,
     ) -> PackedVector2Array {
-        let poly: Vec<Vector2> = polygon.to_vec();
-        let result = seeds::generate_random(&poly, fragment_count, min_cell_distance, edge_padding, seed);
+        let poly = polygon.as_slice();
+        let result = seeds::generate_random(poly, fragment_count, min_cell_distance, edge_padding, seed);
         PackedVector2Array::from(result.as_slice())
     }
 
     /// Generate grid-based seed points with jitter.
     ///
-    /// Creates organized destruction patterns (tiles, bricks).
+    /// Creates organized destruction patterns (tiles, bricks). Each cell's jitter
+    /// depends only on the seed and the cell's row and column, so an edit that
+    /// keeps the polygon's bounds leaves every surviving seed where it was.
     #[func]
     pub fn generate_grid_seeds(
         polygon: PackedVector2Array,
@@ -252,8 +1104,29 @@ impl CutoutDestructionProcessor {
         edge_padding: f32,
         seed: i64,
     ) -> PackedVector2Array {
-        let poly: Vec<Vector2> = polygon.to_vec();
-        let result = seeds::generate_grid(&poly, rows, cols, jitter, min_cell_distance, edge_padding, seed);
+        let poly = polygon.as_slice();
+        let result = seeds::generate_grid(poly, rows, cols, jitter, min_cell_distance, edge_padding, seed);
+        PackedVector2Array::from(result.as_slice())
+    }
+
+    /// Generate hexagonally packed seed points with jitter.
+    ///
+    /// Creates honeycomb destruction patterns: alternate rows are offset by half a
+    /// cell and spaced `sqrt(3)/2` of a cell apart, so unjittered seeds give
+    /// near-regular hexagons. `cols` sets the cell width; rows are centred on the
+    /// polygon, so use enough of them to cover its height.
+    #[func]
+    pub fn generate_hex_seeds(
+        polygon: PackedVector2Array,
+        rows: i32,
+        cols: i32,
+        jitter: f32,
+        min_cell_distance: f32,
+        edge_padding: f32,
+        seed: i64,
+    ) -> PackedVector2Array {
+        let poly = polygon.as_slice();
+        let result = seeds::generate_hex(poly, rows, cols, jitter, min_cell_distance, edge_padding, seed);
         PackedVector2Array::from(result.as_slice())
     }
 
@@ -272,9 +1145,9 @@ impl CutoutDestructionProcessor {
         min_cell_distance: f32,
         seed: i64,
     ) -> PackedVector2Array {
-        let poly: Vec<Vector2> = polygon.to_vec();
+        let poly = polygon.as_slice();
         let result = seeds::generate_radial(
-            &poly, origin, ring_count, ring_size, points_per_ring,
+            poly, origin, ring_count, ring_size, points_per_ring,
             radial_variation, min_cell_distance, seed,
         );
         PackedVector2Array::from(result.as_slice())
@@ -295,9 +1168,9 @@ impl CutoutDestructionProcessor {
         min_cell_distance: f32,
         seed: i64,
     ) -> PackedVector2Array {
-        let poly: Vec<Vector2> = polygon.to_vec();
+        let poly = polygon.as_slice();
         let result = seeds::generate_spiderweb(
-            &poly, origin, ring_count, ring_size, points_per_ring,
+            poly, origin, ring_count, ring_size, points_per_ring,
             radial_variation, min_cell_distance, seed,
         );
         PackedVector2Array::from(result.as_slice())
@@ -315,11 +1188,179 @@ impl CutoutDestructionProcessor {
         poisson_attempts: i32,
         seed: i64,
     ) -> PackedVector2Array {
-        let poly: Vec<Vector2> = polygon.to_vec();
+        let poly = polygon.as_slice();
         let result = seeds::generate_poisson(
-            &poly, fragment_count, min_cell_distance, edge_padding,
+            poly, fragment_count, min_cell_distance, edge_padding,
             poisson_attempts, seed,
         );
         PackedVector2Array::from(result.as_slice())
     }
+
+    /// Generate seed points clustered around an impact point.
+    ///
+    /// Seeds get denser towards the impact, giving small shards near the hit
+    /// and large chunks far from it when used with `fracture_voronoi`.
+    ///
+    /// # Arguments
+    /// * `polygon` - Outer boundary to place seeds in
+    /// * `impact` - Point the seeds cluster around
+    /// * `fragment_count` - Target number of seeds
+    /// * `falloff` - How strongly seeds cluster (0 = uniform, 2-4 = strong)
+    /// * `min_cell_distance` - Minimum spacing as a fraction of the bounds' smaller side
+    /// * `seed` - Random seed
+    #[func]
+    pub fn generate_impact_seeds(
+        polygon: PackedVector2Array,
+        impact: Vector2,
+        fragment_count: i32,
+        falloff: f32,
+        min_cell_distance: f32,
+        seed: i64,
+    ) -> PackedVector2Array {
+        let poly = polygon.as_slice();
+        let result = seeds::generate_impact(
+            poly, impact, fragment_count, falloff, min_cell_distance, seed,
+        );
+        PackedVector2Array::from(result.as_slice())
+    }
+
+    /// Generate seed points in a ring around an origin.
+    ///
+    /// Seeds fall only between `inner_radius` and `outer_radius`, so
+    /// `fracture_voronoi` shatters a ring (an explosion or shockwave front) and
+    /// leaves the centre as a single piece.
+    ///
+    /// # Arguments
+    /// * `polygon` - Outer boundary to place seeds in
+    /// * `origin` - Centre of the ring
+    /// * `inner_radius` - Distance from `origin` where the ring starts
+    /// * `outer_radius` - Distance from `origin` where the ring ends (greater than `inner_radius`)
+    /// * `fragment_count` - Target number of seeds
+    /// * `min_cell_distance` - Minimum spacing as a fraction of the bounds' smaller side
+    /// * `seed` - Random seed
+    #[func]
+    #[allow(clippy::too_many_arguments)] // GDExtension limitation - can't use structs
+    pub fn generate_annulus_seeds(
+        polygon: PackedVector2Array,
+        origin: Vector2,
+        inner_radius: f32,
+        outer_radius: f32,
+        fragment_count: i32,
+        min_cell_distance: f32,
+        seed: i64,
+    ) -> PackedVector2Array {
+        let poly = polygon.as_slice();
+        let result = seeds::generate_annulus(
+            poly, origin, inner_radius, outer_radius, fragment_count,
+            min_cell_distance, seed,
+        );
+        PackedVector2Array::from(result.as_slice())
+    }
+
+    /// Generate seed points weighted by distance from the polygon's edges.
+    ///
+    /// Samples only the polygon's interior, favouring points far from its
+    /// edges. Use for thin or hollow shapes (crescents, rings, letters) where
+    /// `generate_random_seeds` returns far fewer seeds than requested.
+    ///
+    /// # Arguments
+    /// * `polygon` - Outer boundary to place seeds in
+    /// * `fragment_count` - Target number of seeds
+    /// * `seed` - Random seed
+    #[func]
+    pub fn generate_distance_weighted_seeds(
+        polygon: PackedVector2Array,
+        fragment_count: i32,
+        seed: i64,
+    ) -> PackedVector2Array {
+        let result = seeds::generate_distance_weighted(polygon.as_slice(), fragment_count, seed);
+        PackedVector2Array::from(result.as_slice())
+    }
+
+    /// Relax seed points towards the centroids of their Voronoi cells (Lloyd's algorithm).
+    ///
+    /// Evens out cell sizes, especially for random seeds.
+    ///
+    /// # Arguments
+    /// * `polygon` - Outer boundary the cells are clipped to
+    /// * `seed_points` - Seed points from any of the generate methods
+    /// * `iterations` - Number of relaxation steps (1-5 is usually enough)
+    ///
+    /// # Returns
+    /// Relaxed seed points, in the same order as the input
+    #[func]
+    pub fn relax_seeds(
+        polygon: PackedVector2Array,
+        seed_points: PackedVector2Array,
+        iterations: i32,
+    ) -> PackedVector2Array {
+        let poly = polygon.as_slice();
+        let seeds = seed_points.as_slice();
+        let result = voronoi::relax_seeds(poly, seeds, iterations);
+        PackedVector2Array::from(result.as_slice())
+    }
+}
+
+impl CutoutDestructionProcessor {
+    /// Run a fracture on each island and concatenate the fragments
+    ///
+    /// Entries that aren't an `Array[PackedVector2Array]` are reported and skipped.
+    fn fracture_islands(
+        islands: &Array<Variant>,
+        fracture: impl Fn(&Array<PackedVector2Array>) -> Array<PackedVector2Array>,
+    ) -> Array<PackedVector2Array> {
+        let mut result = Array::new();
+
+        for (i, island) in islands.iter_shared().enumerate() {
+            let Ok(polygons) = island.try_to::<Array<PackedVector2Array>>() else {
+                cutout_error!(
+                    CutoutError::InvalidInput,
+                    "Island {} is not an Array[PackedVector2Array], skipping",
+                    i
+                );
+                continue;
+            };
+
+            for fragment in fracture(&Self::input(polygons)).iter_shared() {
+                result.push(&fragment);
+            }
+        }
+
+        result
+    }
+
+    /// Build the physics properties dictionary for a fragment
+    fn physics_properties(outer: &[Vector2], holes: &[Vec<Vector2>], density: f32) -> Dictionary {
+        let mut result = Dictionary::new();
+        let Some(properties) = area_properties(outer, holes) else {
+            return result;
+        };
+
+        result.set("area", properties.area);
+        result.set("mass", properties.area * density);
+        result.set("centroid", properties.centroid);
+        result.set("inertia", properties.inertia * density);
+        result
+    }
+
+    /// Wrap a single fragment as a polygon set with no holes
+    fn fragment_polygons(fragment: PackedVector2Array) -> Array<PackedVector2Array> {
+        let mut polygons = Array::new();
+        polygons.push(&fragment);
+        Self::input(polygons)
+    }
+
+    /// Repair fracture input in `[outer, holes...]` layout, if `set_repair_input` turned it on
+    pub(crate) fn input(polygons: Array<PackedVector2Array>) -> Array<PackedVector2Array> {
+        if !repair::is_repair_input_enabled() {
+            return polygons;
+        }
+
+        let rings: Vec<Vec<Vector2>> = polygons.iter_shared().map(|p| p.to_vec()).collect();
+        let mut result = Array::new();
+        for ring in repair::repair_input(rings) {
+            result.push(&PackedVector2Array::from(ring.as_slice()));
+        }
+        result
+    }
 }
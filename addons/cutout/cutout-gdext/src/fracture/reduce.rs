@@ -0,0 +1,61 @@
+//! Post-fracture vertex reduction
+//!
+//! Clipping through clipper2 leaves fragments with runs of near-collinear
+//! vertices along the original outline. This module runs RDP over every
+//! fragment without opening gaps between neighbours:
+//! 1. Vertices found in more than one fragment (seam ends, shared corners) are
+//!    pinned, so both sides of every seam keep exactly the same points
+//! 2. Each fragment's epsilon is capped at a fraction of its own size, so small
+//!    fragments aren't collapsed by a tolerance picked for large ones
+//! 3. Everything between pinned vertices is simplified with plain RDP
+
+use super::geometry::{polygon_area, SNAP_EPSILON};
+use crate::simplify::rdp;
+use godot::prelude::*;
+use std::collections::HashMap;
+
+/// Largest epsilon used for a fragment, as a fraction of the square root of its area
+const MAX_EPSILON_FRACTION: f32 = 0.05;
+
+/// Simplify every fragment with RDP, keeping the vertices fragments share.
+///
+/// # Arguments
+/// * `fragments` - Fragments from any fracture function
+/// * `epsilon` - RDP distance threshold, capped per fragment by its size
+///
+/// # Returns
+/// The simplified fragments, in the same order. A fragment that would drop
+/// below 3 points is returned unchanged.
+pub fn simplify_fragments(fragments: &[Vec<Vector2>], epsilon: f32) -> Vec<Vec<Vector2>> {
+    // Fragment vertices come off clipper2's fixed-point grid, so shared ones match there
+    let key = |p: Vector2| {
+        (
+            (p.x / SNAP_EPSILON).round() as i64,
+            (p.y / SNAP_EPSILON).round() as i64,
+        )
+    };
+
+    let mut owners: HashMap<(i64, i64), (usize, bool)> = HashMap::new();
+    for (i, fragment) in fragments.iter().enumerate() {
+        for &point in fragment {
+            owners
+                .entry(key(point))
+                .and_modify(|(first, shared)| *shared |= *first != i)
+                .or_insert((i, false));
+        }
+    }
+    let is_shared = |p: Vector2| owners.get(&key(p)).is_some_and(|&(_, shared)| shared);
+
+    fragments
+        .iter()
+        .map(|fragment| {
+            let limit = polygon_area(fragment).abs().sqrt() * MAX_EPSILON_FRACTION;
+            let simplified = rdp::simplify_pinned(fragment, epsilon.min(limit), is_shared);
+            if simplified.len() >= 3 {
+                simplified
+            } else {
+                fragment.clone()
+            }
+        })
+        .collect()
+}
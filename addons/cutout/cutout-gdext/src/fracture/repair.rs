@@ -0,0 +1,133 @@
+//! Repair of invalid fracture input
+//!
+//! Moore Neighbour contours and hand-authored polygons can repeat points or cross
+//! themselves, which Clipper2 doesn't reject but clips into slivers. Repair:
+//! 1. Drops repeated points, and points lying on the line through their
+//!    neighbours (collinear points and zero-width spikes)
+//! 2. Unions the ring with itself (`clipper2`, NonZero fill), splitting it into
+//!    simple rings where it crosses itself
+//! 3. Cleans the resulting rings again and groups them into outers and holes
+//!
+//! The fracture entry points run this on their input when turned on with
+//! `set_repair_input`. The setting is shared by all threads.
+
+use super::clipper_utils::clipper2_self_union;
+use crate::contour::classify::{classify_contours, flatten_shapes, orient, ContourShape};
+use godot::prelude::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Distance from the line through its neighbours below which a point is redundant
+const REDUNDANT_POINT_TOLERANCE: f32 = 1e-4;
+
+static REPAIR_INPUT: AtomicBool = AtomicBool::new(false);
+
+/// Turn repair of fracture input on or off
+pub fn set_repair_input(enabled: bool) {
+    REPAIR_INPUT.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether the fracture entry points repair their input
+pub fn is_repair_input_enabled() -> bool {
+    REPAIR_INPUT.load(Ordering::Relaxed)
+}
+
+/// Split a possibly self-intersecting ring into simple rings.
+///
+/// # Arguments
+/// * `polygon` - Ring in any winding, open or explicitly closed
+///
+/// # Returns
+/// Each outer ring (CCW) followed by its holes (CW), largest shape first. Empty
+/// if nothing with area is left.
+pub fn repair_polygon(polygon: &[Vector2]) -> Vec<Vec<Vector2>> {
+    flatten_shapes(repair_shapes(polygon))
+}
+
+/// Repair fracture input in `[outer, holes...]` layout, if turned on.
+///
+/// The outer ring keeps only its largest piece, since the fracture functions
+/// take a single outer; each hole keeps all of its pieces. Input whose outer
+/// ring has no area left is returned unchanged, for the fracture to reject.
+pub fn repair_input(polygons: Vec<Vec<Vector2>>) -> Vec<Vec<Vector2>> {
+    if !is_repair_input_enabled() {
+        return polygons;
+    }
+
+    let Some((outer, holes)) = polygons.split_first() else {
+        return polygons;
+    };
+    let Some(shape) = repair_shapes(outer).into_iter().next() else {
+        return polygons;
+    };
+
+    let mut rings = shape.into_rings();
+    for hole in holes {
+        for mut piece in repair_shapes(hole).into_iter().map(|shape| shape.outer) {
+            orient(&mut piece, false);
+            rings.push(piece);
+        }
+    }
+    rings
+}
+
+/// Repair a ring into shapes, largest first
+fn repair_shapes(polygon: &[Vector2]) -> Vec<ContourShape> {
+    let cleaned = remove_redundant_points(polygon);
+    if cleaned.is_empty() {
+        return Vec::new();
+    }
+
+    let pieces: Vec<Vec<Vector2>> = clipper2_self_union(&cleaned)
+        .iter()
+        .map(|piece| remove_redundant_points(piece))
+        .filter(|piece| !piece.is_empty())
+        .collect();
+
+    classify_contours(pieces)
+}
+
+/// Drop repeated points and points on the line through their neighbours.
+///
+/// Removing a point can make its neighbours redundant in turn, so passes repeat
+/// until nothing changes. Returns an open ring, or empty if fewer than 3 points
+/// are left.
+fn remove_redundant_points(ring: &[Vector2]) -> Vec<Vector2> {
+    let mut points = ring.to_vec();
+
+    loop {
+        let count = points.len();
+        if count < 3 {
+            return Vec::new();
+        }
+
+        // Compare against the points kept so far, so a point and its repeat are
+        // never both dropped
+        let mut kept: Vec<Vector2> = Vec::with_capacity(count);
+        for i in 0..count {
+            let prev = kept.last().copied().unwrap_or(points[count - 1]);
+            let next = match i + 1 {
+                n if n == count => kept.first().copied().unwrap_or(points[0]),
+                n => points[n],
+            };
+            if !is_redundant(prev, points[i], next) {
+                kept.push(points[i]);
+            }
+        }
+
+        if kept.len() == count {
+            return kept;
+        }
+        points = kept;
+    }
+}
+
+/// True if `point` lies on the line through `prev` and `next`, which covers
+/// repeats of either and spikes doubling back on themselves
+fn is_redundant(prev: Vector2, point: Vector2, next: Vector2) -> bool {
+    let base = next - prev;
+    let length = base.length();
+    if length <= REDUNDANT_POINT_TOLERANCE {
+        return true;
+    }
+    base.cross(point - prev).abs() / length <= REDUNDANT_POINT_TOLERANCE
+}
@@ -1,52 +1,58 @@
 //! Seed point generation for Voronoi fracturing
 //!
-//! Provides 5 seed distribution patterns:
+//! Provides 9 seed distribution patterns:
 //! - Random: Pure random distribution for natural shattering
 //! - Grid: Grid-based with jitter for organized destruction
+//! - Hex: Grid with alternate rows offset, for honeycomb cells
 //! - Radial: Concentric rings for impact/explosion patterns
 //! - Spiderweb: Radial rays + rings for cracked glass
 //! - Poisson Disk: Blue noise for high-quality natural fractures
+//! - Impact: Random, but clustered around an impact point for shattering hits
+//! - Annulus: Random within a ring around an origin, for shockwaves that leave
+//!   the centre intact
+//! - Distance Weighted: Sampled from the polygon's interior, away from its edges,
+//!   for thin or hollow shapes where rejection sampling runs out of attempts
 //!
 //! Reference GDScript: addons/cutout/resources/destruction/cutout_destruction_voronoi.gd
 
-use super::geometry::{calculate_bounds, grow_rect, is_far_enough, point_in_polygon};
+use super::geometry::{calculate_bounds, grow_rect, point_in_polygon, polygon_center, SpatialHash};
+use crate::common::rng::Rng;
+use crate::common::Grid2D;
+use crate::error::{cutout_error, CutoutError};
 use godot::prelude::*;
 
-/// Simple deterministic RNG (xorshift64) for seed generation.
+/// Shrink a polygon's bounds by `edge_padding`, the area seeds are sampled from.
 ///
-/// Avoids depending on external crate just for RNG - we only need uniform f64/f32.
-struct Rng {
-    state: u64,
-}
-
-impl Rng {
-    fn new(seed: i64) -> Self {
-        // Ensure non-zero state
-        let state = if seed == 0 { 0xDEAD_BEEF_CAFE_BABE } else { seed as u64 };
-        Self { state }
-    }
+/// Returns None if there is no area to sample. That is reported when the
+/// padding is at least half the bounds' smaller side; a degenerate polygon
+/// simply gets no seeds.
+fn padded_bounds(bounds: Rect2, edge_padding: f32, generator: &str) -> Option<Rect2> {
+    let padded = grow_rect(bounds, -edge_padding);
 
-    fn next_u64(&mut self) -> u64 {
-        self.state ^= self.state << 13;
-        self.state ^= self.state >> 7;
-        self.state ^= self.state << 17;
-        self.state
+    if padded.size.x <= 0.0 || padded.size.y <= 0.0 {
+        if bounds.size.x > 0.0 && bounds.size.y > 0.0 {
+            cutout_error!(
+                CutoutError::InvalidInput,
+                "{}: edge_padding {} leaves no room inside a {}x{} polygon, using a single center seed",
+                generator,
+                edge_padding,
+                bounds.size.x,
+                bounds.size.y
+            );
+        }
+        return None;
     }
 
-    /// Returns a float in [0, 1)
-    fn randf(&mut self) -> f32 {
-        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
-    }
+    Some(padded)
+}
 
-    /// Returns a float in [min, max)
-    fn randf_range(&mut self, min: f32, max: f32) -> f32 {
-        min + self.randf() * (max - min)
+/// A single seed at the polygon's centre (see `polygon_center`).
+fn center_seed(polygon: &[Vector2], bounds: Rect2) -> Vec<Vector2> {
+    if bounds.size.x <= 0.0 || bounds.size.y <= 0.0 {
+        return Vec::new();
     }
 
-    /// Returns an integer in [0, max)
-    fn randi_range(&mut self, max: usize) -> usize {
-        (self.next_u64() as usize) % max
-    }
+    vec![polygon_center(polygon)]
 }
 
 /// Generate purely random seed points within a polygon.
@@ -57,17 +63,19 @@ pub fn generate_random(
     edge_padding: f32,
     seed: i64,
 ) -> Vec<Vector2> {
-    let mut rng = Rng::new(seed);
-    let bounds = calculate_bounds(polygon);
-    let padded = grow_rect(bounds, -edge_padding);
-
-    if padded.size.x <= 0.0 || padded.size.y <= 0.0 {
+    if fragment_count <= 0 {
         return Vec::new();
     }
 
+    let mut rng = Rng::new(seed);
+    let bounds = calculate_bounds(polygon);
+    let Some(padded) = padded_bounds(bounds, edge_padding, "generate_random") else {
+        return center_seed(polygon, bounds);
+    };
+
     let min_dist = padded.size.x.min(padded.size.y) * min_cell_distance;
     let max_attempts = fragment_count as usize * 10;
-    let mut points = Vec::new();
+    let mut points = SpatialHash::new(min_dist);
 
     for _ in 0..max_attempts {
         if points.len() >= fragment_count as usize {
@@ -79,15 +87,19 @@ pub fn generate_random(
             rng.randf_range(padded.position.y, padded.position.y + padded.size.y),
         );
 
-        if point_in_polygon(candidate, polygon) && is_far_enough(candidate, &points, min_dist) {
-            points.push(candidate);
+        if point_in_polygon(candidate, polygon) && points.is_far_enough(candidate) {
+            points.insert(candidate);
         }
     }
 
-    points
+    points.into_points()
 }
 
 /// Generate grid-based seed points with optional jitter.
+///
+/// Each cell's jitter comes from its own generator keyed on `(seed, row, col)`,
+/// so its offset doesn't change with the number of cells or with which other
+/// cells fall outside the polygon.
 pub fn generate_grid(
     polygon: &[Vector2],
     rows: i32,
@@ -97,38 +109,121 @@ pub fn generate_grid(
     edge_padding: f32,
     seed: i64,
 ) -> Vec<Vector2> {
-    let mut rng = Rng::new(seed);
-    let bounds = calculate_bounds(polygon);
-    let padded = grow_rect(bounds, -edge_padding);
+    generate_lattice(
+        polygon,
+        rows,
+        cols,
+        jitter,
+        min_cell_distance,
+        edge_padding,
+        seed,
+        Lattice::Square,
+    )
+}
 
-    if padded.size.x <= 0.0 || padded.size.y <= 0.0 {
-        return Vec::new();
-    }
+/// Generate hexagonally packed seed points with optional jitter.
+///
+/// Like `generate_grid`, but alternate rows are shifted by half a cell and rows
+/// are `sqrt(3)/2` of a cell width apart, so unjittered seeds give near-regular
+/// hexagonal Voronoi cells. `cols` sets the cell width across the padded bounds;
+/// the rows are centred vertically and any that fall outside the polygon are
+/// dropped, so pick `rows` to cover its height.
+pub fn generate_hex(
+    polygon: &[Vector2],
+    rows: i32,
+    cols: i32,
+    jitter: f32,
+    min_cell_distance: f32,
+    edge_padding: f32,
+    seed: i64,
+) -> Vec<Vector2> {
+    generate_lattice(
+        polygon,
+        rows,
+        cols,
+        jitter,
+        min_cell_distance,
+        edge_padding,
+        seed,
+        Lattice::Hex,
+    )
+}
+
+/// Cell arrangement for `generate_lattice`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Lattice {
+    Square,
+    Hex,
+}
+
+/// One jittered seed per lattice cell, kept if inside the polygon and far enough
+/// from the seeds already placed.
+#[allow(clippy::too_many_arguments)] // generate_grid's arguments plus the lattice
+fn generate_lattice(
+    polygon: &[Vector2],
+    rows: i32,
+    cols: i32,
+    jitter: f32,
+    min_cell_distance: f32,
+    edge_padding: f32,
+    seed: i64,
+    lattice: Lattice,
+) -> Vec<Vector2> {
+    let generator = match lattice {
+        Lattice::Square => "generate_grid",
+        Lattice::Hex => "generate_hex",
+    };
+    let bounds = calculate_bounds(polygon);
+    let Some(padded) = padded_bounds(bounds, edge_padding, generator) else {
+        return center_seed(polygon, bounds);
+    };
 
     let min_dist = padded.size.x.min(padded.size.y) * min_cell_distance;
-    let cell_size = Vector2::new(padded.size.x / cols as f32, padded.size.y / rows as f32);
-    let mut points = Vec::new();
+    let cell_width = padded.size.x / cols as f32;
+    let (cell_size, top) = match lattice {
+        Lattice::Square => (
+            Vector2::new(cell_width, padded.size.y / rows as f32),
+            padded.position.y,
+        ),
+        Lattice::Hex => {
+            let row_height = cell_width * 3.0_f32.sqrt() / 2.0;
+            let centre = padded.position.y + padded.size.y / 2.0;
+            (
+                Vector2::new(cell_width, row_height),
+                centre - row_height * rows as f32 / 2.0,
+            )
+        }
+    };
+    let mut points = SpatialHash::new(min_dist);
 
     for y in 0..rows {
+        // Hex rows sit a quarter cell either side of the square columns, half a
+        // cell apart from each other
+        let row_shift = match lattice {
+            Lattice::Square => 0.0,
+            Lattice::Hex if y % 2 == 0 => -0.25,
+            Lattice::Hex => 0.25,
+        };
+
         for x in 0..cols {
+            let mut rng = Rng::for_cell(seed, y, x);
             let jitter_offset = Vector2::new(
                 rng.randf_range(-0.5, 0.5) * cell_size.x * jitter,
                 rng.randf_range(-0.5, 0.5) * cell_size.y * jitter,
             );
 
             let candidate = Vector2::new(
-                padded.position.x + (x as f32 + 0.5) * cell_size.x + jitter_offset.x,
-                padded.position.y + (y as f32 + 0.5) * cell_size.y + jitter_offset.y,
+                padded.position.x + (x as f32 + 0.5 + row_shift) * cell_size.x + jitter_offset.x,
+                top + (y as f32 + 0.5) * cell_size.y + jitter_offset.y,
             );
 
-            if point_in_polygon(candidate, polygon) && is_far_enough(candidate, &points, min_dist)
-            {
-                points.push(candidate);
+            if point_in_polygon(candidate, polygon) && points.is_far_enough(candidate) {
+                points.insert(candidate);
             }
         }
     }
 
-    points
+    points.into_points()
 }
 
 /// Generate radial seed points in concentric rings.
@@ -146,7 +241,7 @@ pub fn generate_radial(
     let mut rng = Rng::new(seed);
     let bounds = calculate_bounds(polygon);
     let center = if origin == Vector2::ZERO {
-        bounds.position + bounds.size * 0.5
+        polygon_center(polygon)
     } else {
         origin
     };
@@ -165,7 +260,7 @@ pub fn generate_radial(
         .map(|c| (*c - center).length())
         .fold(0.0f32, f32::max);
 
-    let mut points = Vec::new();
+    let mut points = SpatialHash::new(min_dist);
 
     for ring_idx in 0..ring_count {
         let ring_number = (ring_idx + 1) as f32;
@@ -190,14 +285,14 @@ pub fn generate_radial(
                 center + Vector2::new(final_angle.cos(), final_angle.sin()) * radius;
 
             if point_in_polygon(candidate, polygon)
-                && is_far_enough(candidate, &points, min_dist)
+                && points.is_far_enough(candidate)
             {
-                points.push(candidate);
+                points.insert(candidate);
             }
         }
     }
 
-    points
+    points.into_points()
 }
 
 /// Generate spiderweb seed points (radial rays + concentric rings).
@@ -215,7 +310,7 @@ pub fn generate_spiderweb(
     let mut rng = Rng::new(seed);
     let bounds = calculate_bounds(polygon);
     let center = if origin == Vector2::ZERO {
-        bounds.position + bounds.size * 0.5
+        polygon_center(polygon)
     } else {
         origin
     };
@@ -234,11 +329,11 @@ pub fn generate_spiderweb(
         .map(|c| (*c - center).length())
         .fold(0.0f32, f32::max);
 
-    let mut points = Vec::new();
+    let mut points = SpatialHash::new(min_dist);
 
     // Add center point
     if point_in_polygon(center, polygon) {
-        points.push(center);
+        points.insert(center);
     }
 
     // Generate spokes with seeds at each ring intersection
@@ -262,14 +357,14 @@ pub fn generate_spiderweb(
                 center + Vector2::new(final_angle.cos(), final_angle.sin()) * final_radius;
 
             if point_in_polygon(candidate, polygon)
-                && is_far_enough(candidate, &points, min_dist)
+                && points.is_far_enough(candidate)
             {
-                points.push(candidate);
+                points.insert(candidate);
             }
         }
     }
 
-    points
+    points.into_points()
 }
 
 /// Generate Poisson disk distributed seed points (blue noise).
@@ -281,18 +376,20 @@ pub fn generate_poisson(
     poisson_attempts: i32,
     seed: i64,
 ) -> Vec<Vector2> {
-    let mut rng = Rng::new(seed);
-    let bounds = calculate_bounds(polygon);
-    let padded = grow_rect(bounds, -edge_padding);
-
-    if padded.size.x <= 0.0 || padded.size.y <= 0.0 {
+    if fragment_count <= 0 {
         return Vec::new();
     }
 
+    let mut rng = Rng::new(seed);
+    let bounds = calculate_bounds(polygon);
+    let Some(padded) = padded_bounds(bounds, edge_padding, "generate_poisson") else {
+        return center_seed(polygon, bounds);
+    };
+
     let min_dist = padded.size.x.min(padded.size.y) * min_cell_distance;
-    let max_total_attempts = fragment_count as usize * poisson_attempts as usize;
+    let max_total_attempts = fragment_count as usize * poisson_attempts.max(0) as usize;
 
-    let mut points = Vec::new();
+    let mut points = SpatialHash::new(min_dist);
     let mut active_list: Vec<Vector2> = Vec::new();
 
     // Start with random first point
@@ -302,7 +399,7 @@ pub fn generate_poisson(
     );
 
     if point_in_polygon(first, polygon) {
-        points.push(first);
+        points.insert(first);
         active_list.push(first);
     }
 
@@ -313,7 +410,7 @@ pub fn generate_poisson(
         && total_attempts < max_total_attempts
     {
         // Pick random point from active list
-        let idx = rng.randi_range(active_list.len());
+        let idx = rng.randi_range(0, active_list.len() as i32 - 1) as usize;
         let point = active_list[idx];
 
         let mut found_valid = false;
@@ -342,8 +439,8 @@ pub fn generate_poisson(
                 continue;
             }
 
-            if is_far_enough(candidate, &points, min_dist) {
-                points.push(candidate);
+            if points.is_far_enough(candidate) {
+                points.insert(candidate);
                 active_list.push(candidate);
                 found_valid = true;
                 break;
@@ -355,5 +452,307 @@ pub fn generate_poisson(
         }
     }
 
-    points
+    points.into_points()
+}
+
+/// Attempts per requested seed for impact sampling (rejection discards many candidates)
+const IMPACT_ATTEMPTS_PER_SEED: usize = 50;
+
+/// Generate seed points clustered around an impact point.
+///
+/// Uses rejection sampling: uniform candidates are accepted with probability
+/// `(1 - d / reach) ^ falloff`, where `d` is the candidate's distance from the
+/// impact and `reach` the distance to the furthest bounds corner. A falloff of 0
+/// is uniform; higher values pull seeds (and so smaller fragments) towards the
+/// impact. `min_cell_distance` still applies everywhere and limits how tightly
+/// seeds can pack.
+pub fn generate_impact(
+    polygon: &[Vector2],
+    impact: Vector2,
+    fragment_count: i32,
+    falloff: f32,
+    min_cell_distance: f32,
+    seed: i64,
+) -> Vec<Vector2> {
+    let mut rng = Rng::new(seed);
+    let bounds = calculate_bounds(polygon);
+
+    if bounds.size.x <= 0.0 || bounds.size.y <= 0.0 {
+        return Vec::new();
+    }
+
+    let corners = [
+        bounds.position,
+        Vector2::new(bounds.position.x + bounds.size.x, bounds.position.y),
+        bounds.position + bounds.size,
+        Vector2::new(bounds.position.x, bounds.position.y + bounds.size.y),
+    ];
+    let reach = corners
+        .iter()
+        .map(|c| (*c - impact).length())
+        .fold(0.0f32, f32::max)
+        .max(f32::EPSILON);
+
+    let falloff = falloff.max(0.0);
+    let min_dist = bounds.size.x.min(bounds.size.y) * min_cell_distance;
+    let max_attempts = fragment_count.max(0) as usize * IMPACT_ATTEMPTS_PER_SEED;
+    let mut points = SpatialHash::new(min_dist);
+
+    for _ in 0..max_attempts {
+        if points.len() >= fragment_count as usize {
+            break;
+        }
+
+        let candidate = Vector2::new(
+            rng.randf_range(bounds.position.x, bounds.position.x + bounds.size.x),
+            rng.randf_range(bounds.position.y, bounds.position.y + bounds.size.y),
+        );
+
+        let weight = (1.0 - (candidate - impact).length() / reach).max(0.0).powf(falloff);
+        if rng.randf() >= weight {
+            continue;
+        }
+
+        if point_in_polygon(candidate, polygon) && points.is_far_enough(candidate) {
+            points.insert(candidate);
+        }
+    }
+
+    points.into_points()
+}
+
+/// Attempts per requested seed for annulus sampling (rejection discards many candidates)
+const ANNULUS_ATTEMPTS_PER_SEED: usize = 50;
+
+/// Generate seed points in a ring between two radii of an origin.
+///
+/// Uses rejection sampling over the part of the polygon's bounds covered by the
+/// outer circle: candidates are kept if their distance from `origin` is within
+/// `[inner_radius, outer_radius]` and they lie inside the polygon. Fracturing
+/// with these seeds shatters only the ring, leaving the centre as one piece
+/// (and the outside too, if the ring doesn't reach the polygon's edge).
+#[allow(clippy::too_many_arguments)]
+pub fn generate_annulus(
+    polygon: &[Vector2],
+    origin: Vector2,
+    inner_radius: f32,
+    outer_radius: f32,
+    fragment_count: i32,
+    min_cell_distance: f32,
+    seed: i64,
+) -> Vec<Vector2> {
+    let mut rng = Rng::new(seed);
+    let bounds = calculate_bounds(polygon);
+
+    if bounds.size.x <= 0.0 || bounds.size.y <= 0.0 {
+        return Vec::new();
+    }
+
+    let inner_radius = inner_radius.max(0.0);
+    if outer_radius <= inner_radius {
+        cutout_error!(
+            CutoutError::InvalidInput,
+            "generate_annulus: outer_radius {} must be greater than inner_radius {}",
+            outer_radius,
+            inner_radius
+        );
+        return Vec::new();
+    }
+
+    // Only the polygon's bounds within the outer circle can hold seeds
+    let end = bounds.position + bounds.size;
+    let min_x = bounds.position.x.max(origin.x - outer_radius);
+    let min_y = bounds.position.y.max(origin.y - outer_radius);
+    let max_x = end.x.min(origin.x + outer_radius);
+    let max_y = end.y.min(origin.y + outer_radius);
+    if min_x >= max_x || min_y >= max_y {
+        return Vec::new();
+    }
+
+    let min_dist = bounds.size.x.min(bounds.size.y) * min_cell_distance;
+    let max_attempts = fragment_count.max(0) as usize * ANNULUS_ATTEMPTS_PER_SEED;
+    let mut points = SpatialHash::new(min_dist);
+
+    for _ in 0..max_attempts {
+        if points.len() >= fragment_count as usize {
+            break;
+        }
+
+        let candidate = Vector2::new(rng.randf_range(min_x, max_x), rng.randf_range(min_y, max_y));
+
+        let distance = (candidate - origin).length();
+        if distance < inner_radius || distance > outer_radius {
+            continue;
+        }
+
+        if point_in_polygon(candidate, polygon) && points.is_far_enough(candidate) {
+            points.insert(candidate);
+        }
+    }
+
+    points.into_points()
+}
+
+/// Cells along the longer side of the distance-weighted sampling grid
+const DISTANCE_GRID_RESOLUTION: usize = 256;
+
+/// Attempts per requested seed for distance-weighted sampling
+const DISTANCE_ATTEMPTS_PER_SEED: usize = 20;
+
+/// Generate seed points weighted by distance from the polygon's edges.
+///
+/// Rasterizes the polygon, computes each interior cell's distance to the
+/// nearest exterior cell (an 8-neighbour chamfer transform) and picks cells with
+/// probability proportional to that distance. Every candidate is drawn from the
+/// interior, so crescents, rings and letters get their full seed count where
+/// `generate_random` would reject most candidates. Weighting by distance keeps
+/// seeds near the middle of thin parts, so fragments span their full width
+/// instead of splitting into slivers along the edge.
+///
+/// Seeds are at least one grid cell apart.
+pub fn generate_distance_weighted(
+    polygon: &[Vector2],
+    fragment_count: i32,
+    seed: i64,
+) -> Vec<Vector2> {
+    let mut rng = Rng::new(seed);
+    let bounds = calculate_bounds(polygon);
+
+    if fragment_count <= 0 || bounds.size.x <= 0.0 || bounds.size.y <= 0.0 {
+        return Vec::new();
+    }
+
+    let cell_size = bounds.size.x.max(bounds.size.y) / DISTANCE_GRID_RESOLUTION as f32;
+    let width = ((bounds.size.x / cell_size).ceil() as usize).max(1);
+    let height = ((bounds.size.y / cell_size).ceil() as usize).max(1);
+    let cell_center = |x: usize, y: usize| {
+        bounds.position + Vector2::new(x as f32 + 0.5, y as f32 + 0.5) * cell_size
+    };
+
+    let mut distances = Grid2D::new(width, height);
+    for (x, y, distance) in distances.iter_mut() {
+        if point_in_polygon(cell_center(x, y), polygon) {
+            *distance = f32::INFINITY;
+        }
+    }
+    chamfer_distance(&mut distances);
+
+    // Cumulative weights over the interior cells, for sampling by binary search
+    let mut cells = Vec::new();
+    let mut cumulative = Vec::new();
+    let mut total = 0.0;
+    for (x, y, &distance) in distances.iter() {
+        if distance > 0.0 {
+            total += distance;
+            cells.push((x, y));
+            cumulative.push(total);
+        }
+    }
+
+    if cells.is_empty() {
+        return Vec::new();
+    }
+
+    let max_attempts = fragment_count as usize * DISTANCE_ATTEMPTS_PER_SEED;
+    let mut points = SpatialHash::new(cell_size);
+
+    for _ in 0..max_attempts {
+        if points.len() >= fragment_count as usize {
+            break;
+        }
+
+        let target = rng.randf() * total;
+        let index = cumulative
+            .partition_point(|&c| c <= target)
+            .min(cells.len() - 1);
+        let (x, y) = cells[index];
+
+        // Jitter within the cell; edge cells may poke outside, so fall back to the centre
+        let center = cell_center(x, y);
+        let jittered = center
+            + Vector2::new(rng.randf_range(-0.5, 0.5), rng.randf_range(-0.5, 0.5)) * cell_size;
+        let candidate = if point_in_polygon(jittered, polygon) {
+            jittered
+        } else {
+            center
+        };
+
+        if points.is_far_enough(candidate) {
+            points.insert(candidate);
+        }
+    }
+
+    points.into_points()
+}
+
+/// Two-pass 8-neighbour chamfer distance transform, in cells.
+///
+/// Cells at 0 are exterior; every other cell gets its approximate distance to
+/// the nearest exterior cell. Cells outside the grid count as exterior.
+fn chamfer_distance(distances: &mut Grid2D<f32>) {
+    let (width, height) = (distances.width() as i32, distances.height() as i32);
+    let get = |d: &Grid2D<f32>, x: i32, y: i32| -> f32 {
+        if x < 0 || y < 0 || x >= width || y >= height {
+            0.0
+        } else {
+            d.get_at(x as usize, y as usize).copied().unwrap_or(0.0)
+        }
+    };
+
+    const FORWARD: [(i32, i32, f32); 4] = [
+        (-1, 0, 1.0),
+        (0, -1, 1.0),
+        (-1, -1, std::f32::consts::SQRT_2),
+        (1, -1, std::f32::consts::SQRT_2),
+    ];
+
+    for y in 0..height {
+        for x in 0..width {
+            let current = get(distances, x, y);
+            if current > 0.0 {
+                let best = FORWARD
+                    .iter()
+                    .map(|&(dx, dy, cost)| get(distances, x + dx, y + dy) + cost)
+                    .fold(current, f32::min);
+                distances.set(x as usize, y as usize, best);
+            }
+        }
+    }
+
+    for y in (0..height).rev() {
+        for x in (0..width).rev() {
+            let current = get(distances, x, y);
+            if current > 0.0 {
+                let best = FORWARD
+                    .iter()
+                    .map(|&(dx, dy, cost)| get(distances, x - dx, y - dy) + cost)
+                    .fold(current, f32::min);
+                distances.set(x as usize, y as usize, best);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_positive_counts_give_no_seeds() {
+        let square = vec![
+            Vector2::new(0.0, 0.0),
+            Vector2::new(10.0, 0.0),
+            Vector2::new(10.0, 10.0),
+            Vector2::new(0.0, 10.0),
+        ];
+
+        for count in [0, -1, i32::MIN] {
+            assert!(generate_random(&square, count, 0.0, 0.0, 1).is_empty());
+            assert!(generate_poisson(&square, count, 0.0, 0.0, 30, 1).is_empty());
+            // Padding past the centre, where a positive count gets one central seed
+            assert!(generate_random(&square, count, 0.0, 20.0, 1).is_empty());
+            assert!(generate_poisson(&square, count, 0.0, 20.0, 30, 1).is_empty());
+        }
+        assert_eq!(generate_random(&square, 3, 0.0, 20.0, 1).len(), 1);
+    }
 }
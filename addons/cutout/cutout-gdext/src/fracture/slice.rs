@@ -4,10 +4,13 @@
 //!
 //! This algorithm works by:
 //! 1. Extending the slice line to fully cross the polygon
-//! 2. Using clipper2 to clip the polygon against each half-plane of the line
+//! 2. Using clipper2 to split the polygon against one half-plane of the line:
+//!    the intersection gives one side and the difference the other, so both
+//!    share the same seam vertices and tile the polygon without a gap
 //! 3. Handling holes by including them in both halves
 
-use super::geometry::calculate_bounds;
+use super::geometry::{calculate_bounds, point_in_polygon, polygon_area, polygon_center};
+use crate::common::rng::Rng;
 use godot::prelude::*;
 
 use super::clipper_utils::{clipper2_difference, clipper2_intersect};
@@ -15,6 +18,17 @@ use super::clipper_utils::{clipper2_difference, clipper2_intersect};
 // Segment encoded as a 2-element PackedVector2Array [point_a, point_b].
 type Segment = (Vector2, Vector2);
 
+/// Pieces on the left and on the right of a slice line
+type Sides = (Vec<Vec<Vector2>>, Vec<Vec<Vector2>>);
+
+/// Crossings closer than this are the same point (a line through a vertex
+/// meets both edges that share it)
+const VERTEX_EPSILON: f32 = 1e-4;
+
+/// Pieces with less area than this are rounding slivers left by Clipper2 along
+/// a line that only touches the polygon
+const MIN_PIECE_AREA: f32 = 1e-3;
+
 /// Fracture polygons along a line segment.
 ///
 /// # Arguments
@@ -23,57 +37,63 @@ type Segment = (Vector2, Vector2);
 /// * `line_end` - End point of the slice line
 ///
 /// # Returns
-/// Array of polygon fragments (typically 2 halves, or original if line misses)
+/// Array of polygon fragments: the pieces left of the line, then those right of
+/// it (one each for a convex polygon), or the original if the line misses or
+/// only touches the polygon
 pub fn fracture(
     polygons: &Array<PackedVector2Array>,
     line_start: Vector2,
     line_end: Vector2,
 ) -> Array<PackedVector2Array> {
-    if polygons.is_empty() {
-        return Array::new();
-    }
+    let rings: Vec<Vec<Vector2>> = polygons.iter_shared().map(|p| p.to_vec()).collect();
 
-    let outer: Vec<Vector2> = polygons.get(0).unwrap().to_vec();
-    if outer.len() < 3 {
-        return Array::new();
+    match slice_rings(&rings, line_start, line_end) {
+        Some(pieces) => to_godot_array(pieces),
+        None => polygons.clone(),
     }
+}
 
-    // Find intersections of the slice line with the outer polygon
-    let intersections = find_polygon_intersections(&outer, line_start, line_end);
+/// Fracture polygons held as plain vectors along a line segment.
+///
+/// # Arguments
+/// * `polygons` - First = outer boundary, rest = holes
+/// * `line_start` - Start point of the slice line
+/// * `line_end` - End point of the slice line
+///
+/// # Returns
+/// Same as `fracture`
+pub fn fracture_rings(
+    polygons: &[Vec<Vector2>],
+    line_start: Vector2,
+    line_end: Vector2,
+) -> Vec<Vec<Vector2>> {
+    slice_rings(polygons, line_start, line_end).unwrap_or_else(|| polygons.to_vec())
+}
 
-    if intersections.len() < 2 {
-        // Line doesn't fully cross the polygon - return original
-        return polygons.clone();
+/// Split the outer ring along the line and subtract the holes from each side.
+///
+/// Returns None if the line doesn't fully cross the outer ring or no piece survives.
+fn slice_rings(
+    polygons: &[Vec<Vector2>],
+    line_start: Vector2,
+    line_end: Vector2,
+) -> Option<Vec<Vec<Vector2>>> {
+    let outer = polygons.first()?;
+    if outer.len() < 3 {
+        return None;
     }
 
-    // Build two half-plane clipping polygons from the slice line
-    let bounds = calculate_bounds(&outer);
-    let margin = (bounds.size.x + bounds.size.y) * 0.5; // generous margin
-
-    let dir = (line_end - line_start).normalized();
-    let normal = Vector2::new(-dir.y, dir.x); // perpendicular
-
-    // Create large clipping rectangles on each side of the line
-    let left_clip = build_half_plane_rect(line_start, line_end, normal, margin);
-    let right_clip = build_half_plane_rect(line_start, line_end, -normal, margin);
-
-    // Clip outer polygon against each half
-    let left_fragments = clipper2_intersect(&outer, &left_clip);
-    let right_fragments = clipper2_intersect(&outer, &right_clip);
+    // Split outer polygon into the pieces on each side of the line
+    let (left_fragments, right_fragments) = split_crossing(outer, line_start, line_end)?;
 
     // Collect holes for subtraction
-    let holes: Vec<Vec<Vector2>> = (1..polygons.len())
-        .filter_map(|i| {
-            let h: Vec<Vector2> = polygons.get(i).unwrap().to_vec();
-            if h.len() >= 3 {
-                Some(h)
-            } else {
-                None
-            }
-        })
+    let holes: Vec<Vec<Vector2>> = polygons[1..]
+        .iter()
+        .filter(|h| h.len() >= 3)
+        .cloned()
         .collect();
 
-    let mut result = Array::new();
+    let mut result = Vec::new();
 
     // Process each side's fragments, subtracting holes
     for fragments in [&left_fragments, &right_fragments] {
@@ -83,26 +103,50 @@ pub fn fracture(
             }
 
             let final_pieces = subtract_all_holes(fragment, &holes);
-            for piece in final_pieces {
-                if piece.len() >= 3 {
-                    let mut packed = PackedVector2Array::new();
-                    for p in &piece {
-                        packed.push(*p);
-                    }
-                    result.push(&packed);
-                }
-            }
+            result.extend(final_pieces.into_iter().filter(|piece| piece.len() >= 3));
         }
     }
 
-    if result.is_empty() {
-        return polygons.clone();
-    }
+    (!result.is_empty()).then_some(result)
+}
 
+/// Copy plain fragments into a Godot array
+fn to_godot_array(pieces: Vec<Vec<Vector2>>) -> Array<PackedVector2Array> {
+    let mut result = Array::new();
+    for piece in pieces {
+        result.push(&PackedVector2Array::from(piece.as_slice()));
+    }
     result
 }
 
-/// Find all intersection points between a line segment and polygon edges.
+/// Split a polygon along a line that crosses it.
+///
+/// A line through a vertex or along an edge only touches the polygon, leaving
+/// one side empty (or with a zero-area sliver), so that counts as no cut. For a
+/// convex polygon a crossing line gives exactly one piece on each side.
+///
+/// # Returns
+/// The pieces on the left and right of the line, or None if it doesn't cut
+/// the polygon in two
+fn split_crossing(
+    polygon: &[Vector2],
+    line_start: Vector2,
+    line_end: Vector2,
+) -> Option<Sides> {
+    if find_polygon_intersections(polygon, line_start, line_end).len() < 2 {
+        // Line doesn't fully cross the polygon
+        return None;
+    }
+
+    let (mut left, mut right) = split_along_line(polygon, line_start, line_end);
+    for side in [&mut left, &mut right] {
+        side.retain(|piece| piece.len() >= 3 && polygon_area(piece).abs() >= MIN_PIECE_AREA);
+    }
+
+    (!left.is_empty() && !right.is_empty()).then_some((left, right))
+}
+
+/// Find all distinct intersection points between a line segment and polygon edges.
 fn find_polygon_intersections(
     polygon: &[Vector2],
     line_start: Vector2,
@@ -116,7 +160,13 @@ fn find_polygon_intersections(
         let edge_end = polygon[(i + 1) % n];
 
         if let Some(point) = line_segment_intersection(line_start, line_end, edge_start, edge_end) {
-            intersections.push(point);
+            // A line through a vertex meets both edges sharing it
+            if !intersections
+                .iter()
+                .any(|&seen: &Vector2| (seen - point).length() < VERTEX_EPSILON)
+            {
+                intersections.push(point);
+            }
         }
     }
 
@@ -152,6 +202,36 @@ fn line_segment_intersection(
     }
 }
 
+/// Split a polygon into the pieces on the left and right of a line.
+///
+/// Both sides come from a single half-plane: the left pieces are its
+/// intersection with the polygon and the right pieces the difference. Clipper2
+/// computes each seam vertex once from the same pair of edges, so the two sides
+/// always tile the polygon, even where the line grazes a concave notch.
+fn split_along_line(
+    polygon: &[Vector2],
+    line_start: Vector2,
+    line_end: Vector2,
+) -> Sides {
+    // Every vertex lies within this distance of `line_start`, so a rectangle this
+    // deep along both the line and its normal covers the whole polygon however
+    // elongated it is
+    let extent = polygon
+        .iter()
+        .map(|&point| point.distance_to(line_start))
+        .fold(0.0, f32::max)
+        + 1.0;
+
+    let dir = (line_end - line_start).normalized();
+    let normal = Vector2::new(-dir.y, dir.x); // perpendicular
+
+    let half_plane = build_half_plane_rect(line_start, line_end, normal, extent);
+    (
+        clipper2_intersect(polygon, &half_plane),
+        clipper2_difference(polygon, &half_plane),
+    )
+}
+
 /// Build a large rectangle representing one side of a line.
 fn build_half_plane_rect(
     line_start: Vector2,
@@ -191,6 +271,159 @@ fn subtract_all_holes(fragment: &[Vector2], holes: &[Vec<Vector2>]) -> Vec<Vec<V
     remaining
 }
 
+// ============================================================================
+// Curved slicing
+// ============================================================================
+
+/// Width of the corridor removed along a curved cut.
+///
+/// Thin enough to be invisible, but wide enough to survive clipper2's
+/// fixed-point rounding (two decimal places).
+const CURVE_CUT_WIDTH: f32 = 0.05;
+
+/// Fracture polygons along a bezier curve.
+///
+/// A curved cut can't be expressed as two half-planes, so the curve is flattened
+/// into a polyline and a thin corridor around it is subtracted from the outer
+/// polygon. Like the straight slice, both ends are extended along their tangents
+/// so a curve that crosses the polygon cuts it completely.
+///
+/// # Arguments
+/// * `polygons` - First = outer boundary, rest = holes
+/// * `control_points` - Bezier control points: 3 for quadratic, 4 for cubic
+///   (2 gives a straight line, more gives a higher-degree curve)
+/// * `samples` - Line segments to flatten the curve into
+///
+/// # Returns
+/// Array of polygon fragments, or the original if the curve doesn't split it
+pub fn fracture_curve(
+    polygons: &Array<PackedVector2Array>,
+    control_points: &[Vector2],
+    samples: i32,
+) -> Array<PackedVector2Array> {
+    let rings: Vec<Vec<Vector2>> = polygons.iter_shared().map(|p| p.to_vec()).collect();
+
+    match curve_rings(&rings, control_points, samples) {
+        Some(pieces) => to_godot_array(pieces),
+        None => polygons.clone(),
+    }
+}
+
+/// Fracture polygons held as plain vectors along a bezier curve.
+///
+/// # Arguments
+/// * `polygons` - First = outer boundary, rest = holes
+/// * `control_points` - Bezier control points
+/// * `samples` - Line segments to flatten the curve into
+///
+/// # Returns
+/// Same as `fracture_curve`
+pub fn fracture_curve_rings(
+    polygons: &[Vec<Vector2>],
+    control_points: &[Vector2],
+    samples: i32,
+) -> Vec<Vec<Vector2>> {
+    curve_rings(polygons, control_points, samples).unwrap_or_else(|| polygons.to_vec())
+}
+
+/// Subtract a corridor around the flattened curve from the outer ring, then
+/// subtract the holes from each piece.
+///
+/// Returns None if the curve doesn't split the outer ring or no piece survives.
+fn curve_rings(
+    polygons: &[Vec<Vector2>],
+    control_points: &[Vector2],
+    samples: i32,
+) -> Option<Vec<Vec<Vector2>>> {
+    let outer = polygons.first()?;
+    if outer.len() < 3 {
+        return None;
+    }
+    let holes: Vec<Vec<Vector2>> = polygons[1..]
+        .iter()
+        .filter(|h| h.len() >= 3)
+        .cloned()
+        .collect();
+
+    let mut path = flatten_bezier(control_points, samples.max(1) as usize);
+    path.dedup();
+    if path.len() < 2 {
+        return None;
+    }
+
+    let bounds = calculate_bounds(outer);
+    let margin = (bounds.size.x + bounds.size.y) * 0.5;
+    let n = path.len();
+    let start_dir = (path[0] - path[1]).normalized();
+    let end_dir = (path[n - 1] - path[n - 2]).normalized();
+    path.insert(0, path[0] + start_dir * margin);
+    path.push(path[n] + end_dir * margin);
+
+    let pieces = clipper2_difference(outer, &build_corridor(&path, CURVE_CUT_WIDTH * 0.5));
+    if pieces.len() < 2 {
+        return None;
+    }
+
+    let result: Vec<Vec<Vector2>> = pieces
+        .iter()
+        .flat_map(|fragment| subtract_all_holes(fragment, &holes))
+        .filter(|piece| piece.len() >= 3)
+        .collect();
+
+    (!result.is_empty()).then_some(result)
+}
+
+/// Flatten a bezier curve of any degree into `samples + 1` points (de Casteljau).
+fn flatten_bezier(control_points: &[Vector2], samples: usize) -> Vec<Vector2> {
+    if control_points.len() < 2 {
+        return control_points.to_vec();
+    }
+
+    let mut scratch = Vec::with_capacity(control_points.len());
+    (0..=samples)
+        .map(|k| {
+            let t = k as f32 / samples as f32;
+            scratch.clear();
+            scratch.extend_from_slice(control_points);
+            for level in (1..scratch.len()).rev() {
+                for i in 0..level {
+                    scratch[i] = scratch[i].lerp(scratch[i + 1], t);
+                }
+            }
+            scratch[0]
+        })
+        .collect()
+}
+
+/// Build a closed polygon covering `half_width` on either side of a polyline.
+///
+/// Interior vertices are offset along the average of their two segment normals,
+/// so the corridor narrows slightly at bends, which doesn't matter at this width.
+fn build_corridor(path: &[Vector2], half_width: f32) -> Vec<Vector2> {
+    let normal_of = |a: Vector2, b: Vector2| {
+        let dir = (b - a).normalized();
+        Vector2::new(-dir.y, dir.x)
+    };
+
+    let n = path.len();
+    let normals: Vec<Vector2> = (0..n)
+        .map(|i| {
+            let before = (i > 0).then(|| normal_of(path[i - 1], path[i]));
+            let after = (i + 1 < n).then(|| normal_of(path[i], path[i + 1]));
+            match (before, after) {
+                (Some(a), Some(b)) if (a + b).length_squared() > 1e-6 => (a + b).normalized(),
+                (Some(a), _) => a,
+                (None, Some(b)) => b,
+                (None, None) => Vector2::ZERO,
+            }
+        })
+        .collect();
+
+    let left = path.iter().zip(&normals).map(|(p, nrm)| *p + *nrm * half_width);
+    let right = path.iter().zip(&normals).rev().map(|(p, nrm)| *p - *nrm * half_width);
+    left.chain(right).collect()
+}
+
 // ============================================================================
 // Multi-slice implementation with segment generation
 // ============================================================================
@@ -217,57 +450,16 @@ impl From<i32> for SlicePattern {
     }
 }
 
-/// Simple xorshift RNG matching GDScript's RandomNumberGenerator behavior
-struct SimpleRng {
-    state: u64,
-}
-
-impl SimpleRng {
-    fn new(seed: i64) -> Self {
-        // Match GDScript's seed initialization
-        let mut state = if seed == 0 { 1 } else { seed.unsigned_abs() };
-        // Warm up
-        for _ in 0..4 {
-            state ^= state << 13;
-            state ^= state >> 17;
-            state ^= state << 5;
-        }
-        Self { state }
-    }
-
-    fn randf(&mut self) -> f32 {
-        self.state ^= self.state << 13;
-        self.state ^= self.state >> 17;
-        self.state ^= self.state << 5;
-        self.state as f32 / u64::MAX as f32
-    }
-
-    fn randf_range(&mut self, from: f32, to: f32) -> f32 {
-        from + self.randf() * (to - from)
-    }
-}
-
 /// Bisect a single outer polygon along a line, returning the resulting pieces.
 /// No hole handling — used for intermediate slices.
 fn bisect_outer(outer: &[Vector2], line_start: Vector2, line_end: Vector2) -> Vec<Vec<Vector2>> {
-    let intersections = find_polygon_intersections(outer, line_start, line_end);
-    if intersections.len() < 2 {
-        return vec![outer.to_vec()]; // line misses, keep as-is
+    match split_crossing(outer, line_start, line_end) {
+        Some((mut pieces, right)) => {
+            pieces.extend(right);
+            pieces
+        }
+        None => vec![outer.to_vec()], // line misses or only touches, keep as-is
     }
-
-    let bounds = calculate_bounds(outer);
-    let margin = (bounds.size.x + bounds.size.y) * 0.5;
-
-    let dir = (line_end - line_start).normalized();
-    let normal = Vector2::new(-dir.y, dir.x);
-
-    let left_clip = build_half_plane_rect(line_start, line_end, normal, margin);
-    let right_clip = build_half_plane_rect(line_start, line_end, -normal, margin);
-
-    let mut pieces = clipper2_intersect(outer, &left_clip);
-    pieces.extend(clipper2_intersect(outer, &right_clip));
-    pieces.retain(|p| p.len() >= 3);
-    pieces
 }
 
 /// Generate slice segments based on pattern
@@ -275,7 +467,7 @@ fn bisect_outer(outer: &[Vector2], line_start: Vector2, line_end: Vector2) -> Ve
 fn generate_pattern_segments(
     pattern: SlicePattern,
     outer: &[Vector2],
-    rng: &mut SimpleRng,
+    rng: &mut Rng,
     slice_count: i32,
     // Pattern-specific parameters
     origin: Option<Vector2>,
@@ -299,7 +491,9 @@ fn generate_pattern_segments(
 
     match pattern {
         SlicePattern::Radial => {
-            let origin = origin.unwrap_or(center);
+            let origin = origin.unwrap_or_else(|| polygon_center(outer));
+            // Reach past the far side of the bounds even from an off-centre origin
+            let reach = max_extent + origin.distance_to(center);
             let angle_step = std::f32::consts::TAU / slice_count as f32;
 
             for i in 0..slice_count {
@@ -311,8 +505,8 @@ fn generate_pattern_segments(
 
                 let dir = Vector2::new(angle.cos(), angle.sin());
                 segments.push((
-                    origin - dir * max_extent,
-                    origin + dir * max_extent,
+                    origin - dir * reach,
+                    origin + dir * reach,
                 ));
             }
         },
@@ -412,7 +606,7 @@ fn apply_slices(
     outer: &[Vector2],
     holes: &[Vec<Vector2>],
     segments: &[Segment],
-) -> Array<PackedVector2Array> {
+) -> Vec<Vec<Vector2>> {
     // Iteratively slice the outer polygon only
     let mut current: Vec<Vec<Vector2>> = vec![outer.to_vec()];
 
@@ -428,20 +622,11 @@ fn apply_slices(
     }
 
     // Subtract holes once from the final fragment set
-    let mut result = Array::new();
-    for fragment in &current {
-        for piece in subtract_all_holes(fragment, holes) {
-            if piece.len() >= 3 {
-                let mut packed = PackedVector2Array::new();
-                for p in &piece {
-                    packed.push(*p);
-                }
-                result.push(&packed);
-            }
-        }
-    }
-
-    result
+    current
+        .iter()
+        .flat_map(|fragment| subtract_all_holes(fragment, holes))
+        .filter(|piece| piece.len() >= 3)
+        .collect()
 }
 
 /// Helper function to extract outer polygon and holes from a polygon array
@@ -472,7 +657,7 @@ fn fracture_with_segments(
 ) -> Array<PackedVector2Array> {
     let (outer, holes) = match extract_outer_and_holes(polygons) {
         Some((o, h)) => (o, h),
-        None => return Array::new(),
+        None => return polygons.clone(),
     };
 
     if segments.is_empty() {
@@ -483,7 +668,7 @@ fn fracture_with_segments(
     if result.is_empty() {
         return polygons.clone();
     }
-    result
+    to_godot_array(result)
 }
 
 /// Fracture polygons using radial pattern
@@ -496,10 +681,10 @@ pub fn fracture_slices_radial(
 ) -> Array<PackedVector2Array> {
     let (outer, _) = match extract_outer_and_holes(polygons) {
         Some((o, h)) => (o, h),
-        None => return Array::new(),
+        None => return polygons.clone(),
     };
 
-    let mut rng = SimpleRng::new(seed);
+    let mut rng = Rng::new(seed);
     let origin_opt = if origin == Vector2::ZERO { None } else { Some(origin) };
 
     let segments = generate_pattern_segments(
@@ -529,10 +714,10 @@ pub fn fracture_slices_parallel(
 ) -> Array<PackedVector2Array> {
     let (outer, _) = match extract_outer_and_holes(polygons) {
         Some((o, h)) => (o, h),
-        None => return Array::new(),
+        None => return polygons.clone(),
     };
 
-    let mut rng = SimpleRng::new(seed);
+    let mut rng = Rng::new(seed);
 
     let segments = generate_pattern_segments(
         SlicePattern::Parallel,
@@ -568,10 +753,10 @@ pub fn fracture_slices_grid(
 ) -> Array<PackedVector2Array> {
     let (outer, _) = match extract_outer_and_holes(polygons) {
         Some((o, h)) => (o, h),
-        None => return Array::new(),
+        None => return polygons.clone(),
     };
 
-    let mut rng = SimpleRng::new(seed);
+    let mut rng = Rng::new(seed);
 
     let segments = generate_pattern_segments(
         SlicePattern::Grid,
@@ -602,10 +787,10 @@ pub fn fracture_slices_chaotic(
 ) -> Array<PackedVector2Array> {
     let (outer, _) = match extract_outer_and_holes(polygons) {
         Some((o, h)) => (o, h),
-        None => return Array::new(),
+        None => return polygons.clone(),
     };
 
-    let mut rng = SimpleRng::new(seed);
+    let mut rng = Rng::new(seed);
 
     let segments = generate_pattern_segments(
         SlicePattern::Chaotic,
@@ -645,6 +830,291 @@ pub fn fracture_slices_manual(
     fracture_with_segments(polygons, decoded_segments)
 }
 
+// ============================================================================
+// Open polylines
+// ============================================================================
+
+/// Split an open polyline wherever it crosses one of the slice segments.
+///
+/// Unlike the polygon slicers, the polyline is not closed: its last point
+/// doesn't connect back to the first, so 2 points are already a valid input.
+/// Pieces share their cut points, and a cut through a vertex splits there once.
+///
+/// # Arguments
+/// * `polyline` - Open polyline, at least 2 points
+/// * `segments` - Slice segments
+///
+/// # Returns
+/// Sub-polylines in order along the input, each with at least 2 points, or the
+/// input as the only piece if no segment crosses it
+pub fn split_polyline(polyline: &[Vector2], segments: &[Segment]) -> Vec<Vec<Vector2>> {
+    let Some(&first) = polyline.first() else {
+        return Vec::new();
+    };
+
+    let mut pieces = Vec::new();
+    let mut current = vec![first];
+
+    for edge in polyline.windows(2) {
+        let (a, b) = (edge[0], edge[1]);
+
+        let mut cuts: Vec<Vector2> = segments
+            .iter()
+            .filter_map(|&(start, end)| line_segment_intersection(start, end, a, b))
+            .collect();
+        cuts.sort_by(|p, q| {
+            p.distance_squared_to(a)
+                .total_cmp(&q.distance_squared_to(a))
+        });
+
+        for cut in cuts {
+            if current.last() != Some(&cut) {
+                current.push(cut);
+            }
+            if current.len() >= 2 {
+                pieces.push(std::mem::replace(&mut current, vec![cut]));
+            }
+        }
+
+        if current.last() != Some(&b) {
+            current.push(b);
+        }
+    }
+
+    if current.len() >= 2 {
+        pieces.push(current);
+    }
+    pieces
+}
+
+// ============================================================================
+// Crack propagation
+// ============================================================================
+
+/// Length of the first crack step, as a fraction of the polygon's largest extent
+const CRACK_FIRST_STEP: f32 = 0.05;
+/// Each step is this much longer than the previous, so cuts thin out with distance
+const CRACK_STEP_GROWTH: f32 = 1.5;
+/// Maximum random turn per crack step, in degrees
+const CRACK_WANDER_DEGREES: f32 = 15.0;
+/// Range of angles a sub-branch splits off its parent at, in degrees
+const CRACK_BRANCH_ANGLE_DEGREES: (f32, f32) = (25.0, 60.0);
+/// Sub-branch steps are this fraction of their parent's step length
+const CRACK_BRANCH_SCALE: f32 = 0.7;
+/// How far along a step its piece is looked up, and how far each cut overshoots
+/// the piece's edges. Clipper2 rounds to two decimal places, so a step's start
+/// can sit just off the cut it lies on.
+const CRACK_PROBE_DISTANCE: f32 = 0.05;
+
+/// Fracture polygons with branching cracks spreading from an impact point.
+///
+/// Main cracks leave the impact in evenly spread (jittered) directions and
+/// wander as they grow. At each step a crack may spawn a shorter sub-branch,
+/// which can branch again up to `branch_depth` levels. Steps get longer away
+/// from the impact, so fragments are smallest there.
+///
+/// Steps are cut in the order they grow, so every step after the first starts
+/// on a cut made by an earlier one. Each step cuts only the piece it runs into,
+/// extended to that piece's far edge so it crosses it completely; the first
+/// step, starting inside the polygon, is extended back through the impact too.
+///
+/// # Arguments
+/// * `polygons` - First = outer boundary, rest = holes
+/// * `impact` - Point the cracks spread from
+/// * `seed` - Random seed
+/// * `branch_count` - Number of main cracks
+/// * `branch_depth` - How many levels of sub-branches may form (0 = none)
+/// * `branchiness` - Chance (0-1) of a sub-branch forming at each crack step
+pub fn fracture_cracks(
+    polygons: &Array<PackedVector2Array>,
+    impact: Vector2,
+    seed: i64,
+    branch_count: i32,
+    branch_depth: i32,
+    branchiness: f32,
+) -> Array<PackedVector2Array> {
+    let (outer, holes) = match extract_outer_and_holes(polygons) {
+        Some((o, h)) => (o, h),
+        None => return polygons.clone(),
+    };
+
+    let steps = crack_steps(
+        &outer,
+        impact,
+        seed,
+        branch_count,
+        branch_depth,
+        branchiness,
+    );
+    let result = apply_crack_steps(&outer, &holes, &steps);
+    if result.is_empty() {
+        return polygons.clone();
+    }
+    to_godot_array(result)
+}
+
+/// Grow the crack tree, returning its steps in the order they grew.
+fn crack_steps(
+    outer: &[Vector2],
+    impact: Vector2,
+    seed: i64,
+    branch_count: i32,
+    branch_depth: i32,
+    branchiness: f32,
+) -> Vec<Segment> {
+    let mut rng = Rng::new(seed);
+    let bounds = calculate_bounds(outer);
+    let max_extent = bounds.size.x.max(bounds.size.y);
+    if max_extent <= 0.0 {
+        return Vec::new();
+    }
+
+    // Every crack must run at least this far to leave the polygon
+    let corners = [
+        bounds.position,
+        Vector2::new(bounds.end().x, bounds.position.y),
+        bounds.end(),
+        Vector2::new(bounds.position.x, bounds.end().y),
+    ];
+    let reach = corners
+        .iter()
+        .map(|c| c.distance_to(impact))
+        .fold(0.0f32, f32::max);
+
+    let crack_count = branch_count.max(1);
+    let spacing = std::f32::consts::TAU / crack_count as f32;
+    let mut segments: Vec<Segment> = Vec::new();
+
+    for i in 0..crack_count {
+        let angle = spacing * i as f32 + rng.randf_range(-0.3, 0.3) * spacing;
+        let crack = Crack {
+            start: impact,
+            angle,
+            step: max_extent * CRACK_FIRST_STEP,
+            reach,
+            depth: branch_depth.max(0),
+        };
+        grow_crack(&mut rng, crack, branchiness.clamp(0.0, 1.0), &mut segments);
+    }
+
+    segments
+}
+
+/// Cut crack steps into a polygon one at a time.
+///
+/// A step cuts the piece just ahead of its start, along a chord running back
+/// and forward from there to that piece's edges. Steps starting outside every
+/// piece (the crack has left the polygon) are skipped.
+fn apply_crack_steps(
+    outer: &[Vector2],
+    holes: &[Vec<Vector2>],
+    steps: &[Segment],
+) -> Vec<Vec<Vector2>> {
+    let mut current: Vec<Vec<Vector2>> = vec![outer.to_vec()];
+
+    for &(start, end) in steps {
+        if start.distance_to(end) <= CRACK_PROBE_DISTANCE {
+            continue;
+        }
+        let dir = (end - start).normalized();
+        let probe = start + dir * CRACK_PROBE_DISTANCE;
+
+        let Some(index) = current
+            .iter()
+            .position(|piece| point_in_polygon(probe, piece))
+        else {
+            continue;
+        };
+        let piece = &current[index];
+        let (Some(back), Some(ahead)) = (
+            distance_to_edge(piece, probe, -dir),
+            distance_to_edge(piece, probe, dir),
+        ) else {
+            continue;
+        };
+
+        let pieces = bisect_outer(
+            piece,
+            probe - dir * (back + CRACK_PROBE_DISTANCE),
+            probe + dir * (ahead + CRACK_PROBE_DISTANCE),
+        );
+        current.splice(index..=index, pieces);
+    }
+
+    current
+        .iter()
+        .flat_map(|fragment| subtract_all_holes(fragment, holes))
+        .filter(|piece| piece.len() >= 3)
+        .collect()
+}
+
+/// Distance from `origin` along `dir` to the nearest edge of a polygon.
+fn distance_to_edge(polygon: &[Vector2], origin: Vector2, dir: Vector2) -> Option<f32> {
+    // No edge is further away than the furthest vertex
+    let reach = polygon
+        .iter()
+        .map(|&point| point.distance_to(origin))
+        .fold(0.0, f32::max)
+        + 1.0;
+    let ray_end = origin + dir * reach;
+
+    let n = polygon.len();
+    (0..n)
+        .filter_map(|i| {
+            line_segment_intersection(origin, ray_end, polygon[i], polygon[(i + 1) % n])
+        })
+        .map(|point| point.distance_to(origin))
+        .min_by(f32::total_cmp)
+}
+
+/// A crack still to be grown
+struct Crack {
+    start: Vector2,
+    angle: f32,
+    /// Length of the first step
+    step: f32,
+    /// Distance from `start` the crack grows before it ends
+    reach: f32,
+    /// Remaining levels of sub-branches
+    depth: i32,
+}
+
+/// Grow a crack step by step, pushing each step as a segment and recursing into
+/// any sub-branches it spawns.
+fn grow_crack(rng: &mut Rng, crack: Crack, branchiness: f32, segments: &mut Vec<Segment>) {
+    let wander = CRACK_WANDER_DEGREES.to_radians();
+    let (min_split, max_split) = CRACK_BRANCH_ANGLE_DEGREES;
+
+    let mut point = crack.start;
+    let mut angle = crack.angle;
+    let mut step = crack.step;
+    let mut travelled = 0.0;
+
+    while travelled < crack.reach {
+        angle += rng.randf_range(-wander, wander);
+        let next = point + Vector2::new(angle.cos(), angle.sin()) * step;
+        segments.push((point, next));
+
+        point = next;
+        travelled += step;
+        step *= CRACK_STEP_GROWTH;
+
+        if crack.depth > 0 && travelled < crack.reach && rng.randf() < branchiness {
+            let side = if rng.randf() < 0.5 { -1.0 } else { 1.0 };
+            let split = rng.randf_range(min_split, max_split).to_radians();
+            let branch = Crack {
+                start: point,
+                angle: angle + side * split,
+                step: step * CRACK_BRANCH_SCALE,
+                // Branches are shorter than what remains of their parent
+                reach: (crack.reach - travelled) * 0.5,
+                depth: crack.depth - 1,
+            };
+            grow_crack(rng, branch, branchiness, segments);
+        }
+    }
+}
+
 /// Optimized parallel slice fracture with projection-bound culling
 pub fn fracture_slices_parallel_optimized(
     polygons: &Array<PackedVector2Array>,
@@ -654,12 +1124,12 @@ pub fn fracture_slices_parallel_optimized(
     parallel_angle_rand: f32,
 ) -> Array<PackedVector2Array> {
     if polygons.is_empty() {
-        return Array::new();
+        return polygons.clone();
     }
 
     let outer: Vec<Vector2> = polygons.get(0).unwrap().to_vec();
     if outer.len() < 3 {
-        return Array::new();
+        return polygons.clone();
     }
 
     let holes: Vec<Vec<Vector2>> = (1..polygons.len())
@@ -681,7 +1151,7 @@ pub fn fracture_slices_parallel_optimized(
     let spacing = max_extent * 2.0 / (slice_count + 1) as f32;
 
     // Generate segments with RNG
-    let mut rng = SimpleRng::new(seed);
+    let mut rng = Rng::new(seed);
     let mut segments = Vec::new();
     for i in 1..=slice_count {
         let mut angle = base_angle;
@@ -802,3 +1272,260 @@ pub fn fracture_slices_parallel_optimized(
     }
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contour::grid::points;
+    use crate::fracture::geometry::polygon_area;
+
+    fn square(min: f32, max: f32) -> Vec<Vector2> {
+        vec![
+            Vector2::new(min, min),
+            Vector2::new(max, min),
+            Vector2::new(max, max),
+            Vector2::new(min, max),
+        ]
+    }
+
+    fn total_area(pieces: &[Vec<Vector2>]) -> f32 {
+        pieces.iter().map(|piece| polygon_area(piece).abs()).sum()
+    }
+
+    #[test]
+    fn line_across_square_gives_two_halves() {
+        let pieces = fracture_rings(
+            &[square(0.0, 10.0)],
+            Vector2::new(5.0, -1.0),
+            Vector2::new(5.0, 11.0),
+        );
+
+        assert_eq!(pieces.len(), 2);
+        for piece in &pieces {
+            assert!((polygon_area(piece).abs() - 50.0).abs() < 0.1);
+        }
+    }
+
+    #[test]
+    fn line_missing_polygon_returns_input() {
+        let polygons = vec![square(0.0, 10.0)];
+        let pieces = fracture_rings(&polygons, Vector2::new(20.0, 0.0), Vector2::new(20.0, 10.0));
+
+        assert_eq!(pieces, polygons);
+    }
+
+    #[test]
+    fn holes_are_subtracted_from_both_sides() {
+        let pieces = fracture_rings(
+            &[square(0.0, 10.0), square(4.0, 6.0)],
+            Vector2::new(5.0, -1.0),
+            Vector2::new(5.0, 11.0),
+        );
+
+        assert_eq!(pieces.len(), 2);
+        assert!((total_area(&pieces) - 96.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn cut_through_vertices_gives_two_triangles() {
+        let pieces = fracture_rings(
+            &[square(0.0, 10.0)],
+            Vector2::new(-1.0, -1.0),
+            Vector2::new(11.0, 11.0),
+        );
+
+        assert_eq!(pieces.len(), 2);
+        for piece in &pieces {
+            assert!((polygon_area(piece).abs() - 50.0).abs() < 0.1);
+        }
+    }
+
+    #[test]
+    fn cut_through_one_vertex_and_opposite_edge() {
+        let triangle = vec![
+            Vector2::new(0.0, 0.0),
+            Vector2::new(10.0, 0.0),
+            Vector2::new(5.0, 10.0),
+        ];
+        let pieces = fracture_rings(&[triangle], Vector2::new(5.0, 12.0), Vector2::new(5.0, -2.0));
+
+        assert_eq!(pieces.len(), 2);
+        assert!((total_area(&pieces) - 50.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn line_touching_a_corner_returns_input() {
+        let polygons = vec![square(0.0, 10.0)];
+        let pieces = fracture_rings(&polygons, Vector2::new(-5.0, 5.0), Vector2::new(5.0, -5.0));
+
+        assert_eq!(pieces, polygons);
+    }
+
+    #[test]
+    fn line_along_an_edge_returns_input() {
+        let polygons = vec![square(0.0, 10.0)];
+        let pieces = fracture_rings(&polygons, Vector2::new(-1.0, 0.0), Vector2::new(11.0, 0.0));
+
+        assert_eq!(pieces, polygons);
+    }
+
+    #[test]
+    fn slices_tile_the_polygon() {
+        let segments = [
+            (Vector2::new(-1.0, 3.0), Vector2::new(11.0, 3.0)),
+            (Vector2::new(7.0, -1.0), Vector2::new(7.0, 11.0)),
+        ];
+        let pieces = apply_slices(&square(0.0, 10.0), &[], &segments);
+
+        assert_eq!(pieces.len(), 4);
+        assert!((total_area(&pieces) - 100.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn cut_near_end_of_long_strip_gives_two_pieces() {
+        let strip = vec![
+            Vector2::new(0.0, 0.0),
+            Vector2::new(100.0, 0.0),
+            Vector2::new(100.0, 1.0),
+            Vector2::new(0.0, 1.0),
+        ];
+        let pieces = fracture_rings(&[strip], Vector2::new(1.0, -1.0), Vector2::new(1.0, 2.0));
+
+        assert_eq!(pieces.len(), 2);
+        let mut areas: Vec<f32> = pieces
+            .iter()
+            .map(|piece| polygon_area(piece).abs())
+            .collect();
+        areas.sort_by(f32::total_cmp);
+        assert!((areas[0] - 1.0).abs() < 0.01);
+        assert!((areas[1] - 99.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn curve_across_square_removes_only_its_corridor() {
+        // Ends on the left and right edges, and stays inside in between
+        let control_points = [
+            Vector2::new(0.0, 30.0),
+            Vector2::new(40.0, 90.0),
+            Vector2::new(60.0, 10.0),
+            Vector2::new(100.0, 70.0),
+        ];
+        let pieces = fracture_curve_rings(&[square(0.0, 100.0)], &control_points, 32);
+
+        assert_eq!(pieces.len(), 2);
+        let length: f32 = flatten_bezier(&control_points, 32)
+            .windows(2)
+            .map(|edge| edge[0].distance_to(edge[1]))
+            .sum();
+        let corridor = CURVE_CUT_WIDTH * length;
+        // Clipper2 rounds the corridor's edges to two decimal places
+        assert!((10000.0 - total_area(&pieces) - corridor).abs() < corridor * 0.25);
+    }
+
+    #[test]
+    fn curve_missing_polygon_returns_input() {
+        let polygons = vec![square(0.0, 100.0)];
+        let control_points = [
+            Vector2::new(200.0, 0.0),
+            Vector2::new(250.0, 50.0),
+            Vector2::new(300.0, 0.0),
+        ];
+        let pieces = fracture_curve_rings(&polygons, &control_points, 16);
+
+        assert_eq!(pieces, polygons);
+    }
+
+    #[test]
+    fn two_point_polyline_is_split_or_kept() {
+        let line = points(&[(0.0, 0.0), (10.0, 0.0)]);
+
+        let crossing = [(Vector2::new(4.0, -1.0), Vector2::new(4.0, 1.0))];
+        assert_eq!(
+            split_polyline(&line, &crossing),
+            vec![
+                points(&[(0.0, 0.0), (4.0, 0.0)]),
+                points(&[(4.0, 0.0), (10.0, 0.0)]),
+            ]
+        );
+
+        let missing = [(Vector2::new(20.0, -1.0), Vector2::new(20.0, 1.0))];
+        assert_eq!(split_polyline(&line, &missing), vec![line]);
+    }
+
+    #[test]
+    fn cut_through_polyline_vertex_splits_once() {
+        let line = points(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0)]);
+        let through_corner = [(Vector2::new(5.0, -5.0), Vector2::new(15.0, 5.0))];
+
+        assert_eq!(
+            split_polyline(&line, &through_corner),
+            vec![
+                points(&[(0.0, 0.0), (10.0, 0.0)]),
+                points(&[(10.0, 0.0), (10.0, 10.0)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn polyline_pieces_share_cut_points_in_input_order() {
+        let line = points(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)]);
+        // Listed out of order along the polyline, with two cuts on the first edge
+        let segments = [
+            (Vector2::new(7.0, 9.0), Vector2::new(7.0, 11.0)),
+            (Vector2::new(9.0, 5.0), Vector2::new(11.0, 5.0)),
+            (Vector2::new(7.0, -1.0), Vector2::new(7.0, 1.0)),
+            (Vector2::new(3.0, -1.0), Vector2::new(3.0, 1.0)),
+        ];
+        let pieces = split_polyline(&line, &segments);
+
+        assert_eq!(
+            pieces,
+            vec![
+                points(&[(0.0, 0.0), (3.0, 0.0)]),
+                points(&[(3.0, 0.0), (7.0, 0.0)]),
+                points(&[(7.0, 0.0), (10.0, 0.0), (10.0, 5.0)]),
+                points(&[(10.0, 5.0), (10.0, 10.0), (7.0, 10.0)]),
+                points(&[(7.0, 10.0), (0.0, 10.0)]),
+            ]
+        );
+        for pair in pieces.windows(2) {
+            assert_eq!(pair[0].last(), pair[1].first());
+        }
+    }
+
+    #[test]
+    fn interior_impact_cracks_a_square() {
+        let square = square(0.0, 100.0);
+        for (impact, seed) in [
+            (Vector2::new(50.0, 50.0), 1),
+            (Vector2::new(23.0, 71.0), 2),
+            (Vector2::new(88.0, 12.0), 3),
+        ] {
+            let steps = crack_steps(&square, impact, seed, 3, 1, 0.5);
+            let pieces = apply_crack_steps(&square, &[], &steps);
+
+            assert!(pieces.len() > 1, "{impact:?} gave {} piece", pieces.len());
+            assert!((total_area(&pieces) - 10000.0).abs() < 1.0);
+        }
+    }
+
+    #[test]
+    fn pieces_add_up_to_the_original_area() {
+        let polygon = vec![
+            Vector2::new(0.0, 0.0),
+            Vector2::new(60.0, 5.0),
+            Vector2::new(80.0, 20.0),
+            Vector2::new(30.0, 12.0),
+            Vector2::new(5.0, 25.0),
+        ];
+        let original = polygon_area(&polygon).abs();
+        let pieces = fracture_rings(
+            &[polygon],
+            Vector2::new(10.0, -5.0),
+            Vector2::new(70.0, 30.0),
+        );
+
+        assert!(pieces.len() >= 2);
+        assert!((total_area(&pieces) - original).abs() < 0.1);
+    }
+}
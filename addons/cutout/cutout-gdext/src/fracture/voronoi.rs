@@ -4,16 +4,90 @@
 //!
 //! This algorithm works by:
 //! 1. Computing Delaunay triangulation of the seed points (via `delaunator`)
-//! 2. Building adjacency from the triangulation
-//! 3. Computing Voronoi cells by clipping a bounding box against perpendicular bisectors
-//!    of each seed's Delaunay neighbors
+//! 2. Building each Voronoi cell as the dual of the triangulation: the circumcenters
+//!    of a seed's incident triangles, in order around the seed. Hull seeds have
+//!    unbounded cells, so they fall back to clipping a bounding box against the
+//!    perpendicular bisectors of their Delaunay neighbors
+//! 3. If the seeds are collinear there is no triangulation, so each seed instead
+//!    gets the slab between the bisectors of its neighbors along the line
 //! 4. Clipping cells to the outer polygon (via `clipper2` intersect)
 //! 5. Subtracting holes from fragments (via `clipper2` difference)
+//! 6. Snapping fragment vertices that drifted off the outer or hole edges in
+//!    clipper2's fixed-point round trip back onto them
+//!
+//! A hole that sits entirely inside one cell comes back from clipper2 as an extra
+//! ring wound opposite to its fragment. `fracture` returns it in the flat list
+//! like any other ring; `fracture_with_holes` groups each cell's rings back into
+//! `[outer, holes...]` fragments instead.
+//!
+//! `relax_seeds` reuses steps 1-4 for Lloyd relaxation of seed points.
+//!
+//! Bisectors are only straight lines under the Euclidean metric. For the
+//! Manhattan and Chebyshev metrics (`fracture_with_metric`), steps 1-3 are
+//! replaced by rasterizing the nearest seed over the polygon's bounds and tracing
+//! each seed's region with Marching Squares. Their bisectors run at multiples of
+//! 45 degrees, which midpoint placement reproduces, giving blocky diamond- and
+//! square-ish cells.
+//!
+//! Weighted cells (`fracture_weighted`) form a power diagram: each seed is a
+//! circle whose radius grows with its weight, and every bisector is shifted away
+//! from the larger circle by the difference of their squared radii. The cells
+//! stay convex and still tile the bounds, and are built by half-plane clipping
+//! against every other seed instead of steps 1-3.
 
 use super::clipper_utils::{clipper2_difference, clipper2_intersect};
-use super::geometry::{calculate_bounds, clip_polygon_to_half_plane};
+use super::geometry::{
+    calculate_bounds, circumcenter, clip_polygon_to_half_plane, polygon_area, rings_centroid,
+    snap_to_boundary, RectIndex, SNAP_EPSILON,
+};
+use super::seeds;
+use crate::common::Grid2D;
+use crate::contour::classify::classify_contours;
+use crate::contour::marching_squares;
+use crate::contour::Grid;
+use crate::error::{cutout_error, CutoutError};
+use crate::simplify::rdp;
+use delaunator::{next_halfedge, Triangulation, EMPTY};
 use godot::prelude::*;
 
+/// Samples along the longer side of the bounds when rasterizing non-Euclidean cells
+const METRIC_RASTER_SIZE: usize = 256;
+/// Deviation, in samples, below which a traced cell point counts as collinear
+const COLLINEAR_TOLERANCE: f32 = 0.01;
+
+/// Distance metric the Voronoi cells are built under
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoronoiMetric {
+    /// Straight-line distance: the usual convex cells
+    Euclidean = 0,
+    /// Sum of the axis distances (L1): diamond-ish cells
+    Manhattan = 1,
+    /// Larger of the axis distances (L-infinity): square-ish cells
+    Chebyshev = 2,
+}
+
+impl VoronoiMetric {
+    /// Map a metric int to its metric, or `None` if it's unknown
+    pub fn from_i32(value: i32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Euclidean),
+            1 => Some(Self::Manhattan),
+            2 => Some(Self::Chebyshev),
+            _ => None,
+        }
+    }
+
+    /// Distance between two points under this metric
+    fn distance(self, a: Vector2, b: Vector2) -> f32 {
+        let d = (a - b).abs();
+        match self {
+            Self::Euclidean => d.length(),
+            Self::Manhattan => d.x + d.y,
+            Self::Chebyshev => d.x.max(d.y),
+        }
+    }
+}
+
 /// Fracture polygons into Voronoi-based fragments.
 ///
 /// # Arguments
@@ -21,57 +95,382 @@ use godot::prelude::*;
 /// * `seed_points` - Voronoi cell centers (from any seed generator)
 ///
 /// # Returns
-/// Array of polygon fragments
+/// Array of polygon fragments, or the original polygons if nothing was split.
+/// Holes inside a fragment are included as separate rings, wound opposite to it.
 pub fn fracture(
     polygons: &Array<PackedVector2Array>,
     seed_points: &PackedVector2Array,
 ) -> Array<PackedVector2Array> {
-    if polygons.is_empty() || seed_points.len() < 2 {
-        return Array::new();
+    fracture_with_metric(polygons, seed_points, VoronoiMetric::Euclidean)
+}
+
+/// Fracture polygons into Voronoi-based fragments under a distance metric.
+///
+/// Non-Euclidean cells are traced from a raster of `METRIC_RASTER_SIZE` samples
+/// along the longer side of the polygon's bounds, so their boundaries are only
+/// accurate to about one sample.
+///
+/// # Arguments
+/// * `polygons` - First = outer boundary, rest = holes
+/// * `seed_points` - Voronoi cell centers (from any seed generator)
+/// * `metric` - Distance metric deciding which seed a point belongs to
+///
+/// # Returns
+/// Same as `fracture`
+pub fn fracture_with_metric(
+    polygons: &Array<PackedVector2Array>,
+    seed_points: &PackedVector2Array,
+    metric: VoronoiMetric,
+) -> Array<PackedVector2Array> {
+    let rings = to_rings(polygons);
+    let Some(cells) = fracture_cells(&rings, seed_points.as_slice(), metric) else {
+        return polygons.clone();
+    };
+
+    let mut fragments = Array::new();
+    for piece in cells.iter().flatten() {
+        fragments.push(&PackedVector2Array::from(piece.as_slice()));
     }
 
-    let outer: Vec<Vector2> = polygons.get(0).unwrap().to_vec();
-    if outer.len() < 3 {
-        return Array::new();
+    fragments
+}
+
+/// Fracture polygons into Voronoi-based fragments, labelling each with its seed.
+///
+/// # Arguments
+/// * `polygons` - First = outer boundary, rest = holes
+/// * `seed_points` - Voronoi cell centers (from any seed generator)
+///
+/// # Returns
+/// The fragments as from `fracture`, and for each one the index into
+/// `seed_points` of the seed whose cell produced it. A cell split by the outline
+/// or a hole gives several fragments sharing a seed. If nothing was split, the
+/// original polygons with every id set to -1.
+pub fn fracture_with_seed_ids(
+    polygons: &Array<PackedVector2Array>,
+    seed_points: &PackedVector2Array,
+) -> (Array<PackedVector2Array>, PackedInt32Array) {
+    let rings = to_rings(polygons);
+    let Some(cells) = fracture_cells(&rings, seed_points.as_slice(), VoronoiMetric::Euclidean)
+    else {
+        let ids = vec![-1; polygons.len()];
+        return (polygons.clone(), PackedInt32Array::from(ids.as_slice()));
+    };
+
+    let mut fragments = Array::new();
+    let mut ids = Vec::new();
+    for (seed, cell) in cells.iter().enumerate() {
+        for piece in cell {
+            fragments.push(&PackedVector2Array::from(piece.as_slice()));
+            ids.push(seed as i32);
+        }
     }
 
-    let seeds: Vec<Vector2> = seed_points.to_vec();
-    let bounds = calculate_bounds(&outer);
+    (fragments, PackedInt32Array::from(ids.as_slice()))
+}
 
-    // Step 1: Delaunay triangulation
-    let triangulation = delaunay(&seeds);
-    let Some(triangulation) = triangulation else {
-        godot_error!("Voronoi fracture: Delaunay triangulation failed with {} seed points. Seeds may be collinear or too close together.", seeds.len());
-        return polygons.clone();
+/// Fracture polygons into Voronoi-based fragments that keep their own holes.
+///
+/// # Arguments
+/// * `polygons` - First = outer boundary, rest = holes
+/// * `seed_points` - Voronoi cell centers (from any seed generator)
+///
+/// # Returns
+/// Array of fragments, each an `Array[PackedVector2Array]` of `[outer, holes...]`
+/// with the outer ring CCW and holes CW. If nothing was split, a single entry
+/// holding the original polygons.
+pub fn fracture_with_holes(
+    polygons: &Array<PackedVector2Array>,
+    seed_points: &PackedVector2Array,
+) -> Array<Variant> {
+    let mut fragments = Array::new();
+
+    let rings = to_rings(polygons);
+    let Some(cells) = fracture_cells(&rings, seed_points.as_slice(), VoronoiMetric::Euclidean)
+    else {
+        fragments.push(&polygons.to_variant());
+        return fragments;
     };
 
-    // Step 2: Build adjacency from triangulation
-    let adjacency = build_adjacency(seeds.len(), &triangulation);
+    // Rings from different cells never nest, so each cell is grouped on its own
+    for cell in cells {
+        for shape in classify_contours(cell) {
+            let mut rings = Array::<PackedVector2Array>::new();
+            for ring in shape.into_rings() {
+                rings.push(&PackedVector2Array::from(ring.as_slice()));
+            }
+            fragments.push(&rings.to_variant());
+        }
+    }
 
-    // Step 3: Compute Voronoi cells
-    let voronoi_cells = compute_voronoi_cells(&seeds, &adjacency, bounds);
+    fragments
+}
+
+/// Fracture polygons held as plain vectors, e.g. on a worker thread.
+///
+/// # Arguments
+/// * `polygons` - First = outer boundary, rest = holes
+/// * `seeds` - Voronoi cell centers (from any seed generator)
+///
+/// # Returns
+/// Same as `fracture`
+pub fn fracture_rings(polygons: &[Vec<Vector2>], seeds: &[Vector2]) -> Vec<Vec<Vector2>> {
+    match fracture_cells(polygons, seeds, VoronoiMetric::Euclidean) {
+        Some(cells) => cells.into_iter().flatten().collect(),
+        None => polygons.to_vec(),
+    }
+}
+
+/// Lloyd iterations applied to each attempt's seeds in `fracture_to_count`
+const COUNT_RELAX_ITERATIONS: i32 = 2;
+
+/// Outcome of `fracture_to_count`
+pub struct CountedFracture {
+    /// Fragments of the attempt closest to the target
+    pub fragments: Vec<Vec<Vector2>>,
+    /// Number of outer rings in `fragments`; a hole left inside a cell comes
+    /// back as a separate ring but isn't a fragment of its own
+    pub fragment_count: usize,
+    /// Number of attempts made
+    pub attempts: usize,
+}
+
+/// Fracture polygons into about `target` fragments.
+///
+/// Seeds that land outside the polygon are rejected and cells can vanish under
+/// holes, so a fixed seed count gives an unpredictable fragment count. Each
+/// attempt generates random seeds, evens them out with Lloyd relaxation and
+/// fractures; the seed count for the next attempt is corrected by how far the
+/// fragment count missed. Stops as soon as a result is within `tolerance` of
+/// `target`.
+///
+/// # Arguments
+/// * `polygons` - First = outer boundary, rest = holes
+/// * `target` - Desired fragment count
+/// * `tolerance` - Accepted difference from `target`
+/// * `max_attempts` - Retry budget (at least one attempt is made)
+/// * `seed` - Random seed; each attempt uses the next one
+///
+/// # Returns
+/// The attempt whose fragment count was closest to `target`
+pub fn fracture_to_count(
+    polygons: &[Vec<Vector2>],
+    target: usize,
+    tolerance: usize,
+    max_attempts: usize,
+    seed: i64,
+) -> CountedFracture {
+    let outer = polygons.first().map(Vec::as_slice).unwrap_or_default();
+    let mut best: Option<(Vec<Vec<Vector2>>, usize)> = None;
+    let mut request = target.max(2);
+    let mut attempts = 0;
+
+    while attempts < max_attempts.max(1) {
+        let seeds = seeds::generate_random(
+            outer,
+            request as i32,
+            0.0,
+            0.0,
+            seed.wrapping_add(attempts as i64),
+        );
+        let seeds = relax_seeds(outer, &seeds, COUNT_RELAX_ITERATIONS);
+        let fragments = fracture_rings(polygons, &seeds);
+        attempts += 1;
+
+        let count = count_outer_rings(&fragments);
+        let miss = count.abs_diff(target);
+        let closest = best
+            .as_ref()
+            .map(|(_, best_count)| best_count.abs_diff(target));
+        if closest.is_none_or(|closest| miss < closest) {
+            best = Some((fragments, count));
+        }
+        if miss <= tolerance {
+            break;
+        }
+
+        // Ask for as many more (or fewer) seeds as fragments were missing
+        request = (request + target).saturating_sub(count).max(2);
+    }
+
+    let (fragments, fragment_count) =
+        best.unwrap_or_else(|| (polygons.to_vec(), count_outer_rings(polygons)));
+    CountedFracture {
+        fragments,
+        fragment_count,
+        attempts,
+    }
+}
+
+/// Number of fragments, leaving out holes, which come back as CW rings of their own
+fn count_outer_rings(rings: &[Vec<Vector2>]) -> usize {
+    rings.iter().filter(|ring| polygon_area(ring) > 0.0).count()
+}
+
+/// Fracture polygons into Voronoi-based fragments of varied sizes.
+///
+/// Each seed acts as a circle of radius `weight` times half the average distance
+/// between neighbouring seeds, and cells are those of the power diagram of these
+/// circles (an additively weighted approximation of multiplicative weighting).
+/// A seed with a larger weight than its neighbours gets a larger cell, and a
+/// seed whose circle is swallowed by a neighbour's can lose its cell entirely.
+/// Equal weights give exactly the cells of `fracture`.
+///
+/// # Arguments
+/// * `polygons` - First = outer boundary, rest = holes
+/// * `seed_points` - Voronoi cell centers (from any seed generator)
+/// * `weights` - Relative size of each seed's cell, same length as `seed_points`
+///   (negative weights count as 0)
+///
+/// # Returns
+/// Same as `fracture`
+pub fn fracture_weighted(
+    polygons: &Array<PackedVector2Array>,
+    seed_points: &PackedVector2Array,
+    weights: &[f32],
+) -> Array<PackedVector2Array> {
+    let seeds = seed_points.as_slice();
+    if weights.iter().all(|&w| w.max(0.0) == weights[0].max(0.0)) {
+        return fracture(polygons, seed_points);
+    }
+
+    let rings = to_rings(polygons);
+    let Some(outer) = rings.first().filter(|outer| outer.len() >= 3) else {
+        return polygons.clone();
+    };
+
+    let cells = power_cells(seeds, weights, calculate_bounds(outer));
+    let Some(cells) = clip_cells(&rings, &cells, seeds.len()) else {
+        return polygons.clone();
+    };
 
-    // Step 4 & 5: Clip cells to outer polygon and subtract holes
     let mut fragments = Array::new();
+    for piece in cells.iter().flatten() {
+        fragments.push(&PackedVector2Array::from(piece.as_slice()));
+    }
 
-    // Collect holes
-    let holes: Vec<Vec<Vector2>> = (1..polygons.len())
-        .filter_map(|i| {
-            let h: Vec<Vector2> = polygons.get(i).unwrap().to_vec();
-            if h.len() >= 3 { Some(h) } else { None }
-        })
+    fragments
+}
+
+/// Copy Godot polygons into plain vectors
+fn to_rings(polygons: &Array<PackedVector2Array>) -> Vec<Vec<Vector2>> {
+    polygons.iter_shared().map(|p| p.to_vec()).collect()
+}
+
+/// Clip every Voronoi cell to the polygons.
+///
+/// # Returns
+/// The rings of each cell, one entry per seed in seed order (empty where the
+/// cell missed the polygon), or None (after reporting why) if nothing was split
+fn fracture_cells(
+    polygons: &[Vec<Vector2>],
+    seeds: &[Vector2],
+    metric: VoronoiMetric,
+) -> Option<Vec<Vec<Vec<Vector2>>>> {
+    let voronoi_cells = unclipped_cells(polygons, seeds, metric)?;
+    clip_cells(polygons, &voronoi_cells, seeds.len())
+}
+
+/// Steps 1-3: the Voronoi cell of every seed over the outer polygon's bounds.
+///
+/// Returns None if the input can't be fractured, reporting why when it isn't
+/// simply too few seeds or an empty polygon.
+fn unclipped_cells(
+    polygons: &[Vec<Vector2>],
+    seeds: &[Vector2],
+    metric: VoronoiMetric,
+) -> Option<Vec<Vec<Vector2>>> {
+    if polygons.is_empty() || seeds.len() < 2 {
+        return None;
+    }
+
+    let outer = &polygons[0];
+    if outer.len() < 3 {
+        return None;
+    }
+
+    let bounds = calculate_bounds(outer);
+
+    // Compute Voronoi cells from the Delaunay triangulation
+    if metric == VoronoiMetric::Euclidean {
+        let Some(cells) = voronoi_cells(seeds, bounds) else {
+            cutout_error!(CutoutError::TriangulationFailed, "Voronoi fracture: Delaunay triangulation failed with {} seed points. Seeds may all be at the same position.", seeds.len());
+            return None;
+        };
+        Some(cells)
+    } else {
+        Some(raster_cells(seeds, bounds, metric))
+    }
+}
+
+/// Steps 4-6: clip cells to the outer polygon, subtract holes and snap to the outline.
+///
+/// # Returns
+/// The rings of each cell, one entry per seed in seed order (empty where the
+/// cell missed the polygon), or None (after reporting why) if nothing was left
+fn clip_cells(
+    polygons: &[Vec<Vector2>],
+    voronoi_cells: &[Vec<Vector2>],
+    seed_count: usize,
+) -> Option<Vec<Vec<Vec<Vector2>>>> {
+    let clipper = CellClipper::new(polygons);
+    let cells: Vec<Vec<Vec<Vector2>>> = voronoi_cells
+        .iter()
+        .map(|cell| clipper.clip(cell))
         .collect();
 
-    // Precompute hole bounds for spatial culling
-    let hole_bounds: Vec<Rect2> = holes.iter().map(|h| calculate_bounds(h)).collect();
+    if cells.iter().all(Vec::is_empty) {
+        report_no_fragments(voronoi_cells.len(), seed_count);
+        return None;
+    }
+
+    Some(cells)
+}
+
+fn report_no_fragments(cell_count: usize, seed_count: usize) {
+    cutout_error!(CutoutError::NoFragments, "Voronoi fracture: No valid fragments generated from {} cells and {} seed points. Polygon may be too small or seeds outside bounds.", cell_count, seed_count);
+}
+
+/// Clips Voronoi cells to a polygon with holes, one cell at a time.
+///
+/// Holds the polygons and the hole index so they are only prepared once.
+struct CellClipper {
+    polygons: Vec<Vec<Vector2>>,
+    holes: Vec<Vec<Vector2>>,
+    hole_bounds: Vec<Rect2>,
+    hole_index: RectIndex,
+}
+
+impl CellClipper {
+    fn new(polygons: &[Vec<Vector2>]) -> Self {
+        // Collect holes
+        let holes: Vec<Vec<Vector2>> = polygons[1..]
+            .iter()
+            .filter(|h| h.len() >= 3)
+            .cloned()
+            .collect();
 
-    for cell in &voronoi_cells {
+        // Precompute hole bounds and index them for spatial culling
+        let hole_bounds: Vec<Rect2> = holes.iter().map(|h| calculate_bounds(h)).collect();
+        let hole_index = RectIndex::new(&hole_bounds);
+
+        Self {
+            polygons: polygons.to_vec(),
+            holes,
+            hole_bounds,
+            hole_index,
+        }
+    }
+
+    /// The rings left of one cell, empty if it missed the polygon
+    fn clip(&self, cell: &[Vector2]) -> Vec<Vec<Vector2>> {
+        let mut pieces = Vec::new();
         if cell.len() < 3 {
-            continue;
+            return pieces;
         }
 
         // Clip cell against outer polygon using clipper2
-        let clipped = clipper2_intersect(cell, &outer);
+        let clipped = clipper2_intersect(cell, &self.polygons[0]);
 
         for fragment in clipped {
             if fragment.len() < 3 {
@@ -79,32 +478,277 @@ pub fn fracture(
             }
 
             // Subtract holes from fragment
-            let remaining = subtract_holes(&fragment, &holes, &hole_bounds);
+            let remaining =
+                subtract_holes(&fragment, &self.holes, &self.hole_bounds, &self.hole_index);
 
-            for piece in remaining {
-                if piece.len() >= 3 {
-                    let mut packed = PackedVector2Array::new();
-                    for p in &piece {
-                        packed.push(*p);
-                    }
-                    fragments.push(&packed);
-                }
+            pieces.extend(remaining.into_iter().filter(|piece| piece.len() >= 3));
+        }
+
+        for piece in &mut pieces {
+            snap_to_boundary(piece, &self.polygons, SNAP_EPSILON);
+        }
+
+        pieces
+    }
+}
+
+/// A Voronoi fracture clipped a few cells at a time.
+///
+/// The cells are computed up front; clipping them to the polygon and its holes
+/// is the expensive part, and `step` does it for a bounded number of cells per
+/// call. Fragments come out in the same order as from `fracture`.
+pub struct VoronoiJob {
+    clipper: CellClipper,
+    cells: Vec<Vec<Vector2>>,
+    seed_count: usize,
+    next: usize,
+    fragments: Vec<Vec<Vector2>>,
+    produced: usize,
+}
+
+impl VoronoiJob {
+    /// Prepare a fracture of `polygons` (outer boundary, then holes).
+    ///
+    /// Returns None if the polygons can't be fractured with these seeds, as
+    /// `fracture` would return its input unchanged.
+    pub fn new(polygons: &[Vec<Vector2>], seeds: &[Vector2]) -> Option<Self> {
+        let cells = unclipped_cells(polygons, seeds, VoronoiMetric::Euclidean)?;
+
+        Some(Self {
+            clipper: CellClipper::new(polygons),
+            cells,
+            seed_count: seeds.len(),
+            next: 0,
+            fragments: Vec::new(),
+            produced: 0,
+        })
+    }
+
+    /// Clip up to `max_cells` more cells (at least one).
+    ///
+    /// # Returns
+    /// True once every cell has been clipped
+    pub fn step(&mut self, max_cells: usize) -> bool {
+        let start = self.next;
+        let end = (start + max_cells.max(1)).min(self.cells.len());
+
+        for cell in &self.cells[start..end] {
+            let pieces = self.clipper.clip(cell);
+            self.produced += pieces.len();
+            self.fragments.extend(pieces);
+        }
+        self.next = end;
+
+        // Reported once, by the step that clipped the last cell
+        let done = self.is_done();
+        if done && end > start && self.produced == 0 {
+            report_no_fragments(self.cells.len(), self.seed_count);
+        }
+        done
+    }
+
+    /// Whether every cell has been clipped
+    pub fn is_done(&self) -> bool {
+        self.next >= self.cells.len()
+    }
+
+    /// Number of cells clipped so far, out of `cell_count`
+    pub fn cells_done(&self) -> usize {
+        self.next
+    }
+
+    /// Total number of cells, one per seed
+    pub fn cell_count(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Total number of fragments produced so far, including ones already taken
+    pub fn produced(&self) -> usize {
+        self.produced
+    }
+
+    /// Remove and return the fragments produced since the last call
+    pub fn take_fragments(&mut self) -> Vec<Vec<Vector2>> {
+        std::mem::take(&mut self.fragments)
+    }
+}
+
+/// Even out a seed distribution with Lloyd relaxation.
+///
+/// Each iteration computes the Voronoi cells of the current seeds clipped to the
+/// polygon and moves every seed to the area-weighted centroid of its cell.
+/// Seeds whose cell is empty (e.g. seeds outside the polygon) stay where they are.
+///
+/// # Arguments
+/// * `polygon` - Outer boundary to clip cells against
+/// * `seeds` - Initial seed points (from any seed generator)
+/// * `iterations` - Number of relaxation steps
+///
+/// # Returns
+/// Relaxed seed points, in the same order as the input
+pub fn relax_seeds(polygon: &[Vector2], seeds: &[Vector2], iterations: i32) -> Vec<Vector2> {
+    let mut relaxed = seeds.to_vec();
+
+    if polygon.len() < 3 || relaxed.len() < 2 {
+        return relaxed;
+    }
+
+    let bounds = calculate_bounds(polygon);
+
+    for _ in 0..iterations {
+        let Some(cells) = voronoi_cells(&relaxed, bounds) else {
+            cutout_error!(CutoutError::TriangulationFailed, "Lloyd relaxation: Delaunay triangulation failed with {} seed points. Seeds may all be at the same position.", relaxed.len());
+            break;
+        };
+
+        for (seed, cell) in relaxed.iter_mut().zip(&cells) {
+            if cell.len() < 3 {
+                continue;
+            }
+
+            // A cell may be split into several pieces by a concave boundary
+            let clipped = clipper2_intersect(cell, polygon);
+            if let Some(centroid) = rings_centroid(&clipped) {
+                *seed = centroid;
             }
         }
     }
 
-    if fragments.is_empty() {
-        godot_error!("Voronoi fracture: No valid fragments generated from {} cells and {} seed points. Polygon may be too small or seeds outside bounds.", voronoi_cells.len(), seeds.len());
-        return polygons.clone();
+    relaxed
+}
+
+/// Compute the Voronoi cell of every seed, one per seed in seed order.
+///
+/// Uses the Delaunay dual when the seeds can be triangulated, and `collinear_cells`
+/// when they all lie on one line. Returns None if every seed is at the same position.
+fn voronoi_cells(seeds: &[Vector2], bounds: Rect2) -> Option<Vec<Vec<Vector2>>> {
+    match delaunay(seeds) {
+        Some(triangulation) => Some(compute_voronoi_cells(seeds, &triangulation, bounds)),
+        None => collinear_cells(seeds, bounds),
     }
+}
 
-    fragments
+/// Compute the Voronoi cells of seeds that all lie on one line.
+///
+/// `delaunator` produces no triangles for collinear seeds (or only two seeds), but
+/// their Voronoi diagram is still well defined: parallel slabs cut by the
+/// perpendicular bisectors between consecutive seeds along the line. Each cell is
+/// built with `clipped_cell` against just those two neighbors. Duplicate seeds get
+/// an empty cell, as in `compute_voronoi_cells`.
+///
+/// Returns None if every seed is at the same position.
+fn collinear_cells(seeds: &[Vector2], bounds: Rect2) -> Option<Vec<Vec<Vector2>>> {
+    let first = *seeds.first()?;
+
+    // The seed farthest from the first one gives the line's direction
+    let farthest = seeds.iter().copied().max_by(|a, b| {
+        first
+            .distance_squared_to(*a)
+            .total_cmp(&first.distance_squared_to(*b))
+    })?;
+    let span = farthest - first;
+    if span.length_squared() <= f32::EPSILON {
+        return None;
+    }
+    let direction = span.normalized();
+
+    // Order the seeds along the line
+    let along: Vec<f32> = seeds.iter().map(|s| (*s - first).dot(direction)).collect();
+    let mut order: Vec<usize> = (0..seeds.len()).collect();
+    order.sort_by(|&a, &b| along[a].total_cmp(&along[b]));
+
+    // Drop duplicates, keeping the first seed at each position
+    order.dedup_by(|a, b| seeds[*a].distance_squared_to(seeds[*b]) <= f32::EPSILON);
+
+    let mut cells = vec![Vec::new(); seeds.len()];
+    for (k, &i) in order.iter().enumerate() {
+        let neighbors: Vec<usize> = [k.checked_sub(1), Some(k + 1)]
+            .into_iter()
+            .flatten()
+            .filter_map(|j| order.get(j).copied())
+            .collect();
+        cells[i] = clipped_cell(seeds, i, &neighbors, bounds);
+    }
+
+    Some(cells)
+}
+
+/// Compute the Voronoi cell of every seed under a metric by rasterizing it.
+///
+/// Every sample over `bounds` (plus a one-sample margin, so cells close outside
+/// them) is labelled with its nearest seed, ties going to the lower index. Each
+/// seed's samples are then traced with Marching Squares. Returns one cell per
+/// seed, in seed order; seeds nearest to no sample get an empty cell.
+fn raster_cells(seeds: &[Vector2], bounds: Rect2, metric: VoronoiMetric) -> Vec<Vec<Vector2>> {
+    let mut cells = vec![Vec::new(); seeds.len()];
+
+    let step = bounds.size.x.max(bounds.size.y) / METRIC_RASTER_SIZE as f32;
+    if step <= 0.0 {
+        return cells;
+    }
+
+    let origin = bounds.position - Vector2::new(step, step);
+    let width = (bounds.size.x / step).ceil() as usize + 2;
+    let height = (bounds.size.y / step).ceil() as usize + 2;
+    let sample_point = |x: f32, y: f32| origin + Vector2::new(x + 0.5, y + 0.5) * step;
+
+    // Nearest seed of every sample, and the sample bounds of each seed's region
+    let mut labels: Grid2D<usize> = Grid2D::new(width, height);
+    let mut extents: Vec<Option<(usize, usize, usize, usize)>> = vec![None; seeds.len()];
+
+    for y in 0..height {
+        for x in 0..width {
+            let point = sample_point(x as f32, y as f32);
+            let nearest = (0..seeds.len())
+                .min_by(|&a, &b| {
+                    metric
+                        .distance(point, seeds[a])
+                        .total_cmp(&metric.distance(point, seeds[b]))
+                })
+                .unwrap_or(0);
+
+            labels.set(x, y, nearest);
+            let extent = extents[nearest].get_or_insert((x, y, x, y));
+            *extent = (
+                extent.0.min(x),
+                extent.1.min(y),
+                extent.2.max(x),
+                extent.3.max(y),
+            );
+        }
+    }
+
+    for (i, extent) in extents.into_iter().enumerate() {
+        let Some((min_x, min_y, max_x, max_y)) = extent else {
+            continue;
+        };
+
+        let mut mask = Grid::new(max_x - min_x + 1, max_y - min_y + 1);
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                mask.set(x - min_x, y - min_y, labels.get_at(x, y) == Some(&i));
+            }
+        }
+
+        // Cells are star-shaped around their seed, so the largest ring is the cell
+        if let Some(ring) = marching_squares::calculate(&mask).into_iter().next() {
+            let ring: Vec<Vector2> = ring
+                .into_iter()
+                .map(|p| sample_point(p.x + min_x as f32, p.y + min_y as f32))
+                .collect();
+
+            // Drop the collinear point at every sample along straight boundaries
+            cells[i] = rdp::simplify(&ring, step * COLLINEAR_TOLERANCE);
+        }
+    }
+
+    cells
 }
 
 /// Compute Delaunay triangulation using the `delaunator` crate.
 ///
-/// Returns triangle indices as a flat Vec (every 3 = one triangle), or None on failure.
-fn delaunay(points: &[Vector2]) -> Option<Vec<usize>> {
+/// Returns the triangulation (triangle indices plus half-edges), or None on failure.
+fn delaunay(points: &[Vector2]) -> Option<Triangulation> {
     let coords: Vec<delaunator::Point> = points
         .iter()
         .map(|p| delaunator::Point {
@@ -118,7 +762,7 @@ fn delaunay(points: &[Vector2]) -> Option<Vec<usize>> {
         return None;
     }
 
-    Some(result.triangles)
+    Some(result)
 }
 
 /// Build an adjacency list from Delaunay triangulation.
@@ -154,46 +798,167 @@ fn build_adjacency(num_points: usize, triangles: &[usize]) -> Vec<Vec<usize>> {
     adjacency
 }
 
-/// Compute Voronoi cells by half-plane clipping against Delaunay neighbors.
+/// Compute the Voronoi cell of every seed from its Delaunay triangulation.
 ///
-/// Each cell starts as the bounding box and is clipped against perpendicular
-/// bisectors of each neighbor.
+/// Interior seeds get the exact dual cell built from circumcenters, so adjacent
+/// cells share their edges exactly. Hull seeds, and seeds next to a degenerate
+/// triangle, fall back to `clipped_cell`. Returns one cell per seed, in seed
+/// order; seeds missing from the triangulation (duplicates) get an empty cell.
 fn compute_voronoi_cells(
     seeds: &[Vector2],
-    adjacency: &[Vec<usize>],
+    triangulation: &Triangulation,
     bounds: Rect2,
 ) -> Vec<Vec<Vector2>> {
-    let mut cells = Vec::with_capacity(seeds.len());
-
-    for (i, center) in seeds.iter().enumerate() {
-        // Start with bounding box
-        let mut cell = vec![
-            bounds.position,
-            Vector2::new(bounds.position.x + bounds.size.x, bounds.position.y),
-            bounds.position + bounds.size,
-            Vector2::new(bounds.position.x, bounds.position.y + bounds.size.y),
-        ];
+    let adjacency = build_adjacency(seeds.len(), &triangulation.triangles);
 
-        // Clip against each neighbor's perpendicular bisector
-        for &neighbor_idx in &adjacency[i] {
-            let other = seeds[neighbor_idx];
-            let midpoint = (*center + other) * 0.5;
-            // Normal points from neighbor toward center (keeps center's side)
-            let normal = (*center - other).normalized();
-
-            cell = clip_polygon_to_half_plane(&cell, midpoint, normal);
+    // One half-edge ending at each seed, as a starting point for walking around it
+    let mut incoming = vec![EMPTY; seeds.len()];
+    for e in 0..triangulation.triangles.len() {
+        let endpoint = triangulation.triangles[next_halfedge(e)];
+        if incoming[endpoint] == EMPTY {
+            incoming[endpoint] = e;
+        }
+    }
 
-            if cell.len() < 3 {
-                break;
+    (0..seeds.len())
+        .map(|i| {
+            if incoming[i] == EMPTY {
+                return Vec::new();
             }
+            dual_cell(seeds, triangulation, incoming[i])
+                .unwrap_or_else(|| clipped_cell(seeds, i, &adjacency[i], bounds))
+        })
+        .collect()
+}
+
+/// Build a seed's exact Voronoi cell from the circumcenters of its incident triangles.
+///
+/// Walks the half-edges around the seed that `start` ends at. Returns `None` if
+/// the walk reaches the convex hull (the cell is unbounded) or a triangle is too
+/// degenerate to have a circumcenter.
+fn dual_cell(
+    seeds: &[Vector2],
+    triangulation: &Triangulation,
+    start: usize,
+) -> Option<Vec<Vector2>> {
+    let mut cell = Vec::new();
+    let mut e = start;
+
+    loop {
+        let t = e - e % 3;
+        let [a, b, c] = [0, 1, 2].map(|k| seeds[triangulation.triangles[t + k]]);
+        cell.push(circumcenter(a, b, c)?);
+
+        // Step to the next triangle around the seed
+        e = triangulation.halfedges[next_halfedge(e)];
+        if e == EMPTY {
+            return None;
+        }
+        if e == start {
+            break;
         }
+    }
 
-        if cell.len() >= 3 {
-            cells.push(cell);
+    if cell.len() < 3 {
+        return None;
+    }
+
+    Some(cell)
+}
+
+/// Compute a seed's Voronoi cell by half-plane clipping against Delaunay neighbors.
+///
+/// The cell starts as the bounding box and is clipped against the perpendicular
+/// bisector of each neighbor. Returns an empty cell if it collapses.
+fn clipped_cell(seeds: &[Vector2], i: usize, neighbors: &[usize], bounds: Rect2) -> Vec<Vector2> {
+    let center = seeds[i];
+
+    // Start with bounding box
+    let mut cell = bounds_polygon(bounds);
+
+    // Clip against each neighbor's perpendicular bisector
+    for &neighbor_idx in neighbors {
+        let other = seeds[neighbor_idx];
+        let midpoint = (center + other) * 0.5;
+        // Normal points from neighbor toward center (keeps center's side)
+        let normal = (center - other).normalized();
+
+        cell = clip_polygon_to_half_plane(&cell, midpoint, normal);
+
+        if cell.len() < 3 {
+            return Vec::new();
         }
     }
 
-    cells
+    cell
+}
+
+/// Compute the power diagram cell of every seed, one per seed in seed order.
+///
+/// Seed `i` is a circle of radius `weights[i]` times half the mean distance from
+/// a seed to its nearest neighbour. Each cell starts as the bounding box and is
+/// clipped against the power bisector with every other seed, which is the
+/// perpendicular bisector shifted towards the smaller circle by the difference of
+/// the squared radii over twice the seed distance. Seeds whose cell collapses get
+/// an empty cell; of coincident seeds only the largest (then the first) keeps one.
+fn power_cells(seeds: &[Vector2], weights: &[f32], bounds: Rect2) -> Vec<Vec<Vector2>> {
+    let nearest_sum: f32 = (0..seeds.len())
+        .map(|i| {
+            (0..seeds.len())
+                .filter(|&j| j != i)
+                .map(|j| seeds[i].distance_to(seeds[j]))
+                .fold(f32::INFINITY, f32::min)
+        })
+        .filter(|d| d.is_finite())
+        .sum();
+    let unit = nearest_sum / seeds.len().max(1) as f32 * 0.5;
+
+    // Squared radius of each seed's circle
+    let power: Vec<f32> = weights
+        .iter()
+        .map(|w| (w.max(0.0) * unit).powi(2))
+        .collect();
+
+    (0..seeds.len())
+        .map(|i| {
+            let center = seeds[i];
+            let mut cell = bounds_polygon(bounds);
+
+            for j in (0..seeds.len()).filter(|&j| j != i) {
+                let offset = center - seeds[j];
+                let distance = offset.length();
+
+                if distance <= f32::EPSILON {
+                    if power[j] > power[i] || (power[j] == power[i] && j < i) {
+                        return Vec::new();
+                    }
+                    continue;
+                }
+
+                // Normal points from the other seed toward this one (keeps this side)
+                let normal = offset / distance;
+                let shift = (power[i] - power[j]) / (2.0 * distance);
+                let bisector = (center + seeds[j]) * 0.5 - normal * shift;
+
+                cell = clip_polygon_to_half_plane(&cell, bisector, normal);
+                if cell.len() < 3 {
+                    return Vec::new();
+                }
+            }
+
+            cell
+        })
+        .collect()
+}
+
+/// The corners of a rectangle as a CCW polygon
+fn bounds_polygon(bounds: Rect2) -> Vec<Vector2> {
+    vec![
+        bounds.position,
+        Vector2::new(bounds.position.x + bounds.size.x, bounds.position.y),
+        bounds.position + bounds.size,
+        Vector2::new(bounds.position.x, bounds.position.y + bounds.size.y),
+    ]
 }
 
 // Clipper2 helper functions have been moved to clipper_utils module
@@ -203,6 +968,7 @@ fn subtract_holes(
     fragment: &[Vector2],
     holes: &[Vec<Vector2>],
     hole_bounds: &[Rect2],
+    hole_index: &RectIndex,
 ) -> Vec<Vec<Vector2>> {
     let mut remaining = vec![fragment.to_vec()];
 
@@ -212,7 +978,8 @@ fn subtract_holes(
 
     let fragment_bounds = calculate_bounds(fragment);
 
-    for (hole_idx, hole) in holes.iter().enumerate() {
+    // Only holes sharing an index cell with the fragment, still in hole order
+    for hole_idx in hole_index.query(fragment_bounds) {
         // Spatial culling: skip holes that don't overlap fragment bounds
         if !rects_intersect(fragment_bounds, hole_bounds[hole_idx]) {
             continue;
@@ -221,7 +988,7 @@ fn subtract_holes(
         let mut next_remaining = Vec::new();
 
         for piece in &remaining {
-            let after_subtract = clipper2_difference(piece, hole);
+            let after_subtract = clipper2_difference(piece, &holes[hole_idx]);
             next_remaining.extend(after_subtract);
         }
 
@@ -238,3 +1005,118 @@ fn rects_intersect(a: Rect2, b: Rect2) -> bool {
         && a.position.y < b.position.y + b.size.y
         && a.position.y + a.size.y > b.position.y
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fracture::geometry::point_in_polygon;
+
+    #[test]
+    fn five_collinear_seeds_give_parallel_slabs() {
+        // Out of order along the line, so the cells must be sorted back
+        let seeds: Vec<Vector2> = [50.0, 10.0, 90.0, 30.0, 70.0]
+            .iter()
+            .map(|&x| Vector2::new(x, 50.0))
+            .collect();
+        let bounds = Rect2::new(Vector2::ZERO, Vector2::new(100.0, 100.0));
+
+        let cells = voronoi_cells(&seeds, bounds).expect("collinear seeds have cells");
+
+        assert_eq!(cells.len(), 5);
+        for (seed, cell) in seeds.iter().zip(&cells) {
+            assert!(point_in_polygon(*seed, cell));
+            assert!((polygon_area(cell).abs() - 2000.0).abs() < 0.1);
+        }
+    }
+
+    #[test]
+    fn collinear_seeds_still_fracture() {
+        let square = vec![
+            Vector2::new(0.0, 0.0),
+            Vector2::new(100.0, 0.0),
+            Vector2::new(100.0, 100.0),
+            Vector2::new(0.0, 100.0),
+        ];
+        let seeds: Vec<Vector2> = (0..5)
+            .map(|i| Vector2::new(10.0 + 20.0 * i as f32, 50.0))
+            .collect();
+
+        let fragments = fracture_rings(&[square], &seeds);
+
+        assert_eq!(fragments.len(), 5);
+        let total: f32 = fragments.iter().map(|f| polygon_area(f).abs()).sum();
+        assert!((total - 10000.0).abs() < 1.0);
+    }
+
+    /// Twelve seeds spread over a 100x100 square
+    fn scattered_seeds() -> Vec<Vector2> {
+        let mut rng = crate::common::rng::Rng::new(69);
+        (0..12)
+            .map(|_| Vector2::new(rng.randf_range(5.0, 95.0), rng.randf_range(5.0, 95.0)))
+            .collect()
+    }
+
+    /// Clip a cell to a rectangle; interior seeds' dual cells can reach past it
+    fn clip_to_rect(cell: &[Vector2], rect: Rect2) -> Vec<Vector2> {
+        let corners = bounds_polygon(rect);
+        (0..4).fold(cell.to_vec(), |cell, k| {
+            let (a, b) = (corners[k], corners[(k + 1) % 4]);
+            let inward = Vector2::new(a.y - b.y, b.x - a.x).normalized();
+            clip_polygon_to_half_plane(&cell, a, inward)
+        })
+    }
+
+    #[test]
+    fn equal_power_cells_match_voronoi_cells() {
+        let seeds = scattered_seeds();
+        let bounds = Rect2::new(Vector2::ZERO, Vector2::new(100.0, 100.0));
+
+        let voronoi = voronoi_cells(&seeds, bounds).expect("scattered seeds triangulate");
+        let power = power_cells(&seeds, &[1.0; 12], bounds);
+
+        assert_eq!(power.len(), voronoi.len());
+        for (a, b) in power.iter().zip(&voronoi) {
+            let b = clip_to_rect(b, bounds);
+            assert!((polygon_area(a).abs() - polygon_area(&b).abs()).abs() < 0.01);
+            // Same corners, whatever vertex or winding each ring starts with
+            for (from, to) in [(a, &b), (&b, a)] {
+                for point in from {
+                    assert!(to.iter().any(|other| other.distance_to(*point) < 1e-3));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn heavier_seed_grows_its_cell_and_still_tiles() {
+        let pentagon = vec![
+            Vector2::new(10.0, 0.0),
+            Vector2::new(90.0, 0.0),
+            Vector2::new(100.0, 60.0),
+            Vector2::new(50.0, 100.0),
+            Vector2::new(0.0, 60.0),
+        ];
+        let bounds = calculate_bounds(&pentagon);
+        let seeds = scattered_seeds();
+        let heavy = (0..seeds.len())
+            .min_by(|&a, &b| {
+                let center = Vector2::new(50.0, 50.0);
+                seeds[a]
+                    .distance_to(center)
+                    .total_cmp(&seeds[b].distance_to(center))
+            })
+            .unwrap();
+
+        let mut weights = [1.0; 12];
+        let even = power_cells(&seeds, &weights, bounds);
+        weights[heavy] = 2.0;
+        let weighted = power_cells(&seeds, &weights, bounds);
+
+        assert!(polygon_area(&weighted[heavy]) > polygon_area(&even[heavy]) + 1.0);
+
+        let area = polygon_area(&pentagon).abs();
+        let cells = clip_cells(&[pentagon], &weighted, seeds.len()).unwrap();
+        let total: f32 = cells.iter().flatten().map(|f| polygon_area(f).abs()).sum();
+        assert!((total - area).abs() < 1.0);
+    }
+}
@@ -0,0 +1,270 @@
+//! Convex decomposition for physics collision shapes
+//!
+//! Uses the Hertel-Mehlhorn algorithm:
+//! 1. Dropping near-collinear vertices, which would otherwise end up as
+//!    zero-area pieces
+//! 2. Triangulating the polygon (with holes) via `triangulate`
+//! 3. Visiting each interior diagonal and removing it whenever the two pieces
+//!    it separates merge into a convex polygon
+//!
+//! The result has at most 4x the minimum possible number of convex pieces, which
+//! is plenty for feeding `CollisionPolygon2D` or convex `CollisionShape2D`.
+
+use super::triangulate::triangulate;
+use crate::contour::classify::orient;
+use crate::fracture::geometry::polygon_area;
+use godot::prelude::*;
+use std::collections::HashMap;
+
+/// Sine of the smallest turn angle a vertex must make to not count as collinear
+const COLLINEAR_TOLERANCE: f32 = 1e-4;
+
+/// Split a (possibly concave, possibly holed) polygon into convex pieces.
+///
+/// Input that is already convex and has no holes comes back as a single piece,
+/// cleaned and wound like every other piece.
+///
+/// # Arguments
+/// * `outer` - Outer boundary, any winding
+/// * `holes` - Holes inside the outer boundary, any winding
+///
+/// # Returns
+/// Convex polygons with positive signed area, or empty if the outer ring is degenerate
+pub fn decompose_convex(outer: &[Vector2], holes: &[Vec<Vector2>]) -> Vec<Vec<Vector2>> {
+    let outer_clean = remove_collinear(outer);
+    if outer_clean.len() < 3 {
+        return Vec::new();
+    }
+
+    let holes_clean: Vec<Vec<Vector2>> = holes
+        .iter()
+        .map(|h| remove_collinear(h))
+        .filter(|h| h.len() >= 3)
+        .collect();
+
+    if holes_clean.is_empty() && is_convex(&outer_clean) {
+        let mut piece = outer_clean;
+        orient(&mut piece, true);
+        return vec![piece];
+    }
+
+    let mut points = outer_clean.clone();
+    for hole in &holes_clean {
+        points.extend_from_slice(hole);
+    }
+
+    let indices = triangulate(&outer_clean, &holes_clean);
+
+    // Pieces as vertex index loops, plus which piece owns each directed edge
+    let mut pieces: Vec<Option<Vec<usize>>> = indices
+        .chunks_exact(3)
+        .map(|t| Some(t.iter().map(|&i| i as usize).collect()))
+        .collect();
+    let mut owner: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut diagonals = Vec::new();
+
+    for (p, piece) in pieces.iter().enumerate() {
+        let piece = piece.as_ref().unwrap();
+        for k in 0..3 {
+            let edge = (piece[k], piece[(k + 1) % 3]);
+            owner.insert(edge, p);
+        }
+    }
+
+    // Interior edges are owned in both directions; visit each one once
+    for piece in pieces.iter().flatten() {
+        for k in 0..3 {
+            let (a, b) = (piece[k], piece[(k + 1) % 3]);
+            if a < b && owner.contains_key(&(b, a)) {
+                diagonals.push((a, b));
+            }
+        }
+    }
+
+    for (a, b) in diagonals {
+        let (Some(&p), Some(&q)) = (owner.get(&(a, b)), owner.get(&(b, a))) else {
+            continue;
+        };
+        if p == q {
+            continue;
+        }
+
+        let (Some(first), Some(second)) = (&pieces[p], &pieces[q]) else {
+            continue;
+        };
+        let Some(merged) = merge_pieces(first, second, a, b) else {
+            continue;
+        };
+
+        // Only the diagonal's endpoints change their interior angle
+        let convex = merged.iter().enumerate().all(|(k, &v)| {
+            (v != a && v != b) || is_convex_at(&points, &merged, k)
+        });
+        if !convex {
+            continue;
+        }
+
+        for k in 0..second.len() {
+            let edge = (second[k], second[(k + 1) % second.len()]);
+            owner.insert(edge, p);
+        }
+        owner.remove(&(a, b));
+        owner.remove(&(b, a));
+
+        pieces[p] = Some(merged);
+        pieces[q] = None;
+    }
+
+    pieces
+        .into_iter()
+        .flatten()
+        .map(|piece| remove_collinear(&piece.iter().map(|&i| points[i]).collect::<Vec<_>>()))
+        .filter(|piece| piece.len() >= 3 && polygon_area(piece) > 0.0)
+        .collect()
+}
+
+/// Check whether a polygon is convex (in either winding), ignoring collinear vertices.
+pub fn is_convex(polygon: &[Vector2]) -> bool {
+    let n = polygon.len();
+    if n < 3 {
+        return false;
+    }
+
+    let mut sign = 0.0f32;
+    for i in 0..n {
+        let turn = turn_sine(polygon[(i + n - 1) % n], polygon[i], polygon[(i + 1) % n]);
+        if turn.abs() <= COLLINEAR_TOLERANCE {
+            continue;
+        }
+        if sign == 0.0 {
+            sign = turn.signum();
+        } else if turn.signum() != sign {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Join two pieces sharing the diagonal `a`-`b`.
+///
+/// `first` must contain the directed edge `a -> b` and `second` the edge `b -> a`.
+fn merge_pieces(first: &[usize], second: &[usize], a: usize, b: usize) -> Option<Vec<usize>> {
+    let i = directed_edge_position(first, a, b)?;
+    let j = directed_edge_position(second, b, a)?;
+
+    // Walk `first` from b round to a, then `second` from after a round to before b
+    let mut merged = Vec::with_capacity(first.len() + second.len() - 2);
+    merged.extend((1..=first.len()).map(|k| first[(i + k) % first.len()]));
+    merged.extend((2..second.len()).map(|k| second[(j + k) % second.len()]));
+    Some(merged)
+}
+
+/// Position `k` in a loop where `loop[k] == a` and the next vertex is `b`
+fn directed_edge_position(ring: &[usize], a: usize, b: usize) -> Option<usize> {
+    (0..ring.len()).find(|&k| ring[k] == a && ring[(k + 1) % ring.len()] == b)
+}
+
+/// Check whether the vertex at loop position `k` is convex (or straight) in a
+/// positive-area loop.
+fn is_convex_at(points: &[Vector2], ring: &[usize], k: usize) -> bool {
+    let n = ring.len();
+    let prev = points[ring[(k + n - 1) % n]];
+    let cur = points[ring[k]];
+    let next = points[ring[(k + 1) % n]];
+    turn_sine(prev, cur, next) >= -COLLINEAR_TOLERANCE
+}
+
+/// Remove duplicate and near-collinear vertices from a ring.
+///
+/// A duplicated closing vertex is removed too.
+fn remove_collinear(ring: &[Vector2]) -> Vec<Vector2> {
+    let mut cleaned = ring.to_vec();
+
+    // Repeated points, including an explicit closing vertex, would otherwise take
+    // both copies out as zero-length turns
+    cleaned.dedup();
+    if cleaned.len() > 1 && cleaned.first() == cleaned.last() {
+        cleaned.pop();
+    }
+
+    // Removing a vertex can make its neighbours collinear, so repeat until stable
+    loop {
+        let n = cleaned.len();
+        if n < 3 {
+            return cleaned;
+        }
+
+        let before = n;
+        let mut kept = Vec::with_capacity(n);
+        for i in 0..n {
+            let prev = kept.last().copied().unwrap_or(cleaned[n - 1]);
+            let next = cleaned[(i + 1) % n];
+            if turn_sine(prev, cleaned[i], next).abs() > COLLINEAR_TOLERANCE {
+                kept.push(cleaned[i]);
+            }
+        }
+
+        cleaned = kept;
+        if cleaned.len() == before {
+            return cleaned;
+        }
+    }
+}
+
+/// Sine of the turn angle at `cur`; positive for a turn towards positive area.
+///
+/// Zero-length edges count as no turn at all.
+fn turn_sine(prev: Vector2, cur: Vector2, next: Vector2) -> f32 {
+    let u = cur - prev;
+    let v = next - cur;
+    let lengths = u.length() * v.length();
+    if lengths <= f32::EPSILON {
+        return 0.0;
+    }
+    (u.x * v.y - u.y * v.x) / lengths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clockwise_convex_input_is_cleaned_and_turned() {
+        // CW, with a point halfway along the bottom edge and the first point repeated
+        let square = [
+            Vector2::new(0.0, 0.0),
+            Vector2::new(0.0, 10.0),
+            Vector2::new(10.0, 10.0),
+            Vector2::new(10.0, 0.0),
+            Vector2::new(5.0, 0.0),
+            Vector2::new(0.0, 0.0),
+        ];
+
+        let pieces = decompose_convex(&square, &[]);
+        assert_eq!(pieces.len(), 1);
+        assert_eq!(pieces[0].len(), 4);
+        assert!((polygon_area(&pieces[0]) - 100.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn clockwise_concave_input_gives_positive_convex_pieces() {
+        let l_shape = [
+            Vector2::new(0.0, 0.0),
+            Vector2::new(0.0, 20.0),
+            Vector2::new(20.0, 20.0),
+            Vector2::new(20.0, 10.0),
+            Vector2::new(10.0, 10.0),
+            Vector2::new(10.0, 0.0),
+        ];
+
+        let pieces = decompose_convex(&l_shape, &[]);
+        assert!(pieces.len() >= 2);
+        for piece in &pieces {
+            assert!(polygon_area(piece) > 0.0);
+            assert!(is_convex(piece));
+        }
+        let total: f32 = pieces.iter().map(|piece| polygon_area(piece)).sum();
+        assert!((total - 300.0).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,102 @@
+//! Convex hulls for cheap collision bounds
+//!
+//! Uses Andrew's monotone chain:
+//! 1. Sorting the points by x, then y, and dropping duplicates
+//! 2. Building the lower hull left to right, popping every point that doesn't
+//!    make a left turn
+//! 3. Building the upper hull right to left the same way
+//!
+//! Runs in O(n log n) and never produces collinear hull vertices.
+
+use godot::prelude::*;
+
+/// Compute the convex hull of a set of points.
+///
+/// Fewer than 3 unique points have no hull polygon, so they are returned
+/// directly (deduplicated and sorted). All-collinear input reduces to its two
+/// end points.
+///
+/// # Returns
+/// The hull vertices with positive signed area, starting at the point with the
+/// smallest x (then y)
+pub fn convex_hull(points: &[Vector2]) -> Vec<Vector2> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    sorted.dedup();
+
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let mut hull: Vec<Vector2> = Vec::with_capacity(sorted.len() + 1);
+
+    // Lower hull
+    for &point in &sorted {
+        while hull.len() >= 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], point) <= 0.0 {
+            hull.pop();
+        }
+        hull.push(point);
+    }
+
+    // Upper hull, stopping short of the lower hull it joins
+    let lower_len = hull.len() + 1;
+    for &point in sorted.iter().rev().skip(1) {
+        while hull.len() >= lower_len
+            && cross(hull[hull.len() - 2], hull[hull.len() - 1], point) <= 0.0
+        {
+            hull.pop();
+        }
+        hull.push(point);
+    }
+
+    // The last point is the first one again
+    hull.pop();
+    hull
+}
+
+/// Z component of the cross product (a - o) x (b - o)
+fn cross(o: Vector2, a: Vector2, b: Vector2) -> f32 {
+    (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fracture::geometry::polygon_area;
+
+    #[test]
+    fn interior_and_edge_points_are_dropped() {
+        let points = [
+            Vector2::new(0.0, 0.0),
+            Vector2::new(2.0, 1.0),
+            Vector2::new(4.0, 0.0),
+            Vector2::new(2.0, 0.0),
+            Vector2::new(4.0, 4.0),
+            Vector2::new(1.0, 3.0),
+            Vector2::new(0.0, 4.0),
+        ];
+
+        let hull = convex_hull(&points);
+        assert_eq!(
+            hull,
+            vec![
+                Vector2::new(0.0, 0.0),
+                Vector2::new(4.0, 0.0),
+                Vector2::new(4.0, 4.0),
+                Vector2::new(0.0, 4.0),
+            ]
+        );
+        assert!((polygon_area(&hull) - 16.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn degenerate_input_is_returned_unique() {
+        let a = Vector2::new(1.0, 1.0);
+        let b = Vector2::new(3.0, 2.0);
+
+        assert!(convex_hull(&[]).is_empty());
+        assert_eq!(convex_hull(&[b, a, b, a]), vec![a, b]);
+        // Collinear points reduce to the two ends
+        assert_eq!(convex_hull(&[b, Vector2::new(2.0, 1.5), a]), vec![a, b]);
+    }
+}
@@ -0,0 +1,15 @@
+//! General-purpose polygon geometry exposed to GDScript
+//!
+//! This module provides:
+//! - `CutoutGeometry` - Stateless Godot API wrapping the shared geometry helpers
+//! - Triangulation - Ear clipping of polygons with holes for mesh generation
+//! - Convex decomposition - Hertel-Mehlhorn splitting for collision shapes
+//! - Convex hulls - Andrew's monotone chain for cheap collision bounds
+//! - Boolean operations - Clipper2 union, intersection, difference and xor
+
+pub mod convex;
+pub mod hull;
+pub mod processor;
+pub mod triangulate;
+
+pub use processor::CutoutGeometry;
@@ -0,0 +1,360 @@
+//! CutoutGeometry - GDScript access to the shared geometry helpers
+//!
+//! Wraps the helpers in `fracture::geometry` so GDScript code can use the same
+//! battle-tested implementations as the fracture algorithms. Follows the same
+//! stateless Processor pattern as CutoutContourProcessor.
+
+use super::convex;
+use super::hull;
+use super::triangulate;
+use crate::contour::{AlgorithmKind, CutoutContourProcessor};
+use crate::error::{cutout_error, CutoutError};
+use crate::fracture::clipper_utils::{clipper2_boolean, clipper2_offset, BooleanOp};
+use crate::fracture::geometry;
+use clipper2::{FillRule, JoinType};
+use godot::classes::Image;
+use godot::prelude::*;
+
+/// Stateless polygon geometry utilities.
+///
+/// All methods can be called directly without instantiation.
+#[derive(GodotClass)]
+#[class(no_init)]
+pub struct CutoutGeometry;
+
+#[godot_api]
+impl CutoutGeometry {
+    /// Offset corner style: sharp corners, beveled past the miter limit
+    #[constant]
+    pub const JOIN_MITER: i32 = 0;
+    /// Offset corner style: rounded corners
+    #[constant]
+    pub const JOIN_ROUND: i32 = 1;
+    /// Offset corner style: squared-off corners
+    #[constant]
+    pub const JOIN_SQUARE: i32 = 2;
+
+    /// Boolean fill rule: regions enclosed an odd number of times are filled
+    #[constant]
+    pub const FILL_EVEN_ODD: i32 = 0;
+    /// Boolean fill rule: regions with a non-zero winding number are filled
+    #[constant]
+    pub const FILL_NON_ZERO: i32 = 1;
+    /// Boolean fill rule: regions with a positive winding number are filled
+    #[constant]
+    pub const FILL_POSITIVE: i32 = 2;
+    /// Boolean fill rule: regions with a negative winding number are filled
+    #[constant]
+    pub const FILL_NEGATIVE: i32 = 3;
+
+    /// Check if a point is inside a polygon using ray casting.
+    ///
+    /// # Arguments
+    /// * `point` - Point to test
+    /// * `polygon` - Polygon vertices (at least 3)
+    ///
+    /// # Returns
+    /// True if the point is inside the polygon. Points on the boundary count as inside.
+    #[func]
+    pub fn point_in_polygon(point: Vector2, polygon: PackedVector2Array) -> bool {
+        geometry::point_in_polygon(point, polygon.as_slice())
+    }
+
+    /// Check if a point lies on a polygon's boundary.
+    ///
+    /// # Arguments
+    /// * `point` - Point to test
+    /// * `polygon` - Polygon vertices
+    /// * `eps` - Maximum distance from an edge to still count as on it
+    ///
+    /// # Returns
+    /// True if the point is within `eps` of any edge or vertex
+    #[func]
+    pub fn point_on_boundary(point: Vector2, polygon: PackedVector2Array, eps: f32) -> bool {
+        geometry::point_on_boundary(point, polygon.as_slice(), eps)
+    }
+
+    /// Calculate the signed area of a polygon using the shoelace formula.
+    ///
+    /// # Returns
+    /// Positive area for CCW winding, negative for CW winding
+    #[func]
+    pub fn polygon_area(polygon: PackedVector2Array) -> f32 {
+        geometry::polygon_area(polygon.as_slice())
+    }
+
+    /// Calculate the bounding rectangle of a polygon.
+    ///
+    /// # Returns
+    /// The bounding rectangle, or an empty Rect2 for an empty polygon
+    #[func]
+    pub fn calculate_bounds(polygon: PackedVector2Array) -> Rect2 {
+        geometry::calculate_bounds(polygon.as_slice())
+    }
+
+    /// Calculate the circumcenter of a triangle.
+    ///
+    /// # Returns
+    /// The circumcenter as a Vector2, or null if the points are (nearly) collinear
+    #[func]
+    pub fn circumcenter(a: Vector2, b: Vector2, c: Vector2) -> Variant {
+        match geometry::circumcenter(a, b, c) {
+            Some(center) => center.to_variant(),
+            None => Variant::nil(),
+        }
+    }
+
+    /// Clip a polygon to the half-plane on the side its normal points to.
+    ///
+    /// # Arguments
+    /// * `polygon` - Polygon vertices
+    /// * `plane_point` - Any point on the clipping line
+    /// * `plane_normal` - Normal pointing into the kept half
+    ///
+    /// # Returns
+    /// The clipped polygon (empty if nothing remains)
+    #[func]
+    pub fn clip_polygon_to_half_plane(
+        polygon: PackedVector2Array,
+        plane_point: Vector2,
+        plane_normal: Vector2,
+    ) -> PackedVector2Array {
+        let clipped =
+            geometry::clip_polygon_to_half_plane(polygon.as_slice(), plane_point, plane_normal);
+        PackedVector2Array::from(clipped.as_slice())
+    }
+
+    /// Triangulate a polygon with holes for mesh generation.
+    ///
+    /// # Arguments
+    /// * `outer` - Outer boundary, any winding
+    /// * `holes` - Holes inside the outer boundary, any winding
+    ///
+    /// # Returns
+    /// Flat triangle index buffer (every 3 indices = one triangle) referencing the
+    /// vertex list `outer + holes[0] + holes[1] + ...`. All triangles are wound
+    /// clockwise on screen, ready for `ArrayMesh`.
+    #[func]
+    pub fn triangulate(
+        outer: PackedVector2Array,
+        holes: Array<PackedVector2Array>,
+    ) -> PackedInt32Array {
+        let holes: Vec<Vec<Vector2>> = holes.iter_shared().map(|h| h.to_vec()).collect();
+        let indices = triangulate::triangulate(outer.as_slice(), &holes);
+        PackedInt32Array::from(indices.as_slice())
+    }
+
+    /// Split a concave polygon into convex pieces for physics.
+    ///
+    /// Already-convex input is returned as-is. Near-collinear vertices are
+    /// dropped so no zero-area pieces are produced.
+    ///
+    /// # Arguments
+    /// * `polygon` - Polygon vertices, any winding
+    ///
+    /// # Returns
+    /// Array of convex polygons, ready for `CollisionPolygon2D` or
+    /// `ConvexPolygonShape2D`
+    #[func]
+    pub fn decompose_convex(polygon: PackedVector2Array) -> Array<PackedVector2Array> {
+        Self::to_godot_array(convex::decompose_convex(polygon.as_slice(), &[]))
+    }
+
+    /// Split a concave polygon with holes into convex pieces for physics.
+    ///
+    /// # Arguments
+    /// * `outer` - Outer boundary, any winding
+    /// * `holes` - Holes inside the outer boundary, any winding
+    ///
+    /// # Returns
+    /// Array of convex polygons covering the outer boundary minus the holes
+    #[func]
+    pub fn decompose_convex_with_holes(
+        outer: PackedVector2Array,
+        holes: Array<PackedVector2Array>,
+    ) -> Array<PackedVector2Array> {
+        let holes: Vec<Vec<Vector2>> = holes.iter_shared().map(|h| h.to_vec()).collect();
+        Self::to_godot_array(convex::decompose_convex(outer.as_slice(), &holes))
+    }
+
+    /// Compute the convex hull of a set of points (Andrew's monotone chain).
+    ///
+    /// # Arguments
+    /// * `points` - Points in any order; duplicates are ignored
+    ///
+    /// # Returns
+    /// The hull as a single convex polygon with positive signed area, ready for
+    /// `ConvexPolygonShape2D`. Fewer than 3 unique points are returned directly.
+    #[func]
+    pub fn convex_hull(points: PackedVector2Array) -> PackedVector2Array {
+        PackedVector2Array::from(hull::convex_hull(points.as_slice()).as_slice())
+    }
+
+    /// Compute the convex hull of an image's solid pixels.
+    ///
+    /// Traces the image with Marching Squares and takes the hull of every
+    /// contour vertex, for a collision shape far cheaper than the full contours.
+    ///
+    /// # Arguments
+    /// * `image` - Image to process
+    /// * `alpha_threshold` - Alpha threshold for solid pixels
+    /// * `max_resolution` - Maximum resolution (NO_RESOLUTION_LIMIT = no limit)
+    ///
+    /// # Returns
+    /// The hull in image space, or an empty array if the image has no solid pixels
+    #[func]
+    pub fn image_convex_hull(
+        image: Gd<Image>,
+        alpha_threshold: f32,
+        max_resolution: Vector2,
+    ) -> PackedVector2Array {
+        let contours = CutoutContourProcessor::calculate_single(
+            image,
+            AlgorithmKind::MarchingSquares as i32,
+            alpha_threshold,
+            max_resolution,
+        );
+
+        // Holes lie inside their outer rings, so they can't change the hull
+        let points: Vec<Vector2> = contours
+            .iter_shared()
+            .flat_map(|contour| contour.to_vec())
+            .collect();
+        PackedVector2Array::from(hull::convex_hull(&points).as_slice())
+    }
+
+    /// Offset a polygon outward or inward by a fixed distance.
+    ///
+    /// Useful for outlines, glow boundaries, or shrinking a collision shape to sit
+    /// inside a sprite.
+    ///
+    /// # Arguments
+    /// * `polygon` - Polygon vertices, any winding
+    /// * `delta` - Offset distance; positive outsets, negative insets
+    /// * `join_type` - Corner style: `JOIN_MITER`, `JOIN_ROUND` or `JOIN_SQUARE`
+    ///
+    /// # Returns
+    /// Array of offset polygons. Insetting a thin shape can split it into several
+    /// polygons or remove it entirely.
+    #[func]
+    pub fn offset_polygon(
+        polygon: PackedVector2Array,
+        delta: f32,
+        join_type: i32,
+    ) -> Array<PackedVector2Array> {
+        let join = match join_type {
+            Self::JOIN_MITER => JoinType::Miter,
+            Self::JOIN_ROUND => JoinType::Round,
+            Self::JOIN_SQUARE => JoinType::Square,
+            _ => {
+                cutout_error!(
+                    CutoutError::UnknownOption,
+                    "Unknown join type: {}, defaulting to Miter",
+                    join_type
+                );
+                JoinType::Miter
+            }
+        };
+
+        Self::to_godot_array(clipper2_offset(polygon.as_slice(), delta, join))
+    }
+
+    // ========================================================================
+    // Boolean Operations
+    // ========================================================================
+
+    /// Merge two sets of polygons into the area covered by either.
+    ///
+    /// # Arguments
+    /// * `subject` - Subject polygons, any winding
+    /// * `clip` - Clip polygons, any winding
+    /// * `fill_rule` - How overlapping paths within a set combine: `FILL_EVEN_ODD`,
+    ///   `FILL_NON_ZERO`, `FILL_POSITIVE` or `FILL_NEGATIVE`
+    ///
+    /// # Returns
+    /// Array of result polygons. Outer boundaries have a positive `polygon_area`
+    /// and holes a negative one, each returned as its own polygon.
+    #[func]
+    pub fn union(
+        subject: Array<PackedVector2Array>,
+        clip: Array<PackedVector2Array>,
+        fill_rule: i32,
+    ) -> Array<PackedVector2Array> {
+        Self::boolean(BooleanOp::Union, subject, clip, fill_rule)
+    }
+
+    /// Keep only the area covered by both sets of polygons.
+    ///
+    /// See `union` for the arguments and result winding.
+    #[func]
+    pub fn intersection(
+        subject: Array<PackedVector2Array>,
+        clip: Array<PackedVector2Array>,
+        fill_rule: i32,
+    ) -> Array<PackedVector2Array> {
+        Self::boolean(BooleanOp::Intersection, subject, clip, fill_rule)
+    }
+
+    /// Cut the clip polygons out of the subject polygons.
+    ///
+    /// See `union` for the arguments and result winding.
+    #[func]
+    pub fn difference(
+        subject: Array<PackedVector2Array>,
+        clip: Array<PackedVector2Array>,
+        fill_rule: i32,
+    ) -> Array<PackedVector2Array> {
+        Self::boolean(BooleanOp::Difference, subject, clip, fill_rule)
+    }
+
+    /// Keep the area covered by exactly one of the two sets of polygons.
+    ///
+    /// See `union` for the arguments and result winding.
+    #[func]
+    pub fn xor(
+        subject: Array<PackedVector2Array>,
+        clip: Array<PackedVector2Array>,
+        fill_rule: i32,
+    ) -> Array<PackedVector2Array> {
+        Self::boolean(BooleanOp::Xor, subject, clip, fill_rule)
+    }
+}
+
+impl CutoutGeometry {
+    /// Run a Clipper2 boolean operation on two Godot polygon arrays
+    fn boolean(
+        op: BooleanOp,
+        subject: Array<PackedVector2Array>,
+        clip: Array<PackedVector2Array>,
+        fill_rule: i32,
+    ) -> Array<PackedVector2Array> {
+        let fill_rule = match fill_rule {
+            Self::FILL_EVEN_ODD => FillRule::EvenOdd,
+            Self::FILL_NON_ZERO => FillRule::NonZero,
+            Self::FILL_POSITIVE => FillRule::Positive,
+            Self::FILL_NEGATIVE => FillRule::Negative,
+            _ => {
+                cutout_error!(
+                    CutoutError::UnknownOption,
+                    "Unknown fill rule: {}, defaulting to NonZero",
+                    fill_rule
+                );
+                FillRule::NonZero
+            }
+        };
+
+        let subject: Vec<Vec<Vector2>> = subject.iter_shared().map(|p| p.to_vec()).collect();
+        let clip: Vec<Vec<Vector2>> = clip.iter_shared().map(|p| p.to_vec()).collect();
+        Self::to_godot_array(clipper2_boolean(op, &subject, &clip, fill_rule))
+    }
+
+    /// Convert Vec<Vec<Vector2>> to a Godot polygon array
+    fn to_godot_array(polygons: Vec<Vec<Vector2>>) -> Array<PackedVector2Array> {
+        let mut result = Array::new();
+        result.resize(polygons.len(), &PackedVector2Array::new());
+        for (i, polygon) in polygons.iter().enumerate() {
+            result.set(i, &PackedVector2Array::from(polygon.as_slice()));
+        }
+        result
+    }
+}
@@ -0,0 +1,279 @@
+//! Ear-clipping triangulation of polygons with holes
+//!
+//! Reference GDScript: addons/cutout/utils/cutout_geometry_utils.gd (ear_clipping_triangulation)
+//!
+//! This algorithm works by:
+//! 1. Orienting the outer ring CCW (positive `polygon_area`) and holes CW
+//! 2. Merging each hole into the outer ring through a bridge edge, from the
+//!    hole's rightmost vertex to the closest ring vertex it can see. Holes are
+//!    merged rightmost first so a bridge never has to cross an unmerged hole.
+//! 3. Repeatedly clipping convex "ears" (vertices whose triangle with their two
+//!    neighbours contains no other vertex) off the merged ring
+//!
+//! Bridge vertices appear twice in the merged ring but keep their original
+//! index, so the output references the caller's vertices directly.
+
+use crate::fracture::geometry::polygon_area;
+use godot::prelude::*;
+
+/// Triangulate a polygon with holes.
+///
+/// Vertex indices refer to the concatenation of `outer` followed by each hole in
+/// order. Every output triangle has positive signed area (the same orientation as
+/// a CCW outer ring), which is clockwise on screen in Godot's y-down 2D space.
+///
+/// An explicitly closed ring (last vertex equal to the first) is accepted; the
+/// duplicate vertex is simply never referenced.
+///
+/// # Arguments
+/// * `outer` - Outer boundary, any winding
+/// * `holes` - Holes inside the outer boundary, any winding
+///
+/// # Returns
+/// Flat triangle index buffer (every 3 indices = one triangle), or empty if the
+/// outer ring has fewer than 3 vertices
+pub fn triangulate(outer: &[Vector2], holes: &[Vec<Vector2>]) -> Vec<i32> {
+    let mut ring = ring_indices(0, outer);
+    if ring.len() < 3 {
+        return Vec::new();
+    }
+    if polygon_area(outer) < 0.0 {
+        ring.reverse();
+    }
+
+    let mut points: Vec<Vector2> = outer.to_vec();
+    let mut hole_rings: Vec<Vec<usize>> = Vec::with_capacity(holes.len());
+    for hole in holes {
+        let base = points.len();
+        points.extend_from_slice(hole);
+
+        let mut hole_ring = ring_indices(base, hole);
+        if hole_ring.len() < 3 {
+            continue;
+        }
+
+        if polygon_area(hole) > 0.0 {
+            hole_ring.reverse();
+        }
+        hole_rings.push(hole_ring);
+    }
+
+    // Rightmost holes first, so bridges only ever cross merged geometry
+    hole_rings.sort_by(|a, b| max_x(&points, b).total_cmp(&max_x(&points, a)));
+
+    for h in 0..hole_rings.len() {
+        let (hole, remaining) = hole_rings[h..].split_first().unwrap();
+        ring = bridge_hole(&points, ring, hole, remaining);
+    }
+
+    ear_clip(&points, ring)
+        .into_iter()
+        .flat_map(|tri| tri.map(|i| i as i32))
+        .collect()
+}
+
+/// Indices of a ring's vertices, skipping a duplicated closing vertex.
+fn ring_indices(base: usize, ring: &[Vector2]) -> Vec<usize> {
+    let len = if ring.len() > 1 && ring.first() == ring.last() {
+        ring.len() - 1
+    } else {
+        ring.len()
+    };
+    (base..base + len).collect()
+}
+
+/// Largest x coordinate of a ring's vertices
+fn max_x(points: &[Vector2], ring: &[usize]) -> f32 {
+    ring.iter()
+        .map(|&i| points[i].x)
+        .fold(f32::NEG_INFINITY, f32::max)
+}
+
+/// Merge a hole into the ring via a bridge from the hole's rightmost vertex.
+///
+/// The bridge goes to the closest ring vertex that is visible (the bridge crosses
+/// no edge) and whose interior angle the bridge enters. If no such vertex exists
+/// (malformed input), the hole is left out.
+fn bridge_hole(
+    points: &[Vector2],
+    ring: Vec<usize>,
+    hole: &[usize],
+    other_holes: &[Vec<usize>],
+) -> Vec<usize> {
+    let m_pos = (0..hole.len())
+        .max_by(|&a, &b| points[hole[a]].x.total_cmp(&points[hole[b]].x))
+        .unwrap();
+    let m = points[hole[m_pos]];
+
+    let mut candidates: Vec<usize> = (0..ring.len()).collect();
+    candidates.sort_by(|&a, &b| {
+        let da = points[ring[a]].distance_squared_to(m);
+        let db = points[ring[b]].distance_squared_to(m);
+        da.total_cmp(&db)
+    });
+
+    let visible = candidates.into_iter().find(|&c| {
+        let v = points[ring[c]];
+        let prev = points[ring[(c + ring.len() - 1) % ring.len()]];
+        let next = points[ring[(c + 1) % ring.len()]];
+
+        locally_inside(prev, v, next, m)
+            && !crosses_ring(points, &ring, v, m)
+            && !crosses_ring(points, hole, v, m)
+            && !other_holes.iter().any(|h| crosses_ring(points, h, v, m))
+    });
+
+    let Some(c) = visible else {
+        return ring;
+    };
+
+    // ..., v, M, hole..., M, v, ...
+    let mut merged = Vec::with_capacity(ring.len() + hole.len() + 2);
+    merged.extend_from_slice(&ring[..=c]);
+    merged.extend((0..=hole.len()).map(|k| hole[(m_pos + k) % hole.len()]));
+    merged.push(ring[c]);
+    merged.extend_from_slice(&ring[c + 1..]);
+    merged
+}
+
+/// Check whether the direction from `v` towards `target` enters the interior of
+/// a CCW ring at vertex `v`, given its neighbours.
+fn locally_inside(prev: Vector2, v: Vector2, next: Vector2, target: Vector2) -> bool {
+    let left_of_incoming = cross(prev, v, target) >= 0.0;
+    let left_of_outgoing = cross(v, next, target) >= 0.0;
+
+    if cross(prev, v, next) >= 0.0 {
+        // Convex: interior is the intersection of both left half-planes
+        left_of_incoming && left_of_outgoing
+    } else {
+        // Reflex: interior is their union
+        left_of_incoming || left_of_outgoing
+    }
+}
+
+/// Check whether segment `a`-`b` properly crosses any edge of a ring.
+///
+/// Edges that merely touch the segment at a shared endpoint don't count.
+fn crosses_ring(points: &[Vector2], ring: &[usize], a: Vector2, b: Vector2) -> bool {
+    (0..ring.len()).any(|i| {
+        let p = points[ring[i]];
+        let q = points[ring[(i + 1) % ring.len()]];
+        if p == a || p == b || q == a || q == b {
+            return false;
+        }
+        segments_cross(a, b, p, q)
+    })
+}
+
+/// Check whether two segments intersect, including touching and collinear overlap.
+fn segments_cross(a: Vector2, b: Vector2, p: Vector2, q: Vector2) -> bool {
+    let d1 = cross(a, b, p);
+    let d2 = cross(a, b, q);
+    let d3 = cross(p, q, a);
+    let d4 = cross(p, q, b);
+
+    if ((d1 > 0.0 && d2 < 0.0) || (d1 < 0.0 && d2 > 0.0))
+        && ((d3 > 0.0 && d4 < 0.0) || (d3 < 0.0 && d4 > 0.0))
+    {
+        return true;
+    }
+
+    (d1 == 0.0 && on_segment(a, b, p))
+        || (d2 == 0.0 && on_segment(a, b, q))
+        || (d3 == 0.0 && on_segment(p, q, a))
+        || (d4 == 0.0 && on_segment(p, q, b))
+}
+
+/// Check whether a point known to be collinear with `a`-`b` lies on the segment.
+fn on_segment(a: Vector2, b: Vector2, p: Vector2) -> bool {
+    p.x >= a.x.min(b.x) && p.x <= a.x.max(b.x) && p.y >= a.y.min(b.y) && p.y <= a.y.max(b.y)
+}
+
+/// Clip ears off a CCW ring until only triangles remain.
+///
+/// If a full pass finds no ear (collinear runs or slightly self-intersecting
+/// input), the most degenerate vertex is cut off instead so the loop always ends.
+fn ear_clip(points: &[Vector2], mut ring: Vec<usize>) -> Vec<[usize; 3]> {
+    let mut triangles = Vec::with_capacity(ring.len().saturating_sub(2));
+    let mut i = 0;
+    let mut since_last_ear = 0;
+
+    while ring.len() > 3 {
+        let n = ring.len();
+        i %= n;
+
+        if is_ear(points, &ring, i) {
+            triangles.push(triangle_at(&ring, i));
+            ring.remove(i);
+            since_last_ear = 0;
+            continue;
+        }
+
+        i += 1;
+        since_last_ear += 1;
+
+        if since_last_ear >= n {
+            // No ear left: cut the flattest vertex, keeping it only if it has area
+            let flattest = (0..n)
+                .min_by(|&a, &b| {
+                    let [pa, ca, na] = triangle_at(&ring, a).map(|v| points[v]);
+                    let [pb, cb, nb] = triangle_at(&ring, b).map(|v| points[v]);
+                    cross(pa, ca, na).abs().total_cmp(&cross(pb, cb, nb).abs())
+                })
+                .unwrap();
+
+            let tri = triangle_at(&ring, flattest);
+            if triangle_has_area(points, tri) {
+                triangles.push(tri);
+            }
+            ring.remove(flattest);
+            since_last_ear = 0;
+        }
+    }
+
+    if let [a, b, c] = ring[..] {
+        if triangle_has_area(points, [a, b, c]) {
+            triangles.push([a, b, c]);
+        }
+    }
+
+    triangles
+}
+
+/// The triangle formed by the vertex at ring position `i` and its neighbours.
+fn triangle_at(ring: &[usize], i: usize) -> [usize; 3] {
+    let n = ring.len();
+    [ring[(i + n - 1) % n], ring[i], ring[(i + 1) % n]]
+}
+
+fn triangle_has_area(points: &[Vector2], [a, b, c]: [usize; 3]) -> bool {
+    cross(points[a], points[b], points[c]) > 0.0
+}
+
+/// Check whether the vertex at ring position `i` is an ear.
+fn is_ear(points: &[Vector2], ring: &[usize], i: usize) -> bool {
+    let tri = triangle_at(ring, i);
+    if !triangle_has_area(points, tri) {
+        return false;
+    }
+
+    let [a, b, c] = tri.map(|v| points[v]);
+
+    // Bridge vertices are duplicated, so compare positions, not ring slots
+    !ring.iter().any(|&v| {
+        let p = points[v];
+        p != a && p != b && p != c && point_in_triangle(p, a, b, c)
+    })
+}
+
+/// Check whether a point lies inside or on a CCW triangle.
+fn point_in_triangle(p: Vector2, a: Vector2, b: Vector2, c: Vector2) -> bool {
+    cross(a, b, p) >= 0.0 && cross(b, c, p) >= 0.0 && cross(c, a, p) >= 0.0
+}
+
+/// Z component of the cross product of `a - o` and `b - o`.
+///
+/// Positive when `o`, `a`, `b` turn counter-clockwise (positive-area orientation).
+fn cross(o: Vector2, a: Vector2, b: Vector2) -> f32 {
+    (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
+}
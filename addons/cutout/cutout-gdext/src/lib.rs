@@ -1,21 +1,51 @@
+use godot::classes::Engine;
 use godot::prelude::*;
 
 // Module declarations
 pub(crate) mod common;
 pub mod contour;
+pub mod error;
+pub mod facade;
 pub mod fracture;
+pub mod geometry;
 pub mod simplify;
 
 // Import classes to ensure they're registered with Godot
 #[allow(unused_imports)]
 use contour::CutoutContourProcessor;
 #[allow(unused_imports)]
+use error::CutoutErrors;
+#[allow(unused_imports)]
+use facade::CutoutFacade;
+#[allow(unused_imports)]
 use fracture::CutoutDestructionProcessor;
+#[allow(unused_imports)]
+use geometry::CutoutGeometry;
+#[allow(unused_imports)]
+use simplify::CutoutSimplifyProcessor;
 
 struct CutoutExtension;
 
 #[gdextension]
-unsafe impl ExtensionLibrary for CutoutExtension {}
+unsafe impl ExtensionLibrary for CutoutExtension {
+    fn on_level_init(level: InitLevel) {
+        // Expose the processors as the `Cutout` engine singleton
+        if level == InitLevel::Scene {
+            Engine::singleton()
+                .register_singleton(facade::SINGLETON_NAME, &CutoutFacade::new_alloc());
+        }
+    }
+
+    fn on_level_deinit(level: InitLevel) {
+        if level == InitLevel::Scene {
+            let mut engine = Engine::singleton();
+            if let Some(singleton) = engine.get_singleton(facade::SINGLETON_NAME) {
+                engine.unregister_singleton(facade::SINGLETON_NAME);
+                singleton.free();
+            }
+        }
+    }
+}
 
 /// Simple test class to verify the extension loads correctly
 #[derive(GodotClass)]
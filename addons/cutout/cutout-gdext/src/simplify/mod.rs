@@ -1,12 +1,14 @@
 //! Polygon simplification algorithms for reducing vertex count
 //!
-//! Currently, polygon simplification is implemented in GDScript for maintainability.
-//! The algorithms are fast enough in GDScript for typical use cases (100-1000 points).
+//! This module provides:
+//! - `CutoutSimplifyProcessor` - Stateless Godot API with single and batch methods
+//! - Ramer-Douglas-Peucker (distance-based)
+//! - Visvalingam-Whyatt (area-based)
 //!
-//! Available GDScript implementations:
-//! - CutoutPolysimpRDP - Ramer-Douglas-Peucker (distance-based)
-//! - CutoutPolysimpVW - Visvalingam-Whyatt (area-based)
-//! - CutoutPolysimpRW - Reumann-Witkam (perpendicular distance)
-//!
-//! Rust implementations may be added in the future if profiling shows
-//! simplification as a performance bottleneck.
\ No newline at end of file
+//! Reumann-Witkam is still implemented in GDScript (CutoutPolysimpRW).
+
+pub mod processor;
+pub mod rdp;
+pub mod visvalingam_whyatt;
+
+pub use processor::CutoutSimplifyProcessor;
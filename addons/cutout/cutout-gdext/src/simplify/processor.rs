@@ -0,0 +1,173 @@
+//! CutoutSimplifyProcessor - GDScript access to polygon simplification
+//!
+//! Follows the same stateless Processor pattern as CutoutContourProcessor, so
+//! contour output can be simplified without constructing any objects. Batch
+//! methods simplify every polygon in parallel on the rayon pool.
+
+use super::rdp;
+use super::visvalingam_whyatt;
+use godot::prelude::*;
+use rayon::prelude::*;
+
+/// Stateless polygon simplification.
+///
+/// All methods can be called directly without instantiation.
+#[derive(GodotClass)]
+#[class(no_init)]
+pub struct CutoutSimplifyProcessor;
+
+#[godot_api]
+impl CutoutSimplifyProcessor {
+    /// Simplify a closed polygon with Ramer-Douglas-Peucker.
+    ///
+    /// # Arguments
+    /// * `polygon` - Polygon vertices; an explicitly closed input stays closed
+    /// * `epsilon` - Points closer than this to the simplified edge are removed
+    ///
+    /// # Returns
+    /// The simplified polygon, or the input unchanged if it has fewer than 3 points
+    #[func]
+    pub fn simplify_rdp(polygon: PackedVector2Array, epsilon: f32) -> PackedVector2Array {
+        PackedVector2Array::from(rdp::simplify(polygon.as_slice(), epsilon).as_slice())
+    }
+
+    /// Simplify a closed polygon with Ramer-Douglas-Peucker, keeping sharp corners.
+    ///
+    /// Smooth stretches are simplified as usual, but spikes and tips that plain RDP
+    /// would shave off survive.
+    ///
+    /// # Arguments
+    /// * `polygon` - Polygon vertices; an explicitly closed input stays closed
+    /// * `epsilon` - Points closer than this to the simplified edge are removed
+    /// * `preserve_angle_degrees` - Corners sharper than this angle are always kept
+    ///   (0 = plain RDP). The angle is measured over about `epsilon` on either side.
+    ///
+    /// # Returns
+    /// The simplified polygon, or the input unchanged if it has fewer than 3 points
+    #[func]
+    pub fn simplify_rdp_preserving_corners(
+        polygon: PackedVector2Array,
+        epsilon: f32,
+        preserve_angle_degrees: f32,
+    ) -> PackedVector2Array {
+        let simplified =
+            rdp::simplify_preserving_corners(polygon.as_slice(), epsilon, preserve_angle_degrees);
+        PackedVector2Array::from(simplified.as_slice())
+    }
+
+    /// Simplify a closed polygon with Ramer-Douglas-Peucker to about `target_points` vertices.
+    ///
+    /// Searches for the epsilon instead of taking one, so the result size is
+    /// predictable whatever the sprite's scale. Useful for generating LODs.
+    ///
+    /// # Arguments
+    /// * `polygon` - Polygon vertices; an explicitly closed input stays closed
+    /// * `target_points` - Desired vertex count; the result never has fewer (minimum 3)
+    ///
+    /// # Returns
+    /// The simplified polygon with as close to `target_points` vertices as found
+    #[func]
+    pub fn simplify_rdp_to_count(
+        polygon: PackedVector2Array,
+        target_points: i32,
+    ) -> PackedVector2Array {
+        let simplified = rdp::simplify_to_count(polygon.as_slice(), target_points.max(0) as usize);
+        PackedVector2Array::from(simplified.as_slice())
+    }
+
+    /// Simplify a closed polygon with Ramer-Douglas-Peucker at several levels of detail.
+    ///
+    /// Gives the same results as calling `simplify_rdp` once per epsilon, but
+    /// shares the work between levels. Handy for `Line2D` outlines at different
+    /// zoom levels.
+    ///
+    /// # Arguments
+    /// * `polygon` - Polygon vertices; an explicitly closed input stays closed
+    /// * `epsilons` - Distance threshold of each level, in any order
+    ///
+    /// # Returns
+    /// One simplified polygon per epsilon, in the same order as `epsilons`
+    #[func]
+    pub fn generate_lods(
+        polygon: PackedVector2Array,
+        epsilons: PackedFloat32Array,
+    ) -> Array<PackedVector2Array> {
+        let lods = rdp::simplify_lods(polygon.as_slice(), epsilons.as_slice());
+
+        let mut result = Array::new();
+        for lod in lods {
+            result.push(&PackedVector2Array::from(lod.as_slice()));
+        }
+        result
+    }
+
+    /// Simplify a closed polygon with Visvalingam-Whyatt.
+    ///
+    /// # Arguments
+    /// * `polygon` - Polygon vertices; an explicitly closed input stays closed
+    /// * `min_area` - Vertices whose effective area is at most this value are removed
+    /// * `target_points` - Stop once this many vertices remain (0 or less = no target)
+    ///
+    /// # Returns
+    /// The simplified polygon, or the input unchanged if it has fewer than 3 points
+    #[func]
+    pub fn simplify_vw(
+        polygon: PackedVector2Array,
+        min_area: f32,
+        target_points: i32,
+    ) -> PackedVector2Array {
+        let simplified = visvalingam_whyatt::simplify(polygon.as_slice(), min_area, target_points);
+        PackedVector2Array::from(simplified.as_slice())
+    }
+
+    /// Simplify many polygons with Ramer-Douglas-Peucker, e.g. the output of
+    /// `CutoutContourProcessor.calculate_single`.
+    ///
+    /// # Returns
+    /// The simplified polygons, in input order
+    #[func]
+    pub fn simplify_rdp_batch(
+        polygons: Array<PackedVector2Array>,
+        epsilon: f32,
+    ) -> Array<PackedVector2Array> {
+        Self::simplify_batch(polygons, |polygon| rdp::simplify(polygon, epsilon))
+    }
+
+    /// Simplify many polygons with Visvalingam-Whyatt.
+    ///
+    /// `target_points` applies to each polygon separately.
+    ///
+    /// # Returns
+    /// The simplified polygons, in input order
+    #[func]
+    pub fn simplify_vw_batch(
+        polygons: Array<PackedVector2Array>,
+        min_area: f32,
+        target_points: i32,
+    ) -> Array<PackedVector2Array> {
+        Self::simplify_batch(polygons, |polygon| {
+            visvalingam_whyatt::simplify(polygon, min_area, target_points)
+        })
+    }
+}
+
+impl CutoutSimplifyProcessor {
+    /// Copy the polygons into Rust and simplify them in parallel
+    fn simplify_batch(
+        polygons: Array<PackedVector2Array>,
+        simplify: impl Fn(&[Vector2]) -> Vec<Vector2> + Sync,
+    ) -> Array<PackedVector2Array> {
+        let polygons: Vec<Vec<Vector2>> = polygons.iter_shared().map(|p| p.to_vec()).collect();
+
+        let simplified: Vec<Vec<Vector2>> = polygons
+            .par_iter()
+            .map(|polygon| simplify(polygon))
+            .collect();
+
+        let mut result = Array::new();
+        for polygon in simplified {
+            result.push(&PackedVector2Array::from(polygon.as_slice()));
+        }
+        result
+    }
+}
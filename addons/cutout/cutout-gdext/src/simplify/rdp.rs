@@ -0,0 +1,430 @@
+//! Ramer-Douglas-Peucker polygon simplification
+//!
+//! Reference GDScript: addons/cutout/resources/polysimp/cutout_polysimp_rdp.gd
+//!
+//! This algorithm works by:
+//! 1. Splitting the closed ring at its first vertex and the vertex furthest from it
+//! 2. For each half, finding the point furthest from the chord between its endpoints
+//! 3. Keeping that point and splitting again if its distance exceeds `epsilon`
+//! 4. Dropping every point that was never kept
+//!
+//! Splitting the ring first avoids the degenerate case of the naive open-polyline
+//! version, where the first and last points coincide and the chord has zero length.
+//!
+//! `simplify_preserving_corners` additionally keeps sharp corners (spikes, tips)
+//! that lie too close to a chord to survive plain RDP. Their angle is measured
+//! between points about `epsilon` away on either side, so pixel staircases don't
+//! register as corners.
+//!
+//! `simplify_pinned` keeps caller-chosen vertices the same way, e.g. vertices
+//! shared with a neighbouring polygon.
+//!
+//! `simplify_to_count` searches for the epsilon that gives a target vertex count,
+//! for predictable LODs regardless of sprite scale.
+//!
+//! `simplify_lods` simplifies at several epsilons at once. RDP's splits don't
+//! depend on epsilon, so it runs the split tree once and records each vertex's
+//! significance (the largest epsilon it survives). Every level is then a filter.
+
+use crate::fracture::geometry::calculate_bounds;
+use godot::prelude::*;
+
+/// Most vertices walked along the ring on each side when measuring a corner angle
+const CORNER_SEARCH_LIMIT: usize = 64;
+
+/// Simplify a closed polygon using the Ramer-Douglas-Peucker algorithm.
+///
+/// The first vertex is always kept. If the input is explicitly closed (last point
+/// equal to the first, as produced by Marching Squares), the output is closed too.
+/// Winding order is preserved.
+///
+/// # Arguments
+/// * `polygon` - Input polygon vertices
+/// * `epsilon` - Distance threshold; points closer than this to the simplified edge are removed
+///
+/// # Returns
+/// The simplified polygon, or the input unchanged if it has fewer than 3 points
+pub fn simplify(polygon: &[Vector2], epsilon: f32) -> Vec<Vector2> {
+    simplify_preserving_corners(polygon, epsilon, 0.0)
+}
+
+/// Simplify a closed polygon with RDP, always keeping corners sharper than a threshold.
+///
+/// # Arguments
+/// * `polygon` - Input polygon vertices
+/// * `epsilon` - Distance threshold; points closer than this to the simplified edge are removed
+/// * `preserve_angle_degrees` - Vertices whose angle is below this are kept regardless
+///   of distance (0 = plain RDP)
+///
+/// # Returns
+/// The simplified polygon, or the input unchanged if it has fewer than 3 points
+pub fn simplify_preserving_corners(
+    polygon: &[Vector2],
+    epsilon: f32,
+    preserve_angle_degrees: f32,
+) -> Vec<Vector2> {
+    simplify_masked(polygon, epsilon, |ring| {
+        if preserve_angle_degrees > 0.0 {
+            sharp_corner_mask(ring, epsilon, preserve_angle_degrees)
+        } else {
+            vec![false; ring.len()]
+        }
+    })
+}
+
+/// Simplify a closed polygon with RDP, always keeping the vertices `pinned` selects.
+///
+/// For vertices that must survive for a reason RDP can't see, such as being
+/// shared with a neighbouring polygon.
+///
+/// # Arguments
+/// * `polygon` - Input polygon vertices
+/// * `epsilon` - Distance threshold; points closer than this to the simplified edge are removed
+/// * `pinned` - Whether a vertex must be kept regardless of distance
+///
+/// # Returns
+/// The simplified polygon, or the input unchanged if it has fewer than 3 points
+pub fn simplify_pinned(
+    polygon: &[Vector2],
+    epsilon: f32,
+    pinned: impl Fn(Vector2) -> bool,
+) -> Vec<Vector2> {
+    simplify_masked(polygon, epsilon, |ring| {
+        ring.iter().map(|&p| pinned(p)).collect()
+    })
+}
+
+/// Simplify a closed polygon, starting from the keep-mask `initial` builds for its ring
+fn simplify_masked(
+    polygon: &[Vector2],
+    epsilon: f32,
+    initial: impl FnOnce(&[Vector2]) -> Vec<bool>,
+) -> Vec<Vector2> {
+    if polygon.len() < 3 {
+        return polygon.to_vec();
+    }
+
+    let is_closed = polygon.first() == polygon.last();
+    let ring = if is_closed {
+        &polygon[..polygon.len() - 1]
+    } else {
+        polygon
+    };
+
+    if ring.len() < 3 {
+        return polygon.to_vec();
+    }
+
+    let mut keep = initial(ring);
+    simplify_ring_mask(ring, epsilon, &mut keep);
+
+    let mut simplified: Vec<Vector2> = ring
+        .iter()
+        .zip(&keep)
+        .filter_map(|(p, &k)| if k { Some(*p) } else { None })
+        .collect();
+
+    if is_closed {
+        simplified.push(ring[0]);
+    }
+
+    simplified
+}
+
+/// Most RDP runs made while searching for an epsilon in `simplify_to_count`
+const COUNT_SEARCH_ITERATIONS: usize = 32;
+
+/// Simplify a closed polygon with RDP to roughly `target_points` vertices.
+///
+/// Binary-searches epsilon between 0 and the polygon's bounding diagonal for the
+/// result whose vertex count is closest to `target_points` without going under.
+/// The closing point of an explicitly closed polygon isn't counted.
+///
+/// # Arguments
+/// * `polygon` - Input polygon vertices
+/// * `target_points` - Desired vertex count (at least 3)
+///
+/// # Returns
+/// The best simplification found, or the input unchanged if it already has
+/// `target_points` vertices or fewer
+pub fn simplify_to_count(polygon: &[Vector2], target_points: usize) -> Vec<Vector2> {
+    let target = target_points.max(3);
+    let vertex_count = |points: &[Vector2]| {
+        let closed = points.len() > 1 && points.first() == points.last();
+        points.len() - usize::from(closed)
+    };
+
+    if vertex_count(polygon) <= target {
+        return polygon.to_vec();
+    }
+
+    let mut best = polygon.to_vec();
+    let mut best_count = vertex_count(&best);
+
+    let mut low = 0.0;
+    let mut high = calculate_bounds(polygon).size.length();
+
+    for _ in 0..COUNT_SEARCH_ITERATIONS {
+        let epsilon = (low + high) * 0.5;
+        let simplified = simplify(polygon, epsilon);
+        let count = vertex_count(&simplified);
+
+        if count < target {
+            high = epsilon; // Too coarse
+            continue;
+        }
+
+        if count < best_count {
+            best = simplified;
+            best_count = count;
+        }
+        if count == target {
+            break;
+        }
+        low = epsilon;
+    }
+
+    best
+}
+
+/// Compute the keep-mask for a closed ring (no duplicated closing point).
+///
+/// The ring is split at index 0, the vertex furthest from it and any vertex
+/// already set in `keep`, and each piece is simplified as an open polyline.
+fn simplify_ring_mask(ring: &[Vector2], epsilon: f32, keep: &mut [bool]) {
+    let n = ring.len();
+    let split = ring_split(ring);
+
+    keep[0] = true;
+    keep[split] = true;
+
+    let anchors: Vec<usize> = (0..n).filter(|&i| keep[i]).collect();
+
+    for (i, &start) in anchors.iter().enumerate() {
+        match anchors.get(i + 1) {
+            Some(&end) => mark_polyline(&ring[start..=end], epsilon, &mut keep[start..=end]),
+            None => {
+                // The last piece wraps around back to index 0, so lay it out contiguously
+                let mut wrapped: Vec<Vector2> = ring[start..].to_vec();
+                wrapped.push(ring[0]);
+
+                let mut wrapped_keep = vec![false; wrapped.len()];
+                mark_polyline(&wrapped, epsilon, &mut wrapped_keep);
+                for (j, &k) in wrapped_keep.iter().enumerate().take(wrapped.len() - 1) {
+                    if k {
+                        keep[start + j] = true;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Index where a closed ring is split into two open polylines
+///
+/// The vertex furthest from the first one is a cheap stand-in for the ring's diameter.
+fn ring_split(ring: &[Vector2]) -> usize {
+    (1..ring.len())
+        .max_by(|&a, &b| {
+            let da = ring[a].distance_squared_to(ring[0]);
+            let db = ring[b].distance_squared_to(ring[0]);
+            da.total_cmp(&db)
+        })
+        .unwrap_or(ring.len() / 2)
+}
+
+/// Simplify a closed polygon with RDP at several epsilons in one pass.
+///
+/// Each level equals `simplify(polygon, epsilon)`, but the split tree is only
+/// built once.
+///
+/// # Arguments
+/// * `polygon` - Input polygon vertices
+/// * `epsilons` - One distance threshold per level, in any order
+///
+/// # Returns
+/// One simplified polygon per epsilon, in the same order as `epsilons`
+pub fn simplify_lods(polygon: &[Vector2], epsilons: &[f32]) -> Vec<Vec<Vector2>> {
+    if polygon.len() < 3 {
+        return vec![polygon.to_vec(); epsilons.len()];
+    }
+
+    let is_closed = polygon.first() == polygon.last();
+    let ring = if is_closed {
+        &polygon[..polygon.len() - 1]
+    } else {
+        polygon
+    };
+
+    if ring.len() < 3 {
+        return vec![polygon.to_vec(); epsilons.len()];
+    }
+
+    let significance = ring_significance(ring);
+
+    epsilons
+        .iter()
+        .map(|&epsilon| {
+            let mut simplified: Vec<Vector2> = ring
+                .iter()
+                .zip(&significance)
+                .filter_map(|(p, &s)| if s > epsilon { Some(*p) } else { None })
+                .collect();
+
+            if is_closed {
+                simplified.push(ring[0]);
+            }
+
+            simplified
+        })
+        .collect()
+}
+
+/// Compute each vertex's significance in a closed ring: it survives RDP exactly
+/// when epsilon is below this value.
+fn ring_significance(ring: &[Vector2]) -> Vec<f32> {
+    let n = ring.len();
+    let split = ring_split(ring);
+    let mut significance = vec![0.0; n];
+
+    polyline_significance(&ring[..=split], &mut significance[..=split]);
+
+    // Second half wraps around back to index 0, so lay it out contiguously
+    let mut second_half: Vec<Vector2> = ring[split..].to_vec();
+    second_half.push(ring[0]);
+
+    let mut second_significance = vec![0.0; second_half.len()];
+    polyline_significance(&second_half, &mut second_significance);
+    significance[split + 1..].copy_from_slice(&second_significance[1..second_half.len() - 1]);
+
+    significance
+}
+
+/// Compute each point's significance in an open polyline.
+///
+/// A point is kept when its own distance and that of every split above it exceed
+/// epsilon, so its significance is the smallest of those distances. Endpoints are
+/// always kept.
+fn polyline_significance(points: &[Vector2], significance: &mut [f32]) {
+    let n = points.len();
+    if n == 0 {
+        return;
+    }
+
+    significance[0] = f32::INFINITY;
+    significance[n - 1] = f32::INFINITY;
+
+    let mut stack = vec![(0, n - 1, f32::INFINITY)];
+
+    while let Some((start, end, limit)) = stack.pop() {
+        let Some((index, distance)) = find_max_distance_point(points, start, end) else {
+            continue;
+        };
+
+        significance[index] = distance.min(limit);
+        stack.push((start, index, significance[index]));
+        stack.push((index, end, significance[index]));
+    }
+}
+
+/// Mark the vertices of a closed ring that form corners sharper than `max_angle_degrees`.
+///
+/// Each vertex's angle is measured to the first points at least `epsilon` away
+/// along the ring in each direction. Of several neighbouring sharp vertices only
+/// the sharpest is marked, so a blunt tip yields one corner rather than a cluster.
+fn sharp_corner_mask(ring: &[Vector2], epsilon: f32, max_angle_degrees: f32) -> Vec<bool> {
+    let n = ring.len();
+    let reach_sq = epsilon.max(f32::EPSILON).powi(2);
+    let max_steps = (n / 2).clamp(1, CORNER_SEARCH_LIMIT);
+
+    // Walk from `i` in direction `step` (1 or n - 1) until a point is far enough away
+    let arm = |i: usize, step: usize| -> Vector2 {
+        let mut j = i;
+        for _ in 0..max_steps {
+            j = (j + step) % n;
+            if ring[j].distance_squared_to(ring[i]) >= reach_sq {
+                break;
+            }
+        }
+        ring[j] - ring[i]
+    };
+
+    let angles: Vec<f32> = (0..n)
+        .map(|i| {
+            let (a, b) = (arm(i, n - 1), arm(i, 1));
+            if a == Vector2::ZERO || b == Vector2::ZERO {
+                return 180.0; // Degenerate, never a corner
+            }
+            a.cross(b).abs().atan2(a.dot(b)).to_degrees()
+        })
+        .collect();
+
+    (0..n)
+        .map(|i| {
+            let (prev, next) = (angles[(i + n - 1) % n], angles[(i + 1) % n]);
+            angles[i] < max_angle_degrees && angles[i] < prev && angles[i] <= next
+        })
+        .collect()
+}
+
+/// Mark the points of an open polyline that survive simplification.
+///
+/// Both endpoints are always kept. Uses an explicit stack instead of recursion to
+/// avoid stack overflow on large contours.
+fn mark_polyline(points: &[Vector2], epsilon: f32, keep: &mut [bool]) {
+    let n = points.len();
+    if n == 0 {
+        return;
+    }
+
+    keep[0] = true;
+    keep[n - 1] = true;
+
+    let mut stack = vec![(0, n - 1)];
+
+    while let Some((start, end)) = stack.pop() {
+        let Some((index, distance)) = find_max_distance_point(points, start, end) else {
+            continue;
+        };
+
+        if distance > epsilon {
+            keep[index] = true;
+            stack.push((start, index));
+            stack.push((index, end));
+        }
+    }
+}
+
+/// Find the point between `start` and `end` (exclusive) furthest from the chord joining them.
+///
+/// Returns `None` if there are no points strictly between the two indices.
+fn find_max_distance_point(points: &[Vector2], start: usize, end: usize) -> Option<(usize, f32)> {
+    if end <= start + 1 {
+        return None;
+    }
+
+    let line_start = points[start];
+    let line_end = points[end];
+
+    (start + 1..end)
+        .map(|i| (i, perpendicular_distance(points[i], line_start, line_end)))
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+/// Distance from a point to a line segment.
+///
+/// Falls back to point distance when the segment has zero length.
+fn perpendicular_distance(point: Vector2, line_start: Vector2, line_end: Vector2) -> f32 {
+    let line_vec = line_end - line_start;
+    let point_vec = point - line_start;
+
+    let line_len_sq = line_vec.length_squared();
+    if line_len_sq == 0.0 {
+        return point_vec.length();
+    }
+
+    let projection = (point_vec.dot(line_vec) / line_len_sq).clamp(0.0, 1.0);
+    let closest_point = line_start + line_vec * projection;
+
+    (point - closest_point).length()
+}
@@ -0,0 +1,155 @@
+//! Visvalingam-Whyatt polygon simplification
+//!
+//! Reference GDScript: addons/cutout/resources/polysimp/cutout_polysimp_vw.gd
+//!
+//! This algorithm works by:
+//! 1. Computing each vertex's effective area (triangle with its two neighbours)
+//! 2. Repeatedly removing the vertex with the smallest effective area
+//! 3. Recomputing the effective areas of the removed vertex's two neighbours
+//! 4. Stopping once the smallest area exceeds `min_area` or the vertex count
+//!    reaches `target_points`
+//!
+//! The polygon is treated as a closed ring, so the first and last vertices get
+//! effective areas using wraparound neighbours.
+
+use godot::prelude::*;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// Smallest vertex count a simplified polygon may have
+const MIN_POLYGON_POINTS: usize = 3;
+
+/// Heap entry for a vertex's effective area at a given version.
+///
+/// Entries become stale when a neighbour is removed and the vertex's area is
+/// recomputed; stale entries are detected by comparing `version` and skipped.
+#[derive(Debug, Clone, Copy)]
+struct AreaEntry {
+    area: f32,
+    index: usize,
+    version: u32,
+}
+
+impl PartialEq for AreaEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for AreaEntry {}
+
+impl PartialOrd for AreaEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AreaEntry {
+    // Reversed so `BinaryHeap` behaves as a min-heap; ties broken by index for determinism
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .area
+            .total_cmp(&self.area)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+/// Simplify a closed polygon using the Visvalingam-Whyatt algorithm.
+///
+/// If the input is explicitly closed (last point equal to the first), the output
+/// is closed too. Winding order is preserved.
+///
+/// # Arguments
+/// * `polygon` - Input polygon vertices
+/// * `min_area` - Vertices whose effective area is at most this value are removed
+/// * `target_points` - Stop once this many vertices remain (0 or less = no target)
+///
+/// # Returns
+/// The simplified polygon, or the input unchanged if it has fewer than 3 points
+pub fn simplify(polygon: &[Vector2], min_area: f32, target_points: i32) -> Vec<Vector2> {
+    if polygon.len() < MIN_POLYGON_POINTS {
+        return polygon.to_vec();
+    }
+
+    let is_closed = polygon.first() == polygon.last();
+    let ring = if is_closed {
+        &polygon[..polygon.len() - 1]
+    } else {
+        polygon
+    };
+
+    let n = ring.len();
+    if n <= MIN_POLYGON_POINTS {
+        return polygon.to_vec();
+    }
+
+    let target = if target_points > 0 {
+        (target_points as usize).max(MIN_POLYGON_POINTS)
+    } else {
+        MIN_POLYGON_POINTS
+    };
+
+    // Doubly-linked ring over the original indices
+    let mut prev: Vec<usize> = (0..n).map(|i| (i + n - 1) % n).collect();
+    let mut next: Vec<usize> = (0..n).map(|i| (i + 1) % n).collect();
+    let mut removed = vec![false; n];
+    let mut versions = vec![0u32; n];
+
+    let mut heap: BinaryHeap<AreaEntry> = (0..n)
+        .map(|i| AreaEntry {
+            area: triangle_area(ring[prev[i]], ring[i], ring[next[i]]),
+            index: i,
+            version: 0,
+        })
+        .collect();
+
+    let mut remaining = n;
+
+    while remaining > target {
+        let Some(entry) = heap.pop() else {
+            break;
+        };
+
+        if removed[entry.index] || entry.version != versions[entry.index] {
+            continue; // Stale entry
+        }
+
+        if entry.area > min_area {
+            break;
+        }
+
+        let i = entry.index;
+        let (p, q) = (prev[i], next[i]);
+
+        removed[i] = true;
+        next[p] = q;
+        prev[q] = p;
+        remaining -= 1;
+
+        for neighbour in [p, q] {
+            versions[neighbour] += 1;
+            heap.push(AreaEntry {
+                area: triangle_area(ring[prev[neighbour]], ring[neighbour], ring[next[neighbour]]),
+                index: neighbour,
+                version: versions[neighbour],
+            });
+        }
+    }
+
+    let mut simplified: Vec<Vector2> = ring
+        .iter()
+        .zip(&removed)
+        .filter_map(|(p, &r)| if r { None } else { Some(*p) })
+        .collect();
+
+    if is_closed {
+        simplified.push(simplified[0]);
+    }
+
+    simplified
+}
+
+/// Unsigned area of the triangle formed by three points.
+fn triangle_area(a: Vector2, b: Vector2, c: Vector2) -> f32 {
+    ((b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y)).abs() * 0.5
+}
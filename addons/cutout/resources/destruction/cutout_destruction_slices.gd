@@ -52,7 +52,7 @@ var _slice_count: int = 3
 var _pattern: Pattern = Pattern.CHAOTIC
 var _parallel_angle: float = 0.0
 var _parallel_angle_rand: float = 0.0  # Random angle variation for parallel lines (0-1 range)
-var _origin: Vector2 = Vector2.ZERO  # Absolute position in polygon coordinate space (Vector2.ZERO = auto-center)
+var _origin: Vector2 = Vector2.ZERO  # Absolute position in polygon coordinate space (Vector2.ZERO = polygon centroid)
 var _radial_randomness: float = 0.0  # 0-1 range for angle variation
 var _h_start: float = 0.0  # Absolute X position for first vertical line
 var _v_start: float = 0.0  # Absolute Y position for first horizontal line
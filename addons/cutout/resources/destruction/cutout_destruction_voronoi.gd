@@ -20,7 +20,8 @@ enum SeedPattern {
 	GRID,        ## Grid-based with jitter - organized destruction (tiles, bricks)
 	RADIAL,      ## Concentric rings from center - impact/explosion patterns
 	SPIDERWEB,   ## Radial rays + rings - cracked glass effect
-	POISSON_DISK ## Blue noise distribution - high-quality natural fractures
+	POISSON_DISK, ## Blue noise distribution - high-quality natural fractures
+	HEX          ## Grid with alternate rows offset - honeycomb cells
 }
 
 ## The pattern to use for seed point placement.
@@ -55,7 +56,7 @@ enum SeedPattern {
 var _grid_rows: int = 3
 var _grid_cols: int = 3
 var _grid_jitter: float = 0.3
-var _origin: Vector2 = Vector2.ZERO
+var _origin: Vector2 = Vector2.ZERO  # Vector2.ZERO = polygon centroid
 var _ring_count: int = 3
 var _ring_size: float = 50.0  # Distance in pixels between rings
 var _points_per_ring: int = 8
@@ -74,7 +75,7 @@ func _get_property_list() -> Array[Dictionary]:
 	var properties: Array[Dictionary] = []
 
 	match pattern:
-		SeedPattern.GRID:
+		SeedPattern.GRID, SeedPattern.HEX:
 			properties.append({
 				"name": "grid_rows",
 				"type": TYPE_INT,
@@ -206,10 +207,9 @@ func _set(property: StringName, value) -> bool:
 ## Delegates seed generation and fracture to the Rust CutoutDestructionProcessor.
 func _fracture(polygons: Array[PackedVector2Array]) -> Array[PackedVector2Array]:
 	var outer_polygon := polygons[0]
-	var bounds := _calculate_bounds(outer_polygon)
 
 	# Generate seed points via the Rust implementation
-	var seed_points := _generate_seed_points_rust(outer_polygon, bounds)
+	var seed_points := _generate_seed_points_rust(outer_polygon)
 
 	# Store for debugging
 	_debug_seed_points = seed_points.duplicate()
@@ -229,7 +229,7 @@ func _fracture(polygons: Array[PackedVector2Array]) -> Array[PackedVector2Array]
 
 
 ## Dispatches seed generation to the appropriate Rust generator based on pattern.
-func _generate_seed_points_rust(outer_polygon: PackedVector2Array, bounds: Rect2) -> PackedVector2Array:
+func _generate_seed_points_rust(outer_polygon: PackedVector2Array) -> PackedVector2Array:
 	match pattern:
 		SeedPattern.RANDOM:
 			return CutoutDestructionProcessor.generate_random_seeds(
@@ -250,10 +250,9 @@ func _generate_seed_points_rust(outer_polygon: PackedVector2Array, bounds: Rect2
 				seed
 			)
 		SeedPattern.RADIAL:
-			var origin := _origin if _origin != Vector2.ZERO else bounds.get_center()
 			return CutoutDestructionProcessor.generate_radial_seeds(
 				outer_polygon,
-				origin,
+				_origin,
 				_ring_count,
 				_ring_size,
 				_points_per_ring,
@@ -262,10 +261,9 @@ func _generate_seed_points_rust(outer_polygon: PackedVector2Array, bounds: Rect2
 				seed
 			)
 		SeedPattern.SPIDERWEB:
-			var origin := _origin if _origin != Vector2.ZERO else bounds.get_center()
 			return CutoutDestructionProcessor.generate_spiderweb_seeds(
 				outer_polygon,
-				origin,
+				_origin,
 				_ring_count,
 				_ring_size,
 				_points_per_ring,
@@ -282,25 +280,16 @@ func _generate_seed_points_rust(outer_polygon: PackedVector2Array, bounds: Rect2
 				_poisson_attempts,
 				seed
 			)
+		SeedPattern.HEX:
+			return CutoutDestructionProcessor.generate_hex_seeds(
+				outer_polygon,
+				_grid_rows,
+				_grid_cols,
+				_grid_jitter,
+				min_cell_distance,
+				edge_padding,
+				seed
+			)
 		_:
 			push_error("Unknown seed pattern: %d" % pattern)
-			return PackedVector2Array()
-
-
-## Calculates the bounding rectangle of a polygon.
-func _calculate_bounds(polygon: PackedVector2Array) -> Rect2:
-	if polygon.is_empty():
-		return Rect2()
-
-	var min_x := polygon[0].x
-	var max_x := polygon[0].x
-	var min_y := polygon[0].y
-	var max_y := polygon[0].y
-
-	for p in polygon:
-		min_x = min(min_x, p.x)
-		max_x = max(max_x, p.x)
-		min_y = min(min_y, p.y)
-		max_y = max(max_y, p.y)
-
-	return Rect2(min_x, min_y, max_x - min_x, max_y - min_y)
\ No newline at end of file
+			return PackedVector2Array()
\ No newline at end of file